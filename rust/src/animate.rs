@@ -0,0 +1,313 @@
+//! The contents of this module is all about animating our output in the console
+use std::io::Write;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::Duration;
+
+use crate::chars::get_first_char_position;
+use crate::config::{Effect, Options};
+use crate::debug::{d, Dt};
+use crate::gradient::get_multiple_transition_colors;
+use crate::plain::strip_ansi;
+use crate::render::{render, resolve_options};
+
+/// The escape sequence to hide the cursor while we animate
+pub const HIDE_CURSOR: &str = "\x1b[?25l";
+
+/// The escape sequence to show the cursor again
+pub const SHOW_CURSOR: &str = "\x1b[?25h";
+
+/// The colors we cycle through when no gradient has been picked
+pub const CYCLE_GRADIENT: [&str; 6] = ["#ff0000", "#ffff00", "#00ff00", "#00ffff", "#0000ff", "#ff00ff"];
+
+/// The fewest steps a cycle takes for each color of its gradient
+pub const CYCLE_STEPS_PER_COLOR: usize = 4;
+
+/// The most frames we keep in memory for a recording, that is an hour at 30 frames per second
+pub const MAX_FRAMES: usize = 108_000;
+
+/// A single frame of an animation
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Frame {
+	/// The output of this frame ready to be printed
+	pub text: String,
+	/// How long this frame is shown before the next one
+	pub delay: Duration,
+}
+
+impl Frame {
+	/// The number of rows this frame takes up in the console
+	pub fn height(&self) -> usize {
+		self.text.split('\n').count()
+	}
+}
+
+/// Replace all characters of a line of output with spaces where `visible` returns false
+///
+/// Escape sequences are kept so colors and backgrounds stay intact.
+///
+/// ```rust
+/// extern crate cfonts;
+///
+/// use cfonts::animate::mask_line;
+///
+/// assert_eq!(mask_line("\x1b[31mabc\x1b[39m", |column| column != 1), "\x1b[31ma c\x1b[39m");
+/// ```
+pub fn mask_line<F: Fn(usize) -> bool>(line: &str, visible: F) -> String {
+	let mut result = String::with_capacity(line.len());
+	let mut chars = line.chars().peekable();
+	let mut column = 0;
+
+	while let Some(character) = chars.next() {
+		if character == '\x1b' && chars.peek() == Some(&'[') {
+			result.push(character);
+			for next in chars.by_ref() {
+				result.push(next);
+				if next.is_ascii_alphabetic() {
+					break;
+				}
+			}
+		} else {
+			result.push(if visible(column) { character } else { ' ' });
+			column += 1;
+		}
+	}
+
+	result
+}
+
+/// The number of visible columns of the widest line of some output
+fn get_width(text: &str) -> usize {
+	text.split('\n').map(|line| strip_ansi(line).chars().count()).max().unwrap_or(0)
+}
+
+/// Each step of an effect as the full output text
+fn get_steps(options: &Options, effect: &Effect) -> Vec<String> {
+	let mut steps = match effect {
+		Effect::Typewriter => {
+			let letters = options.text.chars().collect::<Vec<char>>();
+			(0..=letters.len())
+				.map(|length| {
+					render(Options {
+						text: letters[..length].iter().collect(),
+						..options.clone()
+					})
+					.text
+				})
+				.collect::<Vec<String>>()
+		}
+		Effect::Lines => {
+			let text = render(options.clone()).text;
+			let lines = text.split('\n').collect::<Vec<&str>>();
+			(0..=lines.len())
+				.map(|shown| {
+					lines
+						.iter()
+						.enumerate()
+						.map(|(index, line)| match index < shown {
+							true => line.to_string(),
+							false => mask_line(line, |_| false),
+						})
+						.collect::<Vec<String>>()
+						.join("\n")
+				})
+				.collect::<Vec<String>>()
+		}
+		Effect::Wipe => {
+			let text = render(options.clone()).text;
+			(0..=get_width(&text))
+				.map(|shown| {
+					text.split('\n').map(|line| mask_line(line, |column| column < shown)).collect::<Vec<String>>().join("\n")
+				})
+				.collect::<Vec<String>>()
+		}
+		Effect::Cycle => {
+			let gradient = match options.gradient.is_empty() {
+				true => CYCLE_GRADIENT.iter().map(|color| color.to_string()).collect::<Vec<String>>(),
+				false => options.gradient.clone(),
+			};
+			// the gradient spans the columns after the letter spacing in front of the text
+			let lines = strip_ansi(&render(options.clone()).text)
+				.split('\n')
+				.filter(|line| !line.trim().is_empty())
+				.map(String::from)
+				.collect::<Vec<String>>();
+			let width = match lines.is_empty() {
+				true => 1,
+				false => (get_width(&lines.join("\n")) - get_first_char_position(&lines, options)).max(1),
+			};
+			// the loop needs enough steps for each color to show even when the output is narrow
+			let count = width.max(gradient.len() * CYCLE_STEPS_PER_COLOR);
+
+			// closing the gradient into a loop lets the colors flow around without a seam
+			let mut closed = gradient.clone();
+			closed.push(gradient[0].clone());
+			let mut colors = get_multiple_transition_colors(&closed, count + 1, options);
+			colors.truncate(count);
+
+			(0..count)
+				.map(|phase| {
+					render(Options {
+						gradient: (0..width).map(|column| colors[(column + count - phase) % count].clone()).collect(),
+						transition_gradient: true,
+						..options.clone()
+					})
+					.text
+				})
+				.collect::<Vec<String>>()
+		}
+	};
+
+	// steps that don't change anything visible, like revealing an empty line, would only look like a stutter
+	steps.dedup();
+	steps
+}
+
+/// The number of frames shown within `duration` milliseconds at `per_second` frames per second, at least one
+///
+/// ```rust
+/// extern crate cfonts;
+///
+/// use cfonts::animate::get_frame_count;
+///
+/// assert_eq!(get_frame_count(2_000, 30), 60);
+/// assert_eq!(get_frame_count(10, 30), 1);
+/// assert_eq!(get_frame_count(u64::MAX, 30), (u64::MAX / 1000) as usize);
+/// ```
+pub fn get_frame_count(duration: u64, per_second: u64) -> usize {
+	usize::try_from(duration.saturating_mul(per_second) / 1000).unwrap_or(usize::MAX).max(1)
+}
+
+/// Generate the frames of an animation
+///
+/// Without a `duration` in [`Options`] each step of the effect gets its own frame at the `fps` of [`Options`].
+/// With a `duration` the steps are spread over as many frames as fit into it,
+/// except for [`Effect::Cycle`] which loops through its steps until the time is up.
+/// The last frame has no delay.
+///
+/// ```rust
+/// extern crate cfonts;
+///
+/// use std::time::Duration;
+///
+/// use cfonts::{Effect, Fonts, Options};
+/// use cfonts::animate::frames;
+///
+/// let options = Options {
+///     text: String::from("hi"),
+///     font: Fonts::FontConsole,
+///     spaceless: true,
+///     fps: 10,
+///     ..Options::default()
+/// };
+///
+/// let result = frames(options, Effect::Typewriter).collect::<Vec<_>>();
+/// assert_eq!(result.iter().map(|frame| frame.text.as_str()).collect::<Vec<&str>>(), vec!["", "h", "hi"]);
+/// assert_eq!(result[0].delay, Duration::from_millis(100));
+/// assert_eq!(result[2].delay, Duration::ZERO);
+/// ```
+pub fn frames(options: Options, effect: Effect) -> impl Iterator<Item = Frame> {
+	d("animate::frames()", 1, Dt::Head, &options, &mut std::io::stdout());
+
+	// resolving the options once makes sure every frame has the same colors
+	let options = resolve_options(&options);
+	let steps = get_steps(&options, &effect);
+	let fps = options.fps.max(1) as u64;
+	let interval = Duration::from_millis(1000 / fps);
+	let count = match options.duration {
+		Some(duration) => get_frame_count(duration, fps),
+		None => steps.len(),
+	};
+	d(
+		&format!("animate::frames()\neffect:{:?}\nsteps:{}\nframes:{}", effect, steps.len(), count),
+		1,
+		Dt::Log,
+		&options,
+		&mut std::io::stdout(),
+	);
+
+	(0..count).map(move |index| {
+		let step = match (&effect, count) {
+			(Effect::Cycle, _) => index % steps.len(),
+			(_, 1) => steps.len() - 1,
+			// in u128 so very long durations can't overflow
+			_ => (index as u128 * (steps.len() - 1) as u128 / (count - 1) as u128) as usize,
+		};
+		Frame {
+			text: steps[step].clone(),
+			delay: if index + 1 == count { Duration::ZERO } else { interval },
+		}
+	})
+}
+
+/// The escape sequences to move the cursor back to the start of a frame of `height` rows and clear it
+///
+/// ```rust
+/// extern crate cfonts;
+///
+/// use cfonts::animate::clear_frame;
+///
+/// assert_eq!(clear_frame(0), "");
+/// assert_eq!(clear_frame(1), "\r\x1b[J");
+/// assert_eq!(clear_frame(3), "\r\x1b[2A\x1b[J");
+/// ```
+pub fn clear_frame(height: usize) -> String {
+	match height {
+		0 => String::new(),
+		1 => String::from("\r\x1b[J"),
+		_ => format!("\r\x1b[{}A\x1b[J", height - 1),
+	}
+}
+
+/// Play frames in place in the console until they run out or `stop` is set
+///
+/// The cursor is hidden while playing and shown again at the end, also when we are stopped.
+/// Returns `true` if the animation was stopped.
+///
+/// ```rust
+/// extern crate cfonts;
+///
+/// use std::sync::atomic::AtomicBool;
+/// use std::time::Duration;
+///
+/// use cfonts::animate::{play, Frame};
+///
+/// let frames = vec![
+///     Frame { text: String::from("a\nb"), delay: Duration::ZERO },
+///     Frame { text: String::from("c\nd"), delay: Duration::ZERO },
+/// ];
+/// let mut output = Vec::new();
+///
+/// assert_eq!(play(frames.into_iter(), &mut output, &AtomicBool::new(false)).unwrap(), false);
+/// assert_eq!(String::from_utf8(output).unwrap(), "\x1b[?25la\nb\r\x1b[1A\x1b[Jc\nd\x1b[?25h\n");
+/// ```
+pub fn play<I: Iterator<Item = Frame>, W: Write>(frames: I, out: &mut W, stop: &AtomicBool) -> std::io::Result<bool> {
+	write!(out, "{}", HIDE_CURSOR)?;
+
+	let mut previous_height = 0;
+	let mut stopped = false;
+
+	'frames: for frame in frames {
+		write!(out, "{}{}", clear_frame(previous_height), frame.text)?;
+		out.flush()?;
+		previous_height = frame.height();
+
+		// we sleep in small steps so we can react to being stopped quickly
+		let mut waited = Duration::ZERO;
+		loop {
+			if stop.load(Ordering::Relaxed) {
+				stopped = true;
+				break 'frames;
+			}
+			if waited >= frame.delay {
+				break;
+			}
+			let step = (frame.delay - waited).min(Duration::from_millis(10));
+			std::thread::sleep(step);
+			waited += step;
+		}
+	}
+
+	writeln!(out, "{}", SHOW_CURSOR)?;
+	out.flush()?;
+	Ok(stopped)
+}
@@ -1,14 +1,17 @@
 //! The contents of this module is all about parsing cli arguments
 use std::collections::HashMap;
+use std::path::Path;
 
+use crate::clock::DEFAULT_CLOCK_FORMAT;
 use crate::color::{color, get_foreground_color, hex2rgb, rgb2hex};
 use crate::config::{
-	Align, BgColors, CliOption, Colors, Env, Fonts, OptionType, Options, CLIOPTIONS, GRADIENTS_AGENDER,
-	GRADIENTS_AROMANTIC, GRADIENTS_ASEXUAL, GRADIENTS_BISEXUAL, GRADIENTS_GENDERFLUID, GRADIENTS_GENDERQUEER,
-	GRADIENTS_INTERSEX, GRADIENTS_LESBIAN, GRADIENTS_NONBINARY, GRADIENTS_PANSEXUAL, GRADIENTS_POLYSEXUAL,
-	GRADIENTS_PRIDE, GRADIENTS_TRANSGENDER,
+	Align, BgColors, Border, CandyMode, CliOption, ColorMode, Colors, Cvd, Effect, Env, Fonts, OptionType, Options,
+	OutputFormat, Shell, TerminalBg, CLIOPTIONS,
 };
 use crate::debug::{d, Dt};
+use crate::gradient::GradientPresetRegistry;
+use crate::helpers::{get_closest, get_config_dir};
+use crate::theme::{Theme, ThemeRegistry};
 
 /// This function converts command line arguments into an [`Options`] struct
 ///
@@ -136,8 +139,30 @@ use crate::debug::{d, Dt};
 /// );
 /// ```
 pub fn parse(args: Vec<String>) -> Result<Options, String> {
+	parse_with(args, Options::default())
+}
+
+/// Same as [`parse()`] but the command line arguments are applied on top of `defaults` instead of [`Options::default()`]
+///
+/// ```rust
+/// extern crate cfonts;
+///
+/// use cfonts::{Align, Fonts, Options};
+/// use cfonts::args::parse_with;
+///
+/// let defaults = Options {
+///     font: Fonts::FontTiny,
+///     align: Align::Center,
+///     ..Options::default()
+/// };
+///
+/// let options = parse_with(vec!["path/to/bin".to_string(), "hi".to_string(), "-a".to_string(), "right".to_string()], defaults).unwrap();
+/// assert_eq!(options.font, Fonts::FontTiny);
+/// assert_eq!(options.align, Align::Right);
+/// ```
+pub fn parse_with(args: Vec<String>, defaults: Options) -> Result<Options, String> {
 	let mut my_args = args;
-	let mut options = Options::default();
+	let mut options = defaults;
 
 	// create a lookup table for our CLIOPTIONS
 	let mut options_lookup: HashMap<String, CliOption> = HashMap::new();
@@ -146,386 +171,117 @@ pub fn parse(args: Vec<String>) -> Result<Options, String> {
 		let name = option.name.to_string();
 		let shortcut = option.shortcut.to_string();
 		options_lookup.insert(name, option.clone());
-		options_lookup.insert(shortcut, option.clone());
+		if !option.shortcut.is_empty() {
+			options_lookup.insert(shortcut, option.clone());
+		}
 		if !option.fallback_shortcut.is_empty() {
 			let shortcut = option.fallback_shortcut.to_string();
 			options_lookup.insert(shortcut, option);
 		}
 	}
 
+	if my_args.len() < 2 {
+		return Err(missing_text());
+	}
+
+	// we bring the arguments into the shape [bin, text, ...flags] so the text can be given anywhere
+	let (text, flags) = normalize_args(&my_args[1..])?;
+	let has_text = text.is_some();
+	my_args = [vec![my_args[0].clone(), text.unwrap_or_default()], flags].concat();
+	let flags = &my_args[2..];
+
 	// we check for the debug flag to make sure we send debug messages in this function as well
 	let debug_options = options_lookup.get("-d").unwrap();
 	let enabled_debug =
-		my_args.contains(&debug_options.name.to_string()) || my_args.contains(&debug_options.shortcut.to_string());
+		flags.contains(&debug_options.name.to_string()) || flags.contains(&debug_options.shortcut.to_string());
 	if enabled_debug {
 		options.debug = true;
 	}
 
+	// we check for the lenient flag so unknown flags before it are ignored as well
+	let lenient_options = options_lookup.get("--lenient").unwrap();
+	let lenient = options.lenient || flags.contains(&lenient_options.name.to_string());
+
 	// we check for the line_height flag to make sure we don't override it with the console font
 	let line_height_options = options_lookup.get("-z").unwrap();
-	let line_height_changed = my_args.contains(&line_height_options.name.to_string())
-		|| my_args.contains(&line_height_options.shortcut.to_string());
-
-	d("args::parse()", 1, Dt::Head, &options, &mut std::io::stdout());
+	let line_height_changed =
+		flags.contains(&line_height_options.name.to_string()) || flags.contains(&line_height_options.shortcut.to_string());
 
-	if my_args.len() < 2 {
-		let (start, end) = get_foreground_color(&Colors::Green);
-		return Err(format!(
-			"Please provide text to convert with: {start}cfonts \"Text\"{end}\nRun {start}cfonts --help{end} for more infos",
-			start = start,
-			end = end
-		));
-	}
+	// we check for the transition flag so gradients can be validated no matter where it was given
+	let transition_options = options_lookup.get("-t").unwrap();
+	let transition_given =
+		flags.contains(&transition_options.name.to_string()) || flags.contains(&transition_options.shortcut.to_string());
 
-	let version_options = options_lookup.get("-v").unwrap();
-	if my_args[1] == *version_options.shortcut.to_string()
-		|| my_args[1] == *version_options.name.to_string()
-		|| my_args[1] == *version_options.fallback_shortcut.to_string()
-	{
-		options.version = true;
-	}
-
-	let help_options = options_lookup.get("-h").unwrap();
-	if my_args[1] == *help_options.shortcut.to_string() || my_args[1] == *help_options.name.to_string() {
-		options.help = true;
-	}
+	d("args::parse()", 1, Dt::Head, &options, &mut std::io::stdout());
 
 	// our text to be converted
 	options.text = my_args[1].clone();
 
-	let mut args_length = my_args.len();
-	let mut i = 2; // we skip the first two arguments as the first is path to binary and the second we already take care of above
-							 // we iterate over all arguments and match them with our lookup table
-	while i < args_length {
-		// before we see if this flag exists in our lookup we see if boolean flags have been stacked here
-		if my_args[i].starts_with('-') && !my_args[i].starts_with("--") && my_args[i].len() > 2 {
-			// we know that this is a stack of boolean flags
-			let this_flag = my_args[i].clone();
-			let mut middle_flags = Vec::new();
-
-			// unwrap is guarded by if clause it's contained in
-			for flag in this_flag.strip_prefix('-').unwrap().chars() {
-				let flag_name = format!("-{}", flag);
-				if options_lookup.get(&flag_name).is_some() {
-					middle_flags.push(flag_name);
-				}
-			}
+	// we check for the terminal background flag to make sure themes can pick their light or dark colors
+	let terminal_bg_options = options_lookup.get("--terminal-bg").unwrap();
+	if let Some(index) = my_args.iter().skip(2).position(|arg| arg == terminal_bg_options.name) {
+		if let Some(value) = my_args.get(index + 3) {
+			options.terminal_bg = parse_terminal_bg(value, &options)?;
+		}
+	}
 
-			my_args.splice(i..i + 1, middle_flags.iter().cloned());
-			args_length = my_args.len();
+	// we apply the theme before all other flags so that flags can override what the theme sets
+	let theme_options = options_lookup.get("--theme").unwrap();
+	if let Some(index) = my_args.iter().skip(2).position(|arg| arg == theme_options.name) {
+		match my_args.get(index + 3) {
+			Some(value) => {
+				load_theme(value, &options)?.apply_with_transition(&mut options, transition_given)?;
+			}
+			None => return Err(format!("Missing value for option: {}", color(theme_options.name, Colors::Green))),
 		}
+	}
 
+	let args_length = my_args.len();
+	// we skip the first two arguments as the first is path to binary and the second we already take care of above
+	// we iterate over all arguments and match them with our lookup table
+	let mut i = 2;
+	while i < args_length {
 		match options_lookup.get(&my_args[i]) {
-			Some(this_flag) => {
-				match this_flag.kind {
-					OptionType::Text => { /* Only Text type is on argvs[1] */ }
-					OptionType::Font => {
-						i += 1;
-						if i >= args_length {
-							return Err(format!("Missing value for option: {}", color(this_flag.name, Colors::Green)));
-						}
-						options.font = match my_args[i].to_lowercase().as_str() {
-							"console" => Fonts::FontConsole,
-							"block" => Fonts::FontBlock,
-							"simpleblock" => Fonts::FontSimpleBlock,
-							"simple" => Fonts::FontSimple,
-							"3d" => Fonts::Font3d,
-							"simple3d" => Fonts::FontSimple3d,
-							"chrome" => Fonts::FontChrome,
-							"huge" => Fonts::FontHuge,
-							"shade" => Fonts::FontShade,
-							"slick" => Fonts::FontSlick,
-							"grid" => Fonts::FontGrid,
-							"pallet" => Fonts::FontPallet,
-							"tiny" => Fonts::FontTiny,
-							unknown => {
-								return Err(format!(
-									"The font \"{}\" is not supported.\nAllowed options are: {}",
-									color(unknown, Colors::Green),
-									color(&Fonts::list(), Colors::Green)
-								));
-							}
-						};
-
-						if options.font == Fonts::FontConsole && !line_height_changed {
-							options.line_height = 0;
-						}
-					}
-					OptionType::Align => {
-						i += 1;
-						if i >= args_length {
-							return Err(format!("Missing value for option: {}", color(this_flag.name, Colors::Green)));
-						}
-						options.align = match my_args[i].to_lowercase().as_str() {
-							"left" => Align::Left,
-							"center" => Align::Center,
-							"right" => Align::Right,
-							"top" => Align::Top,
-							"bottom" => Align::Bottom,
-							unknown => {
-								return Err(format!(
-									"The alignment option \"{}\" is not supported.\nAllowed options are: {}",
-									color(unknown, Colors::Green),
-									color(&Align::list(), Colors::Green)
-								));
-							}
-						};
-					}
-					OptionType::Colors => {
-						i += 1;
-						if i >= args_length {
-							return Err(format!("Missing value for option: {}", color(this_flag.name, Colors::Green)));
-						}
-						options.colors = my_args[i]
-							.to_lowercase()
-							.as_str()
-							.split(',')
-							.map(|this_color| match this_color {
-								"system" => Ok(Colors::System),
-								"black" => Ok(Colors::Black),
-								"red" => Ok(Colors::Red),
-								"green" => Ok(Colors::Green),
-								"yellow" => Ok(Colors::Yellow),
-								"blue" => Ok(Colors::Blue),
-								"magenta" => Ok(Colors::Magenta),
-								"cyan" => Ok(Colors::Cyan),
-								"white" => Ok(Colors::White),
-								"gray" => Ok(Colors::Gray),
-								"grey" => Ok(Colors::Gray),
-								"redbright" => Ok(Colors::RedBright),
-								"greenbright" => Ok(Colors::GreenBright),
-								"yellowbright" => Ok(Colors::YellowBright),
-								"bluebright" => Ok(Colors::BlueBright),
-								"magentabright" => Ok(Colors::MagentaBright),
-								"cyanbright" => Ok(Colors::CyanBright),
-								"whitebright" => Ok(Colors::WhiteBright),
-								"candy" => Ok(Colors::Candy),
-								unknown => {
-									if unknown.starts_with('#') && unknown.len() > 2 {
-										Ok(Colors::Rgb(hex2rgb(unknown, &options)))
-									} else {
-										Err(format!(
-											"The color \"{}\" is not supported.\nAllowed options are: {}",
-											color(unknown, Colors::Green),
-											color(&Colors::list(), Colors::Green)
-										))
-									}
-								}
-							})
-							.collect::<Result<Vec<Colors>, String>>()?;
-					}
-					OptionType::BgColor => {
-						i += 1;
-						if i >= args_length {
-							return Err(format!("Missing value for option: {}", color(this_flag.name, Colors::Green)));
+			Some(this_flag) => match this_flag.kind {
+				OptionType::Text => { /* Texts after --then are split off in parse_many() */ }
+				OptionType::Bool => {
+					apply_option(this_flag, "true", &mut options)?;
+				}
+				OptionType::Clock => {
+					// the format is optional so we only take the next argument if it isn't a flag
+					let format = match my_args.get(i + 1) {
+						Some(format) if !format.starts_with('-') => {
+							i += 1;
+							format.clone()
 						}
-						options.background = match my_args[i].to_lowercase().as_str() {
-							"transparent" => BgColors::Transparent,
-							"black" => BgColors::Black,
-							"red" => BgColors::Red,
-							"green" => BgColors::Green,
-							"yellow" => BgColors::Yellow,
-							"blue" => BgColors::Blue,
-							"magenta" => BgColors::Magenta,
-							"cyan" => BgColors::Cyan,
-							"white" => BgColors::White,
-							"gray" => BgColors::Gray,
-							"grey" => BgColors::Gray,
-							"redbright" => BgColors::RedBright,
-							"greenbright" => BgColors::GreenBright,
-							"yellowbright" => BgColors::YellowBright,
-							"bluebright" => BgColors::BlueBright,
-							"magentabright" => BgColors::MagentaBright,
-							"cyanbright" => BgColors::CyanBright,
-							"whitebright" => BgColors::WhiteBright,
-							unknown => {
-								if unknown.starts_with('#') && unknown.len() > 2 {
-									BgColors::Rgb(hex2rgb(unknown, &options))
-								} else {
-									return Err(format!(
-										"The background color \"{}\" is not supported.\nAllowed options are: {}",
-										color(unknown, Colors::Green),
-										color(&BgColors::list(), Colors::Green)
-									));
-								}
-							}
-						};
+						_ => String::from(DEFAULT_CLOCK_FORMAT),
+					};
+					apply_option(this_flag, &format, &mut options)?;
+				}
+				OptionType::Theme => {
+					// the theme has already been applied before we started iterating
+					i += 1;
+				}
+				_ => {
+					i += 1;
+					if i >= args_length {
+						return Err(format!("Missing value for option: {}", color(this_flag.name, Colors::Green)));
 					}
-					OptionType::Gradient => {
-						i += 1;
-						if i >= args_length {
-							return Err(format!("Missing value for option: {}", color(this_flag.name, Colors::Green)));
-						}
-						let expanded_args = match my_args[i].to_lowercase().as_str() {
-							"lgbt" | "lgbtq" | "lgbtqa" | "pride" => {
-								options.transition_gradient = true;
-								GRADIENTS_PRIDE.join(",")
-							}
-							"agender" => {
-								options.transition_gradient = true;
-								GRADIENTS_AGENDER.join(",")
-							}
-							"aromantic" => {
-								options.transition_gradient = true;
-								GRADIENTS_AROMANTIC.join(",")
-							}
-							"asexual" => {
-								options.transition_gradient = true;
-								GRADIENTS_ASEXUAL.join(",")
-							}
-							"bisexual" | "bi" => {
-								options.transition_gradient = true;
-								GRADIENTS_BISEXUAL.join(",")
-							}
-							"genderfluid" => {
-								options.transition_gradient = true;
-								GRADIENTS_GENDERFLUID.join(",")
-							}
-							"genderqueer" => {
-								options.transition_gradient = true;
-								GRADIENTS_GENDERQUEER.join(",")
-							}
-							"intersex" => {
-								options.transition_gradient = true;
-								GRADIENTS_INTERSEX.join(",")
-							}
-							"lesbian" => {
-								options.transition_gradient = true;
-								GRADIENTS_LESBIAN.join(",")
-							}
-							"nonbinary" => {
-								options.transition_gradient = true;
-								GRADIENTS_NONBINARY.join(",")
-							}
-							"pansexual" | "pan" => {
-								options.transition_gradient = true;
-								GRADIENTS_PANSEXUAL.join(",")
-							}
-							"polysexual" | "poly" => {
-								options.transition_gradient = true;
-								GRADIENTS_POLYSEXUAL.join(",")
-							}
-							"transgender" | "trans" => {
-								options.transition_gradient = true;
-								GRADIENTS_TRANSGENDER.join(",")
-							}
-							unknown => unknown.to_string(),
-						};
-
-						options.gradient = expanded_args
-							.split(',')
-							.map(|this_color| match this_color {
-								"black" => Ok(String::from("#000000")),
-								"red" => Ok(String::from("#ff0000")),
-								"green" => Ok(String::from("#00ff00")),
-								"blue" => Ok(String::from("#0000ff")),
-								"yellow" => Ok(String::from("#ffff00")),
-								"magenta" => Ok(String::from("#ff00ff")),
-								"cyan" => Ok(String::from("#00ffff")),
-								"white" => Ok(String::from("#ffffff")),
-								"gray" | "grey" => Ok(String::from("#808080")),
-								unknown => {
-									if unknown.starts_with('#') && unknown.len() > 2 {
-										// parsing hex round trip to make sure it's in a good format
-										Ok(rgb2hex(&hex2rgb(unknown, &options), &options))
-									} else {
-										Err(format!("The gradient color \"{}\" is not supported.\nAllowed options are: black, red, green, blue, yellow, magenta, cyan, white, gray, grey", color(unknown, Colors::Green)))
-									}
-								}
-							}).collect::<Result<Vec<String>,String>>()?;
-
-						let transition_options = options_lookup.get("-t").unwrap();
-						let is_transition = my_args.contains(&transition_options.name.to_string())
-							|| my_args.contains(&transition_options.shortcut.to_string())
-							|| options.transition_gradient;
-						if is_transition && options.gradient.len() < 2 {
-							return Err(format!(
-								"You must specify at least two colors for transition gradients. You specified only \"{}\"",
-								color(&format!("{}", options.gradient.len()), Colors::Green)
-							));
-						}
+					apply_option(this_flag, &my_args[i], &mut options)?;
 
-						if !is_transition && options.gradient.len() != 2 {
-							return Err(format!(
-								"You must specify two colors for a gradient. You specified \"{}\"",
-								color(&format!("{}", options.gradient.len()), Colors::Green)
-							));
-						}
+					if this_flag.kind == OptionType::Font && options.font == Fonts::FontConsole && !line_height_changed {
+						options.line_height = 0;
 					}
-					OptionType::Number => {
-						i += 1;
-						if i >= args_length {
-							return Err(format!("Missing value for option: {}", color(this_flag.name, Colors::Green)));
-						}
-						let number = match my_args[i].parse::<u16>() {
-							Ok(n) => n,
-							Err(_) => {
-								return Err(format!(
-									"Could not read argument for option: {}. Needs to be a positive number but found instead: \"{}\"",
-									color(this_flag.name, Colors::Green),
-									color(&my_args[i], Colors::Green)
-								));
-							}
-						};
-
-						match this_flag.key {
-							"letter_spacing" => {
-								options.letter_spacing = number;
-							}
-							"line_height" => {
-								options.line_height = number;
-							}
-							"max_length" => {
-								options.max_length = number;
-							}
-							"debug_level" => {
-								options.debug_level = number;
-							}
-							_ => {}
-						}
-					}
-					OptionType::Bool => match this_flag.key {
-						"version" => {
-							options.version = true;
-						}
-						"help" => {
-							options.help = true;
-						}
-						"spaceless" => {
-							options.spaceless = true;
-						}
-						"independent_gradient" => {
-							options.independent_gradient = true;
-						}
-						"transition_gradient" => {
-							options.transition_gradient = true;
-						}
-						"debug" => {
-							options.debug = true;
-						}
-						_ => {}
-					},
-					OptionType::Env => {
-						i += 1;
-						if i >= args_length {
-							return Err(format!("Missing value for option: {}", color(this_flag.name, Colors::Green)));
-						}
-						options.env = match my_args[i].to_lowercase().as_str() {
-							"node" | "cli" => Env::Cli,
-							"browser" => Env::Browser,
-							unknown => {
-								return Err(format!(
-									"The env option \"{}\" is not supported.\nAllowed options are: {}",
-									color(unknown, Colors::Green),
-									color(&Env::list(), Colors::Green)
-								));
-							}
-						};
+					if this_flag.kind == OptionType::Gradient {
+						validate_gradient(&options.gradient, transition_given || options.transition_gradient)?;
 					}
 				}
-			}
+			},
 			None => {
-				/* We ignore flags we don't recognize */
+				if my_args[i].starts_with('-') && !lenient {
+					return Err(unknown_flag(&my_args[i]));
+				}
+				/* We ignore everything else we don't recognize */
 				d(&format!("CLI flag \"{}\" was ignored", my_args[i]), 1, Dt::Log, &options, &mut std::io::stdout());
 				// note this will only debug print flags after the encounter the debug flag
 			}
@@ -535,5 +291,1175 @@ pub fn parse(args: Vec<String>) -> Result<Options, String> {
 		i += 1;
 	}
 
+	// only the flags that print something other than the text or bring their own text may leave it out
+	let needs_text = !(options.version
+		|| options.help
+		|| options.list_gradients
+		|| options.print_config
+		|| options.man
+		|| options.completions.is_some()
+		|| options.clock.is_some()
+		|| options.countdown.is_some());
+	if needs_text && !has_text {
+		return Err(missing_text());
+	}
+
 	Ok(options)
 }
+
+/// Parse command line arguments with texts separated by `--then` into one [`Options`] struct per text
+///
+/// Each text is parsed on its own with [`parse_with()`] on top of `defaults` so it only gets the flags given after it.
+/// Use [`crate::render_many()`] to render them stacked on top of each other.
+///
+/// ```rust
+/// extern crate cfonts;
+///
+/// use cfonts::{Fonts, Options};
+/// use cfonts::args::parse_many;
+///
+/// let args = vec!["path/to/bin", "Title", "-f", "block", "--then", "subtitle", "-f", "tiny"];
+/// let blocks = parse_many(args.into_iter().map(String::from).collect(), Options::default()).unwrap();
+///
+/// assert_eq!(blocks.len(), 2);
+/// assert_eq!(blocks[0].text, String::from("Title"));
+/// assert_eq!(blocks[0].font, Fonts::FontBlock);
+/// assert_eq!(blocks[1].text, String::from("subtitle"));
+/// assert_eq!(blocks[1].font, Fonts::FontTiny);
+/// ```
+pub fn parse_many(args: Vec<String>, defaults: Options) -> Result<Vec<Options>, String> {
+	let then_options = CLIOPTIONS.iter().find(|option| option.key == "then").unwrap();
+	let binary = args.first().cloned().unwrap_or_default();
+
+	// a --then right after -- is the text itself
+	let mut groups = vec![Vec::new()];
+	for (index, arg) in args.iter().enumerate() {
+		if arg == then_options.name && index > 0 && args[index - 1] != "--" {
+			groups.push(vec![binary.clone()]);
+		} else {
+			groups.last_mut().unwrap().push(arg.clone());
+		}
+	}
+
+	let blocks =
+		groups.into_iter().map(|group| parse_with(group, defaults.clone())).collect::<Result<Vec<Options>, String>>()?;
+
+	let first = &blocks[0];
+	if blocks.len() > 1
+		&& (first.output.is_some()
+			|| first.output_format != OutputFormat::Text
+			|| first.animate.is_some()
+			|| first.marquee
+			|| first.clock.is_some()
+			|| first.countdown.is_some())
+	{
+		return Err(format!(
+			"The option {} can only be used for the text output in the console",
+			color(then_options.name, Colors::Green)
+		));
+	}
+
+	Ok(blocks)
+}
+
+/// Bring the command line arguments (without the path to the binary) into the shape [`parse()`] works with
+///
+/// A first argument that isn't a flag is always the text.
+/// Otherwise the first argument that isn't a flag or the value of a flag is the text, no matter where it was given.
+/// Values can be given as `--font=tiny` or `-ftiny` and boolean flags can be stacked like `-sit`.
+/// Everything after `--` is never read as a flag so the text can start with a dash.
+/// Returns the text, if there was any, and all flags each followed by its value.
+///
+/// ```rust
+/// extern crate cfonts;
+///
+/// use cfonts::args::normalize_args;
+///
+/// let args = vec!["-sftiny".to_string(), "--align=center".to_string(), "--".to_string(), "-10%".to_string()];
+/// let (text, flags) = normalize_args(&args).unwrap();
+/// assert_eq!(text, Some("-10%".to_string()));
+/// assert_eq!(flags, vec!["-s", "-f", "tiny", "--align", "center"]);
+/// ```
+pub fn normalize_args(args: &[String]) -> Result<(Option<String>, Vec<String>), String> {
+	let find = |flag: &str| {
+		CLIOPTIONS.iter().find(|option| option.name == flag || option.shortcut == flag || option.fallback_shortcut == flag)
+	};
+	let takes_value =
+		|option: &CliOption| !matches!(option.kind, OptionType::Text | OptionType::Bool | OptionType::Clock);
+
+	let mut text = None;
+	let mut flags = Vec::new();
+	let mut args = args.iter();
+
+	while let Some(arg) = args.next() {
+		if arg == "--" {
+			if text.is_none() {
+				text = args.next().cloned();
+			}
+			break;
+		} else if arg.starts_with("--") {
+			let (flag, value) = match arg.split_once('=') {
+				Some((flag, value)) => (flag, Some(value.to_string())),
+				None => (arg.as_str(), None),
+			};
+			flags.push(flag.to_string());
+
+			match (find(flag), value) {
+				(Some(option), Some(value)) if option.kind == OptionType::Bool => {
+					return Err(format!(
+						"The option {} doesn't take a value but got \"{}\"",
+						color(option.name, Colors::Green),
+						color(&value, Colors::Green)
+					));
+				}
+				(_, Some(value)) => flags.push(value),
+				(Some(option), None) if takes_value(option) => flags.extend(args.next().cloned()),
+				// the format of the clock is optional so we only take the next argument if it isn't a flag
+				(Some(option), None)
+					if option.kind == OptionType::Clock && args.as_slice().first().is_some_and(|next| !next.starts_with('-')) =>
+				{
+					flags.extend(args.next().cloned());
+				}
+				(None, None) if unknown_takes_value(args.as_slice(), text.is_some()) => flags.extend(args.next().cloned()),
+				_ => {}
+			}
+		} else if arg.starts_with('-') && arg.len() > 1 {
+			// short flags can be stacked and the first one that takes a value takes the rest of the argument
+			let stack = arg.strip_prefix('-').unwrap();
+			for (index, letter) in stack.char_indices() {
+				let flag = format!("-{}", letter);
+				flags.push(flag.clone());
+
+				let is_last = index + letter.len_utf8() == stack.len();
+				if is_last && find(&flag).is_none() && unknown_takes_value(args.as_slice(), text.is_some()) {
+					flags.extend(args.next().cloned());
+				}
+				if find(&flag).is_some_and(takes_value) {
+					let rest = &stack[index + letter.len_utf8()..];
+					if rest.is_empty() {
+						flags.extend(args.next().cloned());
+					} else {
+						flags.push(rest.to_string());
+					}
+					break;
+				}
+			}
+		} else if text.is_none() {
+			text = Some(arg.clone());
+		}
+		/* We ignore every other argument that isn't a flag */
+	}
+
+	Ok((text, flags))
+}
+
+/// Whether an unknown flag takes the next of the `rest` arguments as its value
+///
+/// We can't know if an unknown flag expects a value so it only takes the next argument when that doesn't cost us the
+/// text, e.g. `--colour red hi` keeps `hi` as the text while `--colour hi` does too.
+fn unknown_takes_value(rest: &[String], has_text: bool) -> bool {
+	match rest.split_first() {
+		Some((next, rest)) if !next.starts_with('-') => {
+			has_text || normalize_args(rest).is_ok_and(|(text, _)| text.is_some())
+		}
+		_ => false,
+	}
+}
+
+/// The error for when there is no text to convert
+fn missing_text() -> String {
+	let (start, end) = get_foreground_color(&Colors::Green);
+	format!(
+		"Please provide text to convert with: {start}cfonts \"Text\"{end}\nRun {start}cfonts --help{end} for more infos",
+		start = start,
+		end = end
+	)
+}
+
+/// A hint with the closest value out of one of our `list()` strings for a misspelled value
+fn did_you_mean(value: &str, list: &str) -> String {
+	match get_closest(value, list.split(", ").filter(|item| !item.contains(' '))) {
+		Some(closest) => format!("\nDid you mean \"{}\"?", color(closest, Colors::Green)),
+		None => String::new(),
+	}
+}
+
+/// The error for a flag we don't know with the closest flag we do know
+fn unknown_flag(flag: &str) -> String {
+	let flags = CLIOPTIONS
+		.iter()
+		.flat_map(|option| [option.name, option.shortcut, option.fallback_shortcut])
+		.filter(|flag| !flag.is_empty())
+		.collect::<Vec<&str>>();
+
+	format!(
+		"The option \"{}\" is not supported.{}\nRun {} to see all options or use {} to ignore unknown options",
+		color(flag, Colors::Green),
+		did_you_mean(flag, &flags.join(", ")),
+		color("cfonts --help", Colors::Green),
+		color("--lenient", Colors::Green)
+	)
+}
+
+/// Apply the value of a single option to [`Options`] the same way the command line does
+///
+/// Options of the type [`OptionType::Bool`] take `"true"` or `"false"` and [`OptionType::Theme`] applies the theme.
+/// Gradients are not validated here since that depends on the transition option.
+///
+/// ```rust
+/// extern crate cfonts;
+///
+/// use cfonts::{Fonts, Options};
+/// use cfonts::config::CLIOPTIONS;
+/// use cfonts::args::apply_option;
+///
+/// let mut options = Options::default();
+/// let font = CLIOPTIONS.iter().find(|option| option.key == "font").unwrap();
+/// let spaceless = CLIOPTIONS.iter().find(|option| option.key == "spaceless").unwrap();
+///
+/// apply_option(font, "tiny", &mut options).unwrap();
+/// apply_option(spaceless, "true", &mut options).unwrap();
+/// assert_eq!(options.font, Fonts::FontTiny);
+/// assert_eq!(options.spaceless, true);
+/// assert!(apply_option(spaceless, "maybe", &mut options).is_err());
+/// ```
+pub fn apply_option(option: &CliOption, value: &str, options: &mut Options) -> Result<(), String> {
+	d("args::apply_option()", 5, Dt::Head, options, &mut std::io::stdout());
+	d(
+		&format!("args::apply_option()\noption:{:?}\nvalue:{:?}", option.key, value),
+		5,
+		Dt::Log,
+		options,
+		&mut std::io::stdout(),
+	);
+
+	match option.kind {
+		OptionType::Text => {
+			options.text = value.to_string();
+		}
+		OptionType::Font => {
+			options.font = parse_font(value, options)?;
+		}
+		OptionType::Align => {
+			options.align = parse_align(value, options)?;
+		}
+		OptionType::Colors => {
+			options.colors = parse_colors(value, options)?;
+		}
+		OptionType::BgColor => {
+			options.background = parse_bg_color(value, options)?;
+		}
+		OptionType::Gradient => {
+			// a single value may be the name of a gradient preset
+			let expanded_value = if value.contains(',') {
+				value.to_string()
+			} else {
+				let mut presets = GradientPresetRegistry::default();
+				let loaded = presets.load_config(options);
+				match presets.get(value) {
+					Some(preset) => {
+						options.transition_gradient = options.transition_gradient || preset.transition;
+						preset.colors.join(",")
+					}
+					// a broken user preset is only an error when it's the one we're after
+					None => match loaded.map(|mut broken| broken.remove(&value.to_lowercase())) {
+						Err(error) | Ok(Some(error)) => return Err(error),
+						Ok(None) => value.to_string(),
+					},
+				}
+			};
+
+			options.gradient = parse_gradient(&expanded_value, options)?;
+		}
+		OptionType::Number => {
+			let number = match value.parse::<u16>() {
+				Ok(n) => n,
+				Err(_) => {
+					return Err(format!(
+						"Could not read argument for option: {}. Needs to be a positive number but found instead: \"{}\"",
+						color(option.name, Colors::Green),
+						color(value, Colors::Green)
+					));
+				}
+			};
+
+			match option.key {
+				"letter_spacing" => {
+					options.letter_spacing = number;
+				}
+				"line_height" => {
+					options.line_height = number;
+				}
+				"max_length" => {
+					options.max_length = number;
+				}
+				"debug_level" => {
+					options.debug_level = number;
+				}
+				"fps" => {
+					options.fps = number;
+				}
+				"speed" => {
+					options.speed = number;
+				}
+				_ => {}
+			}
+		}
+		OptionType::Bool => {
+			let flag = match value.to_lowercase().as_str() {
+				"true" => true,
+				"false" => false,
+				_ => {
+					return Err(format!(
+						"Could not read argument for option: {}. Needs to be true or false but found instead: \"{}\"",
+						color(option.name, Colors::Green),
+						color(value, Colors::Green)
+					));
+				}
+			};
+
+			match option.key {
+				"version" => {
+					options.version = flag;
+				}
+				"help" => {
+					options.help = flag;
+				}
+				"list_gradients" => {
+					options.list_gradients = flag;
+				}
+				"print_config" => {
+					options.print_config = flag;
+				}
+				"man" => {
+					options.man = flag;
+				}
+				"lenient" => {
+					options.lenient = flag;
+				}
+				"strict_contrast" => {
+					options.strict_contrast = flag;
+				}
+				"html_fragment" => {
+					options.html_fragment = flag;
+				}
+				"html_css" => {
+					options.html_css = flag;
+				}
+				"trim" => {
+					options.trim = flag;
+				}
+				"spaceless" => {
+					options.spaceless = flag;
+				}
+				"marquee" => {
+					options.marquee = flag;
+				}
+				"independent_gradient" => {
+					options.independent_gradient = flag;
+				}
+				"transition_gradient" => {
+					options.transition_gradient = flag;
+				}
+				"shadow" => {
+					options.shadow = flag;
+				}
+				"debug" => {
+					options.debug = flag;
+				}
+				_ => {}
+			}
+		}
+		OptionType::Env => {
+			options.env = parse_env(value, options)?;
+		}
+		OptionType::ColorMode => {
+			options.color_mode = parse_color_mode(value, options)?;
+		}
+		OptionType::Seed => {
+			options.seed = match value.parse::<u64>() {
+				Ok(n) => Some(n),
+				Err(_) => {
+					return Err(format!(
+						"Could not read argument for option: {}. Needs to be a positive number but found instead: \"{}\"",
+						color(option.name, Colors::Green),
+						color(value, Colors::Green)
+					));
+				}
+			};
+		}
+		OptionType::CandyMode => {
+			options.candy_mode = parse_candy_mode(value, options)?;
+		}
+		OptionType::Theme => {
+			load_theme(value, options)?.apply(options)?;
+		}
+		OptionType::TerminalBg => {
+			options.terminal_bg = parse_terminal_bg(value, options)?;
+		}
+		OptionType::Simulate => {
+			options.simulate = parse_simulate(value, options)?;
+		}
+		OptionType::Output => {
+			options.output = Some(value.to_string());
+		}
+		OptionType::OutputFormat => {
+			options.output_format = parse_output_format(value, options)?;
+		}
+		OptionType::Animate => {
+			options.animate = Some(parse_effect(value, options)?);
+		}
+		OptionType::Duration => {
+			let duration = parse_duration(value, options)?;
+			match option.key {
+				"countdown" => options.countdown = Some(duration),
+				_ => options.duration = Some(duration),
+			}
+		}
+		OptionType::Clock => {
+			options.clock = Some(value.to_string());
+		}
+		OptionType::Shell => {
+			options.completions = Some(parse_shell(value, options)?);
+		}
+		OptionType::Border => {
+			options.border = parse_border(value, options)?;
+		}
+		OptionType::BorderColor => {
+			options.border_color = parse_colors(value, options)?;
+		}
+		OptionType::Padding => {
+			options.padding = parse_padding(value, options)?;
+		}
+		OptionType::ShadowOffset => {
+			options.shadow_offset = parse_shadow_offset(value, options)?;
+		}
+		OptionType::ShadowChar => {
+			options.shadow_char = parse_shadow_char(value, options)?;
+		}
+		OptionType::ShadowColor => {
+			options.shadow_color = parse_shadow_color(value, options)?;
+		}
+	}
+
+	Ok(())
+}
+
+/// Find a theme by name or path
+///
+/// Names are looked up in the built-in themes and the `themes` folder inside [`get_config_dir()`].
+/// A value ending in `.toml` or `.json` is read as a theme file.
+///
+/// ```rust
+/// extern crate cfonts;
+///
+/// use cfonts::Options;
+/// use cfonts::args::load_theme;
+///
+/// let options = Options::default();
+/// assert_eq!(load_theme("pride", &options).unwrap().transition_gradient, Some(true));
+/// assert!(load_theme("no-such-theme", &options).is_err());
+/// ```
+pub fn load_theme(value: &str, options: &Options) -> Result<Theme, String> {
+	d("args::load_theme()", 3, Dt::Head, options, &mut std::io::stdout());
+	d(&format!("args::load_theme()\nvalue:{:?}", value), 3, Dt::Log, options, &mut std::io::stdout());
+
+	let mut registry = ThemeRegistry::default();
+
+	let name = if value.ends_with(".toml") || value.ends_with(".json") {
+		registry.load_file(Path::new(value), options)?
+	} else {
+		let name = value.to_lowercase();
+		if let Some(dir) = get_config_dir() {
+			// a broken theme file is only an error when it's the one we're after
+			if let Some(error) = registry.load_dir(&dir.join("themes"), options).remove(&name) {
+				return Err(error);
+			}
+		}
+		name
+	};
+
+	match registry.get(&name) {
+		Some(theme) => Ok(theme.clone()),
+		None => Err(format!(
+			"The theme \"{}\" could not be found.\nAllowed options are: {}",
+			color(value, Colors::Green),
+			color(&registry.list().join(", "), Colors::Green)
+		)),
+	}
+}
+
+/// Parse the value of the font option into [`Fonts`]
+///
+/// ```rust
+/// extern crate cfonts;
+///
+/// use cfonts::{Fonts, Options};
+/// use cfonts::args::parse_font;
+///
+/// let options = Options::default();
+/// assert_eq!(parse_font("simple3d", &options), Ok(Fonts::FontSimple3d));
+/// assert_eq!(parse_font("TINY", &options), Ok(Fonts::FontTiny));
+/// assert!(parse_font("unknown", &options).is_err());
+/// ```
+pub fn parse_font(value: &str, options: &Options) -> Result<Fonts, String> {
+	d("args::parse_font()", 5, Dt::Head, options, &mut std::io::stdout());
+	d(&format!("args::parse_font()\nvalue:{:?}", value), 5, Dt::Log, options, &mut std::io::stdout());
+
+	match value.to_lowercase().as_str() {
+		"console" => Ok(Fonts::FontConsole),
+		"block" => Ok(Fonts::FontBlock),
+		"simpleblock" => Ok(Fonts::FontSimpleBlock),
+		"simple" => Ok(Fonts::FontSimple),
+		"3d" => Ok(Fonts::Font3d),
+		"simple3d" => Ok(Fonts::FontSimple3d),
+		"chrome" => Ok(Fonts::FontChrome),
+		"huge" => Ok(Fonts::FontHuge),
+		"shade" => Ok(Fonts::FontShade),
+		"slick" => Ok(Fonts::FontSlick),
+		"grid" => Ok(Fonts::FontGrid),
+		"pallet" => Ok(Fonts::FontPallet),
+		"tiny" => Ok(Fonts::FontTiny),
+		unknown => Err(format!(
+			"The font \"{}\" is not supported.{}\nAllowed options are: {}",
+			color(unknown, Colors::Green),
+			did_you_mean(unknown, &Fonts::list()),
+			color(&Fonts::list(), Colors::Green)
+		)),
+	}
+}
+
+/// Parse the value of the align option into [`Align`]
+///
+/// ```rust
+/// extern crate cfonts;
+///
+/// use cfonts::{Align, Options};
+/// use cfonts::args::parse_align;
+///
+/// let options = Options::default();
+/// assert_eq!(parse_align("center", &options), Ok(Align::Center));
+/// assert!(parse_align("middle", &options).is_err());
+/// ```
+pub fn parse_align(value: &str, options: &Options) -> Result<Align, String> {
+	d("args::parse_align()", 5, Dt::Head, options, &mut std::io::stdout());
+	d(&format!("args::parse_align()\nvalue:{:?}", value), 5, Dt::Log, options, &mut std::io::stdout());
+
+	match value.to_lowercase().as_str() {
+		"left" => Ok(Align::Left),
+		"center" => Ok(Align::Center),
+		"right" => Ok(Align::Right),
+		"top" => Ok(Align::Top),
+		"bottom" => Ok(Align::Bottom),
+		unknown => Err(format!(
+			"The alignment option \"{}\" is not supported.{}\nAllowed options are: {}",
+			color(unknown, Colors::Green),
+			did_you_mean(unknown, &Align::list()),
+			color(&Align::list(), Colors::Green)
+		)),
+	}
+}
+
+/// Parse the comma separated value of the colors option into a vector of [`Colors`]
+///
+/// ```rust
+/// extern crate cfonts;
+///
+/// use cfonts::{Colors, Options, Rgb};
+/// use cfonts::args::parse_colors;
+///
+/// let options = Options::default();
+/// assert_eq!(parse_colors("red,#ff8800", &options), Ok(vec![Colors::Red, Colors::Rgb(Rgb::Val(255, 136, 0))]));
+/// assert!(parse_colors("red,unknown", &options).is_err());
+/// ```
+pub fn parse_colors(value: &str, options: &Options) -> Result<Vec<Colors>, String> {
+	d("args::parse_colors()", 5, Dt::Head, options, &mut std::io::stdout());
+	d(&format!("args::parse_colors()\nvalue:{:?}", value), 5, Dt::Log, options, &mut std::io::stdout());
+
+	value
+		.to_lowercase()
+		.as_str()
+		.split(',')
+		.map(|this_color| match this_color {
+			"system" => Ok(Colors::System),
+			"black" => Ok(Colors::Black),
+			"red" => Ok(Colors::Red),
+			"green" => Ok(Colors::Green),
+			"yellow" => Ok(Colors::Yellow),
+			"blue" => Ok(Colors::Blue),
+			"magenta" => Ok(Colors::Magenta),
+			"cyan" => Ok(Colors::Cyan),
+			"white" => Ok(Colors::White),
+			"gray" => Ok(Colors::Gray),
+			"grey" => Ok(Colors::Gray),
+			"redbright" => Ok(Colors::RedBright),
+			"greenbright" => Ok(Colors::GreenBright),
+			"yellowbright" => Ok(Colors::YellowBright),
+			"bluebright" => Ok(Colors::BlueBright),
+			"magentabright" => Ok(Colors::MagentaBright),
+			"cyanbright" => Ok(Colors::CyanBright),
+			"whitebright" => Ok(Colors::WhiteBright),
+			"candy" => Ok(Colors::Candy),
+			unknown => {
+				if unknown.starts_with('#') && unknown.len() > 2 {
+					Ok(Colors::Rgb(hex2rgb(unknown, options)))
+				} else {
+					Err(format!(
+						"The color \"{}\" is not supported.{}\nAllowed options are: {}",
+						color(unknown, Colors::Green),
+						did_you_mean(unknown, &Colors::list()),
+						color(&Colors::list(), Colors::Green)
+					))
+				}
+			}
+		})
+		.collect::<Result<Vec<Colors>, String>>()
+}
+
+/// Parse the value of the background option into [`BgColors`]
+///
+/// ```rust
+/// extern crate cfonts;
+///
+/// use cfonts::{BgColors, Options, Rgb};
+/// use cfonts::args::parse_bg_color;
+///
+/// let options = Options::default();
+/// assert_eq!(parse_bg_color("blueBright", &options), Ok(BgColors::BlueBright));
+/// assert_eq!(parse_bg_color("#f80", &options), Ok(BgColors::Rgb(Rgb::Val(255, 136, 0))));
+/// assert!(parse_bg_color("candy", &options).is_err());
+/// ```
+pub fn parse_bg_color(value: &str, options: &Options) -> Result<BgColors, String> {
+	d("args::parse_bg_color()", 5, Dt::Head, options, &mut std::io::stdout());
+	d(&format!("args::parse_bg_color()\nvalue:{:?}", value), 5, Dt::Log, options, &mut std::io::stdout());
+
+	match value.to_lowercase().as_str() {
+		"transparent" => Ok(BgColors::Transparent),
+		"black" => Ok(BgColors::Black),
+		"red" => Ok(BgColors::Red),
+		"green" => Ok(BgColors::Green),
+		"yellow" => Ok(BgColors::Yellow),
+		"blue" => Ok(BgColors::Blue),
+		"magenta" => Ok(BgColors::Magenta),
+		"cyan" => Ok(BgColors::Cyan),
+		"white" => Ok(BgColors::White),
+		"gray" => Ok(BgColors::Gray),
+		"grey" => Ok(BgColors::Gray),
+		"redbright" => Ok(BgColors::RedBright),
+		"greenbright" => Ok(BgColors::GreenBright),
+		"yellowbright" => Ok(BgColors::YellowBright),
+		"bluebright" => Ok(BgColors::BlueBright),
+		"magentabright" => Ok(BgColors::MagentaBright),
+		"cyanbright" => Ok(BgColors::CyanBright),
+		"whitebright" => Ok(BgColors::WhiteBright),
+		unknown => {
+			if unknown.starts_with('#') && unknown.len() > 2 {
+				Ok(BgColors::Rgb(hex2rgb(unknown, options)))
+			} else {
+				Err(format!(
+					"The background color \"{}\" is not supported.{}\nAllowed options are: {}",
+					color(unknown, Colors::Green),
+					did_you_mean(unknown, &BgColors::list()),
+					color(&BgColors::list(), Colors::Green)
+				))
+			}
+		}
+	}
+}
+
+/// Parse the comma separated value of the gradient option into a vector of hex colors
+///
+/// ```rust
+/// extern crate cfonts;
+///
+/// use cfonts::Options;
+/// use cfonts::args::parse_gradient;
+///
+/// let options = Options::default();
+/// assert_eq!(parse_gradient("red,#00f", &options), Ok(vec![String::from("#ff0000"), String::from("#0000ff")]));
+/// assert!(parse_gradient("red,candy", &options).is_err());
+/// ```
+pub fn parse_gradient(value: &str, options: &Options) -> Result<Vec<String>, String> {
+	d("args::parse_gradient()", 5, Dt::Head, options, &mut std::io::stdout());
+	d(&format!("args::parse_gradient()\nvalue:{:?}", value), 5, Dt::Log, options, &mut std::io::stdout());
+
+	value
+		.to_lowercase()
+		.split(',')
+		.map(|this_color| match this_color {
+			"black" => Ok(String::from("#000000")),
+			"red" => Ok(String::from("#ff0000")),
+			"green" => Ok(String::from("#00ff00")),
+			"blue" => Ok(String::from("#0000ff")),
+			"yellow" => Ok(String::from("#ffff00")),
+			"magenta" => Ok(String::from("#ff00ff")),
+			"cyan" => Ok(String::from("#00ffff")),
+			"white" => Ok(String::from("#ffffff")),
+			"gray" | "grey" => Ok(String::from("#808080")),
+			unknown => {
+				if unknown.starts_with('#') && unknown.len() > 2 {
+					// parsing hex round trip to make sure it's in a good format
+					Ok(rgb2hex(&hex2rgb(unknown, options), options))
+				} else {
+					Err(format!("The gradient color \"{}\" is not supported.\nAllowed options are: black, red, green, blue, yellow, magenta, cyan, white, gray, grey", color(unknown, Colors::Green)))
+				}
+			}
+		})
+		.collect::<Result<Vec<String>, String>>()
+}
+
+/// Make sure a gradient has enough colors for the kind of gradient it is
+///
+/// ```rust
+/// extern crate cfonts;
+///
+/// use cfonts::args::validate_gradient;
+///
+/// let two_colors = vec![String::from("#ff0000"), String::from("#0000ff")];
+/// assert!(validate_gradient(&two_colors, false).is_ok());
+/// assert!(validate_gradient(&two_colors, true).is_ok());
+/// assert!(validate_gradient(&two_colors[0..1], true).is_err());
+/// ```
+pub fn validate_gradient(gradient: &[String], is_transition: bool) -> Result<(), String> {
+	if is_transition && gradient.len() < 2 {
+		return Err(format!(
+			"You must specify at least two colors for transition gradients. You specified only \"{}\"",
+			color(&format!("{}", gradient.len()), Colors::Green)
+		));
+	}
+
+	if !is_transition && gradient.len() != 2 {
+		return Err(format!(
+			"You must specify two colors for a gradient. You specified \"{}\"",
+			color(&format!("{}", gradient.len()), Colors::Green)
+		));
+	}
+
+	Ok(())
+}
+
+/// Parse the value of the env option into [`Env`]
+///
+/// ```rust
+/// extern crate cfonts;
+///
+/// use cfonts::{Env, Options};
+/// use cfonts::args::parse_env;
+///
+/// let options = Options::default();
+/// assert_eq!(parse_env("node", &options), Ok(Env::Cli));
+/// assert_eq!(parse_env("browser", &options), Ok(Env::Browser));
+/// ```
+pub fn parse_env(value: &str, options: &Options) -> Result<Env, String> {
+	d("args::parse_env()", 5, Dt::Head, options, &mut std::io::stdout());
+	d(&format!("args::parse_env()\nvalue:{:?}", value), 5, Dt::Log, options, &mut std::io::stdout());
+
+	match value.to_lowercase().as_str() {
+		"node" | "cli" => Ok(Env::Cli),
+		"browser" => Ok(Env::Browser),
+		unknown => Err(format!(
+			"The env option \"{}\" is not supported.\nAllowed options are: {}",
+			color(unknown, Colors::Green),
+			color(&Env::list(), Colors::Green)
+		)),
+	}
+}
+
+/// Parse the value of the color mode option into [`ColorMode`]
+///
+/// ```rust
+/// extern crate cfonts;
+///
+/// use cfonts::{ColorMode, Options};
+/// use cfonts::args::parse_color_mode;
+///
+/// let options = Options::default();
+/// assert_eq!(parse_color_mode("none", &options), Ok(ColorMode::NoColor));
+/// assert_eq!(parse_color_mode("256", &options), Ok(ColorMode::Ansi256));
+/// ```
+pub fn parse_color_mode(value: &str, options: &Options) -> Result<ColorMode, String> {
+	d("args::parse_color_mode()", 5, Dt::Head, options, &mut std::io::stdout());
+	d(&format!("args::parse_color_mode()\nvalue:{:?}", value), 5, Dt::Log, options, &mut std::io::stdout());
+
+	match value.to_lowercase().as_str() {
+		"auto" => Ok(ColorMode::Auto),
+		"none" => Ok(ColorMode::NoColor),
+		"16" => Ok(ColorMode::Ansi16),
+		"256" => Ok(ColorMode::Ansi256),
+		"truecolor" => Ok(ColorMode::Truecolor),
+		unknown => Err(format!(
+			"The color mode \"{}\" is not supported.\nAllowed options are: {}",
+			color(unknown, Colors::Green),
+			color(&ColorMode::list(), Colors::Green)
+		)),
+	}
+}
+
+/// Parse the value of the candy mode option into [`CandyMode`]
+///
+/// ```rust
+/// extern crate cfonts;
+///
+/// use cfonts::{CandyMode, Options};
+/// use cfonts::args::parse_candy_mode;
+///
+/// let options = Options::default();
+/// assert_eq!(parse_candy_mode("glyph", &options), Ok(CandyMode::Glyph));
+/// assert!(parse_candy_mode("letter", &options).is_err());
+/// ```
+pub fn parse_candy_mode(value: &str, options: &Options) -> Result<CandyMode, String> {
+	d("args::parse_candy_mode()", 5, Dt::Head, options, &mut std::io::stdout());
+	d(&format!("args::parse_candy_mode()\nvalue:{:?}", value), 5, Dt::Log, options, &mut std::io::stdout());
+
+	match value.to_lowercase().as_str() {
+		"segment" => Ok(CandyMode::Segment),
+		"glyph" => Ok(CandyMode::Glyph),
+		"row" => Ok(CandyMode::Row),
+		"layer" => Ok(CandyMode::Layer),
+		unknown => Err(format!(
+			"The candy mode \"{}\" is not supported.\nAllowed options are: {}",
+			color(unknown, Colors::Green),
+			color(&CandyMode::list(), Colors::Green)
+		)),
+	}
+}
+
+/// Parse the value of the terminal background option into [`TerminalBg`]
+///
+/// ```rust
+/// extern crate cfonts;
+///
+/// use cfonts::{Options, TerminalBg};
+/// use cfonts::args::parse_terminal_bg;
+///
+/// let options = Options::default();
+/// assert_eq!(parse_terminal_bg("light", &options), Ok(TerminalBg::Light));
+/// assert!(parse_terminal_bg("grey", &options).is_err());
+/// ```
+pub fn parse_terminal_bg(value: &str, options: &Options) -> Result<TerminalBg, String> {
+	d("args::parse_terminal_bg()", 5, Dt::Head, options, &mut std::io::stdout());
+	d(&format!("args::parse_terminal_bg()\nvalue:{:?}", value), 5, Dt::Log, options, &mut std::io::stdout());
+
+	match value.to_lowercase().as_str() {
+		"auto" => Ok(TerminalBg::Auto),
+		"light" => Ok(TerminalBg::Light),
+		"dark" => Ok(TerminalBg::Dark),
+		unknown => Err(format!(
+			"The terminal background \"{}\" is not supported.\nAllowed options are: {}",
+			color(unknown, Colors::Green),
+			color(&TerminalBg::list(), Colors::Green)
+		)),
+	}
+}
+
+/// Parse the value of the simulate option into a [`Cvd`], `none` turns the simulation off
+///
+/// ```rust
+/// extern crate cfonts;
+///
+/// use cfonts::{Cvd, Options};
+/// use cfonts::args::parse_simulate;
+///
+/// let options = Options::default();
+/// assert_eq!(parse_simulate("protanopia", &options), Ok(Some(Cvd::Protanopia)));
+/// assert_eq!(parse_simulate("none", &options), Ok(None));
+/// assert!(parse_simulate("red", &options).is_err());
+/// ```
+pub fn parse_simulate(value: &str, options: &Options) -> Result<Option<Cvd>, String> {
+	d("args::parse_simulate()", 5, Dt::Head, options, &mut std::io::stdout());
+	d(&format!("args::parse_simulate()\nvalue:{:?}", value), 5, Dt::Log, options, &mut std::io::stdout());
+
+	match value.to_lowercase().as_str() {
+		"none" => Ok(None),
+		"protanopia" => Ok(Some(Cvd::Protanopia)),
+		"deuteranopia" => Ok(Some(Cvd::Deuteranopia)),
+		"tritanopia" => Ok(Some(Cvd::Tritanopia)),
+		"achromatopsia" => Ok(Some(Cvd::Achromatopsia)),
+		unknown => Err(format!(
+			"The simulation \"{}\" is not supported.\nAllowed options are: none, {}",
+			color(unknown, Colors::Green),
+			color(&Cvd::list(), Colors::Green)
+		)),
+	}
+}
+
+/// Parse the value of the output format option into [`OutputFormat`]
+///
+/// ```rust
+/// extern crate cfonts;
+///
+/// use cfonts::{Options, OutputFormat};
+/// use cfonts::args::parse_output_format;
+///
+/// let options = Options::default();
+/// assert_eq!(parse_output_format("html", &options), Ok(OutputFormat::Html));
+/// assert!(parse_output_format("xml", &options).is_err());
+/// ```
+pub fn parse_output_format(value: &str, options: &Options) -> Result<OutputFormat, String> {
+	d("args::parse_output_format()", 5, Dt::Head, options, &mut std::io::stdout());
+	d(&format!("args::parse_output_format()\nvalue:{:?}", value), 5, Dt::Log, options, &mut std::io::stdout());
+
+	match value.to_lowercase().as_str() {
+		"text" => Ok(OutputFormat::Text),
+		"html" => Ok(OutputFormat::Html),
+		"json" => Ok(OutputFormat::Json),
+		"plain" => Ok(OutputFormat::Plain),
+		unknown => Err(format!(
+			"The output format \"{}\" is not supported.\nAllowed options are: {}",
+			color(unknown, Colors::Green),
+			color(&OutputFormat::list(), Colors::Green)
+		)),
+	}
+}
+
+/// Parse the value of the animate option into an [`Effect`]
+///
+/// ```rust
+/// extern crate cfonts;
+///
+/// use cfonts::{Effect, Options};
+/// use cfonts::args::parse_effect;
+///
+/// let options = Options::default();
+/// assert_eq!(parse_effect("typewriter", &options), Ok(Effect::Typewriter));
+/// assert!(parse_effect("explode", &options).is_err());
+/// ```
+pub fn parse_effect(value: &str, options: &Options) -> Result<Effect, String> {
+	d("args::parse_effect()", 5, Dt::Head, options, &mut std::io::stdout());
+	d(&format!("args::parse_effect()\nvalue:{:?}", value), 5, Dt::Log, options, &mut std::io::stdout());
+
+	match value.to_lowercase().as_str() {
+		"typewriter" => Ok(Effect::Typewriter),
+		"lines" => Ok(Effect::Lines),
+		"wipe" => Ok(Effect::Wipe),
+		"cycle" => Ok(Effect::Cycle),
+		unknown => Err(format!(
+			"The animation \"{}\" is not supported.\nAllowed options are: {}",
+			color(unknown, Colors::Green),
+			color(&Effect::list(), Colors::Green)
+		)),
+	}
+}
+
+/// Parse the value of the completions option into a [`Shell`]
+///
+/// ```rust
+/// extern crate cfonts;
+///
+/// use cfonts::{Options, Shell};
+/// use cfonts::args::parse_shell;
+///
+/// let options = Options::default();
+/// assert_eq!(parse_shell("PowerShell", &options), Ok(Shell::Powershell));
+/// assert!(parse_shell("cmd", &options).is_err());
+/// ```
+pub fn parse_shell(value: &str, options: &Options) -> Result<Shell, String> {
+	d("args::parse_shell()", 5, Dt::Head, options, &mut std::io::stdout());
+	d(&format!("args::parse_shell()\nvalue:{:?}", value), 5, Dt::Log, options, &mut std::io::stdout());
+
+	match value.to_lowercase().as_str() {
+		"bash" => Ok(Shell::Bash),
+		"zsh" => Ok(Shell::Zsh),
+		"fish" => Ok(Shell::Fish),
+		"elvish" => Ok(Shell::Elvish),
+		"powershell" => Ok(Shell::Powershell),
+		unknown => Err(format!(
+			"The shell \"{}\" is not supported.\nAllowed options are: {}",
+			color(unknown, Colors::Green),
+			color(&Shell::list(), Colors::Green)
+		)),
+	}
+}
+
+/// Parse the value of the border option into a [`Border`], `none` turns the border off
+///
+/// ```rust
+/// extern crate cfonts;
+///
+/// use cfonts::{Border, Options};
+/// use cfonts::args::parse_border;
+///
+/// let options = Options::default();
+/// assert_eq!(parse_border("Rounded", &options), Ok(Some(Border::Rounded)));
+/// assert_eq!(parse_border("none", &options), Ok(None));
+/// assert!(parse_border("dotted", &options).is_err());
+/// ```
+pub fn parse_border(value: &str, options: &Options) -> Result<Option<Border>, String> {
+	d("args::parse_border()", 5, Dt::Head, options, &mut std::io::stdout());
+	d(&format!("args::parse_border()\nvalue:{:?}", value), 5, Dt::Log, options, &mut std::io::stdout());
+
+	match value.to_lowercase().as_str() {
+		"none" => Ok(None),
+		"single" => Ok(Some(Border::Single)),
+		"double" => Ok(Some(Border::Double)),
+		"rounded" => Ok(Some(Border::Rounded)),
+		"heavy" => Ok(Some(Border::Heavy)),
+		"ascii" => Ok(Some(Border::Ascii)),
+		unknown => Err(format!(
+			"The border \"{}\" is not supported.{}\nAllowed options are: {}",
+			color(unknown, Colors::Green),
+			did_you_mean(unknown, &Border::list()),
+			color(&format!("none, {}", Border::list()), Colors::Green)
+		)),
+	}
+}
+
+/// Parse the value of the padding option the same way CSS does
+///
+/// One number is used for all sides, two are the vertical and horizontal padding and four are top, right, bottom and
+/// left.
+///
+/// ```rust
+/// extern crate cfonts;
+///
+/// use cfonts::Options;
+/// use cfonts::args::parse_padding;
+///
+/// let options = Options::default();
+/// assert_eq!(parse_padding("1", &options), Ok([1, 1, 1, 1]));
+/// assert_eq!(parse_padding("1,2", &options), Ok([1, 2, 1, 2]));
+/// assert_eq!(parse_padding("1,2,3,4", &options), Ok([1, 2, 3, 4]));
+/// assert!(parse_padding("1,2,3", &options).is_err());
+/// ```
+pub fn parse_padding(value: &str, options: &Options) -> Result<[u16; 4], String> {
+	d("args::parse_padding()", 5, Dt::Head, options, &mut std::io::stdout());
+	d(&format!("args::parse_padding()\nvalue:{:?}", value), 5, Dt::Log, options, &mut std::io::stdout());
+
+	let numbers = value.split(',').map(|number| number.trim().parse::<u16>()).collect::<Result<Vec<u16>, _>>();
+
+	match numbers.as_deref() {
+		Ok([all]) => Ok([*all; 4]),
+		Ok([vertical, horizontal]) => Ok([*vertical, *horizontal, *vertical, *horizontal]),
+		Ok([top, right, bottom, left]) => Ok([*top, *right, *bottom, *left]),
+		_ => Err(format!(
+			"The padding \"{}\" is not supported.\nUse one, two or four positive numbers like: {}",
+			color(value, Colors::Green),
+			color("1,2", Colors::Green)
+		)),
+	}
+}
+
+/// Parse the value of the shadow offset option, one number moves the shadow right and down by the same amount
+///
+/// ```rust
+/// extern crate cfonts;
+///
+/// use cfonts::Options;
+/// use cfonts::args::parse_shadow_offset;
+///
+/// let options = Options::default();
+/// assert_eq!(parse_shadow_offset("2", &options), Ok([2, 2]));
+/// assert_eq!(parse_shadow_offset("2,1", &options), Ok([2, 1]));
+/// assert!(parse_shadow_offset("-1,1", &options).is_err());
+/// ```
+pub fn parse_shadow_offset(value: &str, options: &Options) -> Result<[u16; 2], String> {
+	d("args::parse_shadow_offset()", 5, Dt::Head, options, &mut std::io::stdout());
+	d(&format!("args::parse_shadow_offset()\nvalue:{:?}", value), 5, Dt::Log, options, &mut std::io::stdout());
+
+	let numbers = value.split(',').map(|number| number.trim().parse::<u16>()).collect::<Result<Vec<u16>, _>>();
+
+	match numbers.as_deref() {
+		Ok([both]) => Ok([*both; 2]),
+		Ok([right, down]) => Ok([*right, *down]),
+		_ => Err(format!(
+			"The shadow offset \"{}\" is not supported.\nUse one or two positive numbers like: {}",
+			color(value, Colors::Green),
+			color("2,1", Colors::Green)
+		)),
+	}
+}
+
+/// Parse the value of the shadow character option which has to be a single character
+///
+/// ```rust
+/// extern crate cfonts;
+///
+/// use cfonts::Options;
+/// use cfonts::args::parse_shadow_char;
+///
+/// let options = Options::default();
+/// assert_eq!(parse_shadow_char("▒", &options), Ok('▒'));
+/// assert!(parse_shadow_char("ab", &options).is_err());
+/// ```
+pub fn parse_shadow_char(value: &str, options: &Options) -> Result<char, String> {
+	d("args::parse_shadow_char()", 5, Dt::Head, options, &mut std::io::stdout());
+	d(&format!("args::parse_shadow_char()\nvalue:{:?}", value), 5, Dt::Log, options, &mut std::io::stdout());
+
+	let mut chars = value.chars();
+	match (chars.next(), chars.next()) {
+		(Some(character), None) if character != ' ' => Ok(character),
+		_ => Err(format!(
+			"The shadow character \"{}\" is not supported.\nUse a single character like: {}",
+			color(value, Colors::Green),
+			color("░", Colors::Green)
+		)),
+	}
+}
+
+/// Parse the value of the shadow color option which has to be a single color other than candy
+///
+/// ```rust
+/// extern crate cfonts;
+///
+/// use cfonts::{Colors, Options};
+/// use cfonts::args::parse_shadow_color;
+///
+/// let options = Options::default();
+/// assert_eq!(parse_shadow_color("blue", &options), Ok(Colors::Blue));
+/// assert!(parse_shadow_color("red,blue", &options).is_err());
+/// assert!(parse_shadow_color("candy", &options).is_err());
+/// ```
+pub fn parse_shadow_color(value: &str, options: &Options) -> Result<Colors, String> {
+	d("args::parse_shadow_color()", 5, Dt::Head, options, &mut std::io::stdout());
+	d(&format!("args::parse_shadow_color()\nvalue:{:?}", value), 5, Dt::Log, options, &mut std::io::stdout());
+
+	match parse_colors(value, options)?.as_slice() {
+		[shadow_color] if *shadow_color != Colors::Candy => Ok(shadow_color.clone()),
+		_ => Err(format!(
+			"The shadow color \"{}\" is not supported.\nUse a single color other than {} like: {}",
+			color(value, Colors::Green),
+			color("candy", Colors::Green),
+			color("gray", Colors::Green)
+		)),
+	}
+}
+
+/// Parse a duration like `500ms`, `2s`, `5m` or `1h` into milliseconds, numbers without a unit are seconds
+///
+/// ```rust
+/// extern crate cfonts;
+///
+/// use cfonts::Options;
+/// use cfonts::args::parse_duration;
+///
+/// let options = Options::default();
+/// assert_eq!(parse_duration("500ms", &options), Ok(500));
+/// assert_eq!(parse_duration("2s", &options), Ok(2_000));
+/// assert_eq!(parse_duration("5m", &options), Ok(300_000));
+/// assert_eq!(parse_duration("3", &options), Ok(3_000));
+/// assert!(parse_duration("soon", &options).is_err());
+/// ```
+pub fn parse_duration(value: &str, options: &Options) -> Result<u64, String> {
+	d("args::parse_duration()", 5, Dt::Head, options, &mut std::io::stdout());
+	d(&format!("args::parse_duration()\nvalue:{:?}", value), 5, Dt::Log, options, &mut std::io::stdout());
+
+	let value = value.trim().to_lowercase();
+	let split = value.find(|character: char| !character.is_ascii_digit()).unwrap_or(value.len());
+	let (number, unit) = value.split_at(split);
+	let factor = match unit {
+		"ms" => Some(1),
+		"" | "s" => Some(1_000),
+		"m" => Some(60_000),
+		"h" => Some(3_600_000),
+		_ => None,
+	};
+
+	match (number.parse::<u64>(), factor) {
+		(Ok(number), Some(factor)) => Ok(number.saturating_mul(factor)),
+		_ => Err(format!(
+			"The duration \"{}\" could not be read.\nUse a number followed by one of: {}",
+			color(&value, Colors::Green),
+			color("ms, s, m, h", Colors::Green)
+		)),
+	}
+}
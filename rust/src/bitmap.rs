@@ -0,0 +1,313 @@
+//! The contents of this module is all about the embedded bitmap font we use to draw the output into images
+use crate::config::Options;
+use crate::debug::{d, Dt};
+
+/// The width of a single character cell in pixel
+pub const CELL_WIDTH: usize = 12;
+
+/// The height of a single character cell in pixel
+pub const CELL_HEIGHT: usize = 24;
+
+/// A glyph is a grid of pixels the size of a cell where `true` means the pixel is painted with the foreground color
+pub type Glyph = [[bool; CELL_WIDTH]; CELL_HEIGHT];
+
+/// A 5x7 pixel font for all printable ASCII characters starting at the space character
+///
+/// Each row is stored as the five lowest bits of a byte with the leftmost pixel being the highest bit.
+/// We draw these at double the size into the cell.
+const ASCII_GLYPHS: [[u8; 7]; 95] = [
+	[0b00000, 0b00000, 0b00000, 0b00000, 0b00000, 0b00000, 0b00000], // ' '
+	[0b00100, 0b00100, 0b00100, 0b00100, 0b00100, 0b00000, 0b00100], // '!'
+	[0b01010, 0b01010, 0b01010, 0b00000, 0b00000, 0b00000, 0b00000], // '"'
+	[0b01010, 0b01010, 0b11111, 0b01010, 0b11111, 0b01010, 0b01010], // '#'
+	[0b00100, 0b01111, 0b10100, 0b01110, 0b00101, 0b11110, 0b00100], // '$'
+	[0b11000, 0b11001, 0b00010, 0b00100, 0b01000, 0b10011, 0b00011], // '%'
+	[0b01100, 0b10010, 0b10100, 0b01000, 0b10101, 0b10010, 0b01101], // '&'
+	[0b00100, 0b00100, 0b01000, 0b00000, 0b00000, 0b00000, 0b00000], // '\''
+	[0b00010, 0b00100, 0b01000, 0b01000, 0b01000, 0b00100, 0b00010], // '('
+	[0b01000, 0b00100, 0b00010, 0b00010, 0b00010, 0b00100, 0b01000], // ')'
+	[0b00000, 0b00100, 0b10101, 0b01110, 0b10101, 0b00100, 0b00000], // '*'
+	[0b00000, 0b00100, 0b00100, 0b11111, 0b00100, 0b00100, 0b00000], // '+'
+	[0b00000, 0b00000, 0b00000, 0b00000, 0b01100, 0b00100, 0b01000], // ','
+	[0b00000, 0b00000, 0b00000, 0b11111, 0b00000, 0b00000, 0b00000], // '-'
+	[0b00000, 0b00000, 0b00000, 0b00000, 0b00000, 0b01100, 0b01100], // '.'
+	[0b00000, 0b00001, 0b00010, 0b00100, 0b01000, 0b10000, 0b00000], // '/'
+	[0b01110, 0b10001, 0b10011, 0b10101, 0b11001, 0b10001, 0b01110], // '0'
+	[0b00100, 0b01100, 0b00100, 0b00100, 0b00100, 0b00100, 0b01110], // '1'
+	[0b01110, 0b10001, 0b00001, 0b00010, 0b00100, 0b01000, 0b11111], // '2'
+	[0b11111, 0b00010, 0b00100, 0b00010, 0b00001, 0b10001, 0b01110], // '3'
+	[0b00010, 0b00110, 0b01010, 0b10010, 0b11111, 0b00010, 0b00010], // '4'
+	[0b11111, 0b10000, 0b11110, 0b00001, 0b00001, 0b10001, 0b01110], // '5'
+	[0b00110, 0b01000, 0b10000, 0b11110, 0b10001, 0b10001, 0b01110], // '6'
+	[0b11111, 0b00001, 0b00010, 0b00100, 0b01000, 0b01000, 0b01000], // '7'
+	[0b01110, 0b10001, 0b10001, 0b01110, 0b10001, 0b10001, 0b01110], // '8'
+	[0b01110, 0b10001, 0b10001, 0b01111, 0b00001, 0b00010, 0b01100], // '9'
+	[0b00000, 0b01100, 0b01100, 0b00000, 0b01100, 0b01100, 0b00000], // ':'
+	[0b00000, 0b01100, 0b01100, 0b00000, 0b01100, 0b00100, 0b01000], // ';'
+	[0b00010, 0b00100, 0b01000, 0b10000, 0b01000, 0b00100, 0b00010], // '<'
+	[0b00000, 0b00000, 0b11111, 0b00000, 0b11111, 0b00000, 0b00000], // '='
+	[0b01000, 0b00100, 0b00010, 0b00001, 0b00010, 0b00100, 0b01000], // '>'
+	[0b01110, 0b10001, 0b00001, 0b00010, 0b00100, 0b00000, 0b00100], // '?'
+	[0b01110, 0b10001, 0b00001, 0b01101, 0b10101, 0b10101, 0b01110], // '@'
+	[0b01110, 0b10001, 0b10001, 0b11111, 0b10001, 0b10001, 0b10001], // 'A'
+	[0b11110, 0b10001, 0b10001, 0b11110, 0b10001, 0b10001, 0b11110], // 'B'
+	[0b01110, 0b10001, 0b10000, 0b10000, 0b10000, 0b10001, 0b01110], // 'C'
+	[0b11100, 0b10010, 0b10001, 0b10001, 0b10001, 0b10010, 0b11100], // 'D'
+	[0b11111, 0b10000, 0b10000, 0b11110, 0b10000, 0b10000, 0b11111], // 'E'
+	[0b11111, 0b10000, 0b10000, 0b11110, 0b10000, 0b10000, 0b10000], // 'F'
+	[0b01110, 0b10001, 0b10000, 0b10111, 0b10001, 0b10001, 0b01111], // 'G'
+	[0b10001, 0b10001, 0b10001, 0b11111, 0b10001, 0b10001, 0b10001], // 'H'
+	[0b01110, 0b00100, 0b00100, 0b00100, 0b00100, 0b00100, 0b01110], // 'I'
+	[0b00111, 0b00010, 0b00010, 0b00010, 0b00010, 0b10010, 0b01100], // 'J'
+	[0b10001, 0b10010, 0b10100, 0b11000, 0b10100, 0b10010, 0b10001], // 'K'
+	[0b10000, 0b10000, 0b10000, 0b10000, 0b10000, 0b10000, 0b11111], // 'L'
+	[0b10001, 0b11011, 0b10101, 0b10101, 0b10001, 0b10001, 0b10001], // 'M'
+	[0b10001, 0b10001, 0b11001, 0b10101, 0b10011, 0b10001, 0b10001], // 'N'
+	[0b01110, 0b10001, 0b10001, 0b10001, 0b10001, 0b10001, 0b01110], // 'O'
+	[0b11110, 0b10001, 0b10001, 0b11110, 0b10000, 0b10000, 0b10000], // 'P'
+	[0b01110, 0b10001, 0b10001, 0b10001, 0b10101, 0b10010, 0b01101], // 'Q'
+	[0b11110, 0b10001, 0b10001, 0b11110, 0b10100, 0b10010, 0b10001], // 'R'
+	[0b01111, 0b10000, 0b10000, 0b01110, 0b00001, 0b00001, 0b11110], // 'S'
+	[0b11111, 0b00100, 0b00100, 0b00100, 0b00100, 0b00100, 0b00100], // 'T'
+	[0b10001, 0b10001, 0b10001, 0b10001, 0b10001, 0b10001, 0b01110], // 'U'
+	[0b10001, 0b10001, 0b10001, 0b10001, 0b10001, 0b01010, 0b00100], // 'V'
+	[0b10001, 0b10001, 0b10001, 0b10101, 0b10101, 0b10101, 0b01010], // 'W'
+	[0b10001, 0b10001, 0b01010, 0b00100, 0b01010, 0b10001, 0b10001], // 'X'
+	[0b10001, 0b10001, 0b01010, 0b00100, 0b00100, 0b00100, 0b00100], // 'Y'
+	[0b11111, 0b00001, 0b00010, 0b00100, 0b01000, 0b10000, 0b11111], // 'Z'
+	[0b01110, 0b01000, 0b01000, 0b01000, 0b01000, 0b01000, 0b01110], // '['
+	[0b00000, 0b10000, 0b01000, 0b00100, 0b00010, 0b00001, 0b00000], // '\\'
+	[0b01110, 0b00010, 0b00010, 0b00010, 0b00010, 0b00010, 0b01110], // ']'
+	[0b00100, 0b01010, 0b10001, 0b00000, 0b00000, 0b00000, 0b00000], // '^'
+	[0b00000, 0b00000, 0b00000, 0b00000, 0b00000, 0b00000, 0b11111], // '_'
+	[0b01000, 0b00100, 0b00010, 0b00000, 0b00000, 0b00000, 0b00000], // '`'
+	[0b00000, 0b00000, 0b01110, 0b00001, 0b01111, 0b10001, 0b01111], // 'a'
+	[0b10000, 0b10000, 0b10110, 0b11001, 0b10001, 0b10001, 0b11110], // 'b'
+	[0b00000, 0b00000, 0b01110, 0b10000, 0b10000, 0b10001, 0b01110], // 'c'
+	[0b00001, 0b00001, 0b01101, 0b10011, 0b10001, 0b10001, 0b01111], // 'd'
+	[0b00000, 0b00000, 0b01110, 0b10001, 0b11111, 0b10000, 0b01110], // 'e'
+	[0b00110, 0b01001, 0b01000, 0b11100, 0b01000, 0b01000, 0b01000], // 'f'
+	[0b00000, 0b01111, 0b10001, 0b10001, 0b01111, 0b00001, 0b01110], // 'g'
+	[0b10000, 0b10000, 0b10110, 0b11001, 0b10001, 0b10001, 0b10001], // 'h'
+	[0b00100, 0b00000, 0b01100, 0b00100, 0b00100, 0b00100, 0b01110], // 'i'
+	[0b00010, 0b00000, 0b00110, 0b00010, 0b00010, 0b10010, 0b01100], // 'j'
+	[0b10000, 0b10000, 0b10010, 0b10100, 0b11000, 0b10100, 0b10010], // 'k'
+	[0b01100, 0b00100, 0b00100, 0b00100, 0b00100, 0b00100, 0b01110], // 'l'
+	[0b00000, 0b00000, 0b11010, 0b10101, 0b10101, 0b10001, 0b10001], // 'm'
+	[0b00000, 0b00000, 0b10110, 0b11001, 0b10001, 0b10001, 0b10001], // 'n'
+	[0b00000, 0b00000, 0b01110, 0b10001, 0b10001, 0b10001, 0b01110], // 'o'
+	[0b00000, 0b00000, 0b11110, 0b10001, 0b11110, 0b10000, 0b10000], // 'p'
+	[0b00000, 0b00000, 0b01101, 0b10011, 0b01111, 0b00001, 0b00001], // 'q'
+	[0b00000, 0b00000, 0b10110, 0b11001, 0b10000, 0b10000, 0b10000], // 'r'
+	[0b00000, 0b00000, 0b01110, 0b10000, 0b01110, 0b00001, 0b11110], // 's'
+	[0b01000, 0b01000, 0b11100, 0b01000, 0b01000, 0b01001, 0b00110], // 't'
+	[0b00000, 0b00000, 0b10001, 0b10001, 0b10001, 0b10011, 0b01101], // 'u'
+	[0b00000, 0b00000, 0b10001, 0b10001, 0b10001, 0b01010, 0b00100], // 'v'
+	[0b00000, 0b00000, 0b10001, 0b10001, 0b10101, 0b10101, 0b01010], // 'w'
+	[0b00000, 0b00000, 0b10001, 0b01010, 0b00100, 0b01010, 0b10001], // 'x'
+	[0b00000, 0b00000, 0b10001, 0b10001, 0b01111, 0b00001, 0b01110], // 'y'
+	[0b00000, 0b00000, 0b11111, 0b00010, 0b00100, 0b01000, 0b11111], // 'z'
+	[0b00010, 0b00100, 0b00100, 0b01000, 0b00100, 0b00100, 0b00010], // '{'
+	[0b00100, 0b00100, 0b00100, 0b00100, 0b00100, 0b00100, 0b00100], // '|'
+	[0b01000, 0b00100, 0b00100, 0b00010, 0b00100, 0b00100, 0b01000], // '}'
+	[0b00000, 0b00000, 0b01000, 0b10101, 0b00010, 0b00000, 0b00000], // '~'
+];
+
+/// The weight of a box drawing line
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Line {
+	None,
+	Light,
+	Heavy,
+	Double,
+}
+
+/// The four arms of a box drawing character in the order up, right, down, left
+fn box_arms(character: char) -> Option<[Line; 4]> {
+	use Line::{Double as D, Heavy as H, Light as L, None as N};
+
+	let arms = match character {
+		'─' => [N, L, N, L],
+		'│' => [L, N, L, N],
+		'┌' | '╭' => [N, L, L, N],
+		'┐' | '╮' => [N, N, L, L],
+		'└' | '╰' => [L, L, N, N],
+		'┘' | '╯' => [L, N, N, L],
+		'├' => [L, L, L, N],
+		'┤' => [L, N, L, L],
+		'┬' => [N, L, L, L],
+		'┴' => [L, L, N, L],
+		'┼' => [L, L, L, L],
+		'━' => [N, H, N, H],
+		'┃' => [H, N, H, N],
+		'┏' => [N, H, H, N],
+		'┓' => [N, N, H, H],
+		'┗' => [H, H, N, N],
+		'┛' => [H, N, N, H],
+		'┣' => [H, H, H, N],
+		'┫' => [H, N, H, H],
+		'┳' => [N, H, H, H],
+		'┻' => [H, H, N, H],
+		'╋' => [H, H, H, H],
+		'═' => [N, D, N, D],
+		'║' => [D, N, D, N],
+		'╔' => [N, D, D, N],
+		'╗' => [N, N, D, D],
+		'╚' => [D, D, N, N],
+		'╝' => [D, N, N, D],
+		'╠' => [D, D, D, N],
+		'╣' => [D, N, D, D],
+		'╦' => [N, D, D, D],
+		'╩' => [D, D, N, D],
+		'╬' => [D, D, D, D],
+		_ => return None,
+	};
+
+	Some(arms)
+}
+
+/// Set all pixels within a rectangle of a glyph to a value
+fn fill(glyph: &mut Glyph, x: std::ops::Range<usize>, y: std::ops::Range<usize>, value: bool) {
+	for row in &mut glyph[y] {
+		for pixel in &mut row[x.clone()] {
+			*pixel = value;
+		}
+	}
+}
+
+/// Paint a line from the center of the cell to one of its edges
+fn stroke(glyph: &mut Glyph, arm: usize, half: usize, value: bool) {
+	let center_x = CELL_WIDTH / 2;
+	let center_y = CELL_HEIGHT / 2;
+
+	match arm {
+		0 => fill(glyph, center_x - half..center_x + half, 0..center_y + half, value),
+		1 => fill(glyph, center_x - half..CELL_WIDTH, center_y - half..center_y + half, value),
+		2 => fill(glyph, center_x - half..center_x + half, center_y - half..CELL_HEIGHT, value),
+		_ => fill(glyph, 0..center_x + half, center_y - half..center_y + half, value),
+	}
+}
+
+/// Draw a box drawing character from its arms
+///
+/// Double lines are drawn as a wide line with a thin line cut out of it which gives us correct corners and crossings.
+fn draw_box(glyph: &mut Glyph, arms: [Line; 4]) {
+	for (arm, line) in arms.iter().enumerate() {
+		match line {
+			Line::None => {}
+			Line::Light => stroke(glyph, arm, 1, true),
+			Line::Heavy => stroke(glyph, arm, 2, true),
+			Line::Double => stroke(glyph, arm, 3, true),
+		}
+	}
+
+	for (arm, line) in arms.iter().enumerate() {
+		if *line == Line::Double {
+			stroke(glyph, arm, 1, false);
+		}
+	}
+}
+
+/// Draw a block element from its four quadrants in the order upper left, upper right, lower left, lower right
+fn draw_quadrants(glyph: &mut Glyph, quadrants: [bool; 4]) {
+	let half_x = CELL_WIDTH / 2;
+	let half_y = CELL_HEIGHT / 2;
+
+	for (index, painted) in quadrants.iter().enumerate() {
+		if *painted {
+			let x = if index % 2 == 0 { 0..half_x } else { half_x..CELL_WIDTH };
+			let y = if index < 2 { 0..half_y } else { half_y..CELL_HEIGHT };
+			fill(glyph, x, y, true);
+		}
+	}
+}
+
+/// Return the glyph for a character
+///
+/// Characters we don't have a glyph for are drawn as an empty box.
+///
+/// ```rust
+/// extern crate cfonts;
+///
+/// use cfonts::Options;
+/// use cfonts::bitmap::{get_glyph, CELL_HEIGHT, CELL_WIDTH};
+///
+/// let options = Options::default();
+///
+/// assert_eq!(get_glyph(' ', &options), [[false; CELL_WIDTH]; CELL_HEIGHT]);
+/// assert_eq!(get_glyph('█', &options), [[true; CELL_WIDTH]; CELL_HEIGHT]);
+/// ```
+pub fn get_glyph(character: char, options: &Options) -> Glyph {
+	d(&format!("bitmap::get_glyph({:?})", character), 5, Dt::Log, options, &mut std::io::stdout());
+
+	let mut glyph = [[false; CELL_WIDTH]; CELL_HEIGHT];
+
+	if let Some(arms) = box_arms(character) {
+		draw_box(&mut glyph, arms);
+		return glyph;
+	}
+
+	match character {
+		' '..='~' => {
+			let rows = ASCII_GLYPHS[character as usize - ' ' as usize];
+			for (y, row) in rows.iter().enumerate() {
+				for x in 0..5 {
+					if row & (0b10000 >> x) != 0 {
+						// we draw each pixel as a 2x2 square and center the character within the cell
+						fill(&mut glyph, 1 + x * 2..3 + x * 2, 4 + y * 2..6 + y * 2, true);
+					}
+				}
+			}
+		}
+		'·' => fill(&mut glyph, 5..7, 11..13, true),
+		'█' => fill(&mut glyph, 0..CELL_WIDTH, 0..CELL_HEIGHT, true),
+		'▀' => fill(&mut glyph, 0..CELL_WIDTH, 0..CELL_HEIGHT / 2, true),
+		'▄' => fill(&mut glyph, 0..CELL_WIDTH, CELL_HEIGHT / 2..CELL_HEIGHT, true),
+		'▃' => fill(&mut glyph, 0..CELL_WIDTH, CELL_HEIGHT * 5 / 8..CELL_HEIGHT, true),
+		'▌' => fill(&mut glyph, 0..CELL_WIDTH / 2, 0..CELL_HEIGHT, true),
+		'▐' => fill(&mut glyph, CELL_WIDTH / 2..CELL_WIDTH, 0..CELL_HEIGHT, true),
+		'▘' => draw_quadrants(&mut glyph, [true, false, false, false]),
+		'▝' => draw_quadrants(&mut glyph, [false, true, false, false]),
+		'▖' => draw_quadrants(&mut glyph, [false, false, true, false]),
+		'▗' => draw_quadrants(&mut glyph, [false, false, false, true]),
+		'▚' => draw_quadrants(&mut glyph, [true, false, false, true]),
+		'▞' => draw_quadrants(&mut glyph, [false, true, true, false]),
+		'▙' => draw_quadrants(&mut glyph, [true, false, true, true]),
+		'▛' => draw_quadrants(&mut glyph, [true, true, true, false]),
+		'▜' => draw_quadrants(&mut glyph, [true, true, false, true]),
+		'▟' => draw_quadrants(&mut glyph, [false, true, true, true]),
+		'░' | '▒' | '▓' => {
+			for (y, row) in glyph.iter_mut().enumerate() {
+				for (x, pixel) in row.iter_mut().enumerate() {
+					*pixel = match character {
+						'░' => x % 2 == 0 && y % 2 == 0,
+						'▒' => (x + y) % 2 == 0,
+						_ => !(x % 2 == 1 && y % 2 == 1),
+					};
+				}
+			}
+		}
+		'╱' => {
+			for (y, row) in glyph.iter_mut().enumerate() {
+				let x = (CELL_HEIGHT - 1 - y) * CELL_WIDTH / CELL_HEIGHT;
+				row[x] = true;
+				row[(x + 1).min(CELL_WIDTH - 1)] = true;
+			}
+		}
+		'╲' => {
+			for (y, row) in glyph.iter_mut().enumerate() {
+				let x = y * CELL_WIDTH / CELL_HEIGHT;
+				row[x] = true;
+				row[(x + 1).min(CELL_WIDTH - 1)] = true;
+			}
+		}
+		_ => {
+			fill(&mut glyph, 2..CELL_WIDTH - 2, 4..6, true);
+			fill(&mut glyph, 2..CELL_WIDTH - 2, CELL_HEIGHT - 6..CELL_HEIGHT - 4, true);
+			fill(&mut glyph, 2..4, 4..CELL_HEIGHT - 4, true);
+			fill(&mut glyph, CELL_WIDTH - 4..CELL_WIDTH - 2, 4..CELL_HEIGHT - 4, true);
+		}
+	}
+
+	glyph
+}
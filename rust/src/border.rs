@@ -0,0 +1,285 @@
+//! The contents of this module is all about drawing a frame around our output
+use crate::color::{color2hex, get_background_color_for, get_foreground_color_for, hex2rgb};
+use crate::config::{Align, BgColors, Border, Colors, Env, Options};
+use crate::debug::{d, Dt};
+use crate::gradient::get_multiple_transition_colors;
+use crate::layout::get_width;
+
+/// The characters a [`Border`] is drawn with
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BorderChars {
+	/// The top left corner
+	pub top_left: char,
+	/// The top right corner
+	pub top_right: char,
+	/// The bottom left corner
+	pub bottom_left: char,
+	/// The bottom right corner
+	pub bottom_right: char,
+	/// The top and bottom edge
+	pub horizontal: char,
+	/// The left and right edge
+	pub vertical: char,
+}
+
+/// Get the characters to draw a [`Border`] with
+///
+/// ```rust
+/// extern crate cfonts;
+///
+/// use cfonts::Border;
+/// use cfonts::border::get_border_chars;
+///
+/// let chars = get_border_chars(&Border::Rounded);
+/// assert_eq!(chars.top_left, '╭');
+/// assert_eq!(chars.horizontal, '─');
+/// assert_eq!(get_border_chars(&Border::Ascii).bottom_right, '+');
+/// ```
+pub fn get_border_chars(border: &Border) -> BorderChars {
+	let [top_left, top_right, bottom_left, bottom_right, horizontal, vertical] = match border {
+		Border::Single => ['┌', '┐', '└', '┘', '─', '│'],
+		Border::Double => ['╔', '╗', '╚', '╝', '═', '║'],
+		Border::Rounded => ['╭', '╮', '╰', '╯', '─', '│'],
+		Border::Heavy => ['┏', '┓', '┗', '┛', '━', '┃'],
+		Border::Ascii => ['+', '+', '+', '+', '-', '|'],
+	};
+
+	BorderChars {
+		top_left,
+		top_right,
+		bottom_left,
+		bottom_right,
+		horizontal,
+		vertical,
+	}
+}
+
+/// Get the color of each column of a frame that is `width` columns wide
+///
+/// More than one color in the `border_color` option is painted as a gradient from left to right.
+///
+/// ```rust
+/// extern crate cfonts;
+///
+/// use cfonts::{Colors, Options, Rgb};
+/// use cfonts::border::get_border_colors;
+///
+/// let mut options = Options::default();
+/// options.border_color = vec![Colors::Red];
+/// assert_eq!(get_border_colors(2, &options), vec![Colors::Red, Colors::Red]);
+///
+/// options.border_color = vec![Colors::Rgb(Rgb::Val(255, 0, 0)), Colors::Rgb(Rgb::Val(0, 0, 255))];
+/// assert_eq!(
+///     get_border_colors(3, &options),
+///     vec![
+///         Colors::Rgb(Rgb::Val(255, 0, 0)),
+///         Colors::Rgb(Rgb::Val(127, 0, 127)),
+///         Colors::Rgb(Rgb::Val(0, 0, 255)),
+///     ]
+/// );
+/// ```
+pub fn get_border_colors(width: usize, options: &Options) -> Vec<Colors> {
+	match options.border_color.len() {
+		0 | 1 => vec![options.border_color.first().cloned().unwrap_or(Colors::System); width],
+		_ => {
+			let colors = options.border_color.iter().map(|color| color2hex(color, options)).collect::<Vec<String>>();
+			get_multiple_transition_colors(&colors, width, options)
+				.iter()
+				.map(|hex| Colors::Rgb(hex2rgb(hex, options)))
+				.collect()
+		}
+	}
+}
+
+/// Paint each character of a part of the frame in the color of its column, the last color is used for the rest
+fn paint(text: &str, colors: &[Colors], options: &Options) -> String {
+	let mut runs: Vec<(&Colors, String)> = Vec::new();
+	for (index, character) in text.chars().enumerate() {
+		let color = &colors[index.min(colors.len() - 1)];
+		match runs.last_mut() {
+			Some((last, run)) if *last == color => run.push(character),
+			_ => runs.push((color, character.to_string())),
+		}
+	}
+
+	runs
+		.iter()
+		.map(|(color, run)| {
+			let (start, end) = match (&options.env, color) {
+				(_, Colors::System) => (String::new(), String::new()),
+				(Env::Cli, color) => get_foreground_color_for(color, options),
+				(Env::Browser, color) => {
+					(format!("<span style=\"color:{}\">", color2hex(color, options)), String::from("</span>"))
+				}
+			};
+			format!("{}{}{}", start, run, end)
+		})
+		.collect()
+}
+
+/// Split a row into its markup and the visible characters between it, the visible characters are flagged `true`
+fn split_markup(row: &str, options: &Options) -> Vec<(String, bool)> {
+	let mut parts = Vec::new();
+	let mut rest = row;
+
+	while let Some(character) = rest.chars().next() {
+		let markup_length = if let Some(sequence) = rest.strip_prefix("\x1b[") {
+			// a control sequence ends with its first letter
+			sequence.find(|next: char| next.is_ascii_alphabetic()).map(|end| end + 3)
+		} else if options.env == Env::Browser && character == '<' {
+			if rest.starts_with("<span style=\"") {
+				rest.find("\">").map(|end| end + 2)
+			} else {
+				["</span>", "<br>"].iter().find(|tag| rest.starts_with(**tag)).map(|tag| tag.len())
+			}
+		} else {
+			None
+		};
+
+		let length = markup_length.unwrap_or(character.len_utf8()).min(rest.len());
+		parts.push((rest[..length].to_string(), markup_length.is_none()));
+		rest = &rest[length..];
+	}
+
+	parts
+}
+
+/// Remove the blank columns all rows share on the left and the trailing blanks of each row
+///
+/// Fonts add letter spacing around the text which would otherwise end up as uneven padding inside a frame.
+/// Colors and other markup are kept. Returns the trimmed rows and the visible width of the widest one.
+///
+/// ```rust
+/// extern crate cfonts;
+///
+/// use cfonts::Options;
+/// use cfonts::border::trim_columns;
+///
+/// let rows = vec![String::from("  hello  "), String::from("   hi")];
+///
+/// assert_eq!(
+///     trim_columns(&rows, &Options::default()),
+///     (vec![String::from("hello"), String::from(" hi")], 5)
+/// );
+/// ```
+pub fn trim_columns(rows: &[String], options: &Options) -> (Vec<String>, usize) {
+	d("border::trim_columns()", 1, Dt::Head, options, &mut std::io::stdout());
+
+	let rows = rows.iter().map(|row| split_markup(row, options)).collect::<Vec<Vec<(String, bool)>>>();
+	let leading = rows
+		.iter()
+		.filter_map(|parts| parts.iter().filter(|(_, visible)| *visible).position(|(text, _)| text != " "))
+		.min()
+		.unwrap_or(0);
+
+	let trimmed = rows
+		.iter()
+		.map(|parts| {
+			let last = parts.iter().rposition(|(text, visible)| *visible && text != " ");
+			let mut skipped = 0;
+			parts
+				.iter()
+				.enumerate()
+				.filter(|(index, (_, visible))| {
+					if !*visible {
+						return true;
+					}
+					skipped += 1;
+					skipped > leading && last.is_some_and(|last| *index <= last)
+				})
+				.map(|(_, (text, _))| text.as_str())
+				.collect::<String>()
+		})
+		.collect::<Vec<String>>();
+	let width = trimmed.iter().map(|row| get_width(row, options)).max().unwrap_or(0);
+
+	d(
+		&format!("border::trim_columns()\nleading:{:?}\nwidth:{:?}", leading, width),
+		1,
+		Dt::Log,
+		options,
+		&mut std::io::stdout(),
+	);
+
+	(trimmed, width)
+}
+
+/// Add the padding and frame of [`Options`] around rows of output that are `width` columns wide
+///
+/// Rows are aligned within the frame via the `align` option.
+/// The background is painted inside the frame for each row so it works with any alignment.
+///
+/// ```rust
+/// extern crate cfonts;
+///
+/// use cfonts::{Border, Options};
+/// use cfonts::border::add_border;
+///
+/// let options = Options {
+///     border: Some(Border::Single),
+///     padding: [0, 1, 0, 1],
+///     ..Options::default()
+/// };
+/// let rows = vec![String::from("hello"), String::from("hi")];
+///
+/// assert_eq!(
+///     add_border(&rows, 5, &options),
+///     vec![
+///         String::from("┌───────┐"),
+///         String::from("│ hello │"),
+///         String::from("│ hi    │"),
+///         String::from("└───────┘"),
+///     ]
+/// );
+/// ```
+pub fn add_border(rows: &[String], width: usize, options: &Options) -> Vec<String> {
+	d("border::add_border()", 1, Dt::Head, options, &mut std::io::stdout());
+	d(
+		&format!("border::add_border()\nwidth:{:?}\nborder:{:?}\npadding:{:?}", width, options.border, options.padding),
+		1,
+		Dt::Log,
+		options,
+		&mut std::io::stdout(),
+	);
+
+	let [top, right, bottom, left] = options.padding.map(usize::from);
+	let inner_width = left + width + right;
+	let (bg_start, bg_end) = match options.env {
+		Env::Cli if options.background != BgColors::Transparent => get_background_color_for(&options.background, options),
+		_ => (String::new(), String::new()),
+	};
+
+	let fill = |row: &str| {
+		let space = width.saturating_sub(get_width(row, options));
+		let offset = match options.align {
+			Align::Center => (space as f64 / 2.0).round() as usize,
+			Align::Right => space,
+			Align::Left | Align::Top | Align::Bottom => 0,
+		};
+		format!("{}{}{}{}{}", bg_start, " ".repeat(left + offset), row, " ".repeat(right + space - offset), bg_end)
+	};
+
+	let mut body = vec![fill(""); top];
+	body.extend(rows.iter().map(|row| fill(row)));
+	body.extend(vec![fill(""); bottom]);
+
+	let border = match &options.border {
+		Some(border) => border,
+		None => return body,
+	};
+	let chars = get_border_chars(border);
+	let colors = get_border_colors(inner_width + 2, options);
+	let edge = |start: char, end: char| {
+		let line = format!("{}{}{}", start, chars.horizontal.to_string().repeat(inner_width), end);
+		paint(&line, &colors, options)
+	};
+	let left_edge = paint(&chars.vertical.to_string(), &colors[..1], options);
+	let right_edge = paint(&chars.vertical.to_string(), &colors[colors.len() - 1..], options);
+
+	let mut output = vec![edge(chars.top_left, chars.top_right)];
+	output.extend(body.iter().map(|row| format!("{}{}{}", left_edge, row, right_edge)));
+	output.push(edge(chars.bottom_left, chars.bottom_right));
+
+	d(&format!("border::add_border() -> {:?}", output), 1, Dt::Log, options, &mut std::io::stdout());
+	output
+}
@@ -0,0 +1,153 @@
+//! The contents of this module is all about recording our output as an [asciinema](https://asciinema.org/) cast file
+use serde::Serialize;
+use std::time::Duration;
+
+use crate::animate::{clear_frame, frames, Frame, HIDE_CURSOR, MAX_FRAMES, SHOW_CURSOR};
+use crate::color::color;
+use crate::config::{ColorMode, Colors, Env, Options};
+use crate::debug::{d, Dt};
+use crate::grid::Grid;
+use crate::render::{render, resolve_options};
+
+/// The first line of a cast file describing the terminal
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct CastHeader {
+	/// The version of the asciinema file format
+	pub version: u8,
+	/// The number of columns of the terminal
+	pub width: usize,
+	/// The number of rows of the terminal
+	pub height: usize,
+}
+
+/// Render the text of [`Options`] into an asciinema v2 cast file
+///
+/// With the `animate` option each frame of the animation is recorded, otherwise the cast shows the output once.
+/// Like [`crate::json::render_json()`] we always render for [`Env::Cli`] and use truecolor unless a color mode has been
+/// picked explicitly. Pass a `seed` to get the same cast each time when using random colors.
+///
+/// ```rust
+/// extern crate cfonts;
+///
+/// use cfonts::{Colors, Fonts, Options};
+/// use cfonts::cast::render_cast;
+///
+/// let cast = render_cast(&Options {
+///     text: String::from("hi"),
+///     font: Fonts::FontConsole,
+///     colors: vec![Colors::Red],
+///     spaceless: true,
+///     ..Options::default()
+/// })
+/// .unwrap();
+///
+/// assert_eq!(
+///     cast,
+///     "{\"version\":2,\"width\":2,\"height\":1}\n\
+///     [0.0,\"o\",\"\\u001b[?25l\\u001b[31mh\\u001b[39m\\u001b[31mi\\u001b[39m\"]\n\
+///     [0.0,\"o\",\"\\u001b[?25h\"]\n"
+/// );
+/// ```
+pub fn render_cast(options: &Options) -> Result<String, String> {
+	d("cast::render_cast()", 1, Dt::Head, options, &mut std::io::stdout());
+
+	let options = resolve_options(&Options {
+		env: Env::Cli,
+		color_mode: match options.color_mode {
+			ColorMode::Auto => ColorMode::Truecolor,
+			ref color_mode => color_mode.clone(),
+		},
+		..options.clone()
+	});
+	let frames = match &options.animate {
+		Some(effect) => {
+			let frames = frames(options.clone(), effect.clone()).take(MAX_FRAMES + 1).collect::<Vec<Frame>>();
+			if frames.len() > MAX_FRAMES {
+				return Err(format!(
+					"The animation is too long to be recorded.\nUse a shorter {} or lower {} to stay within {} frames",
+					color("--duration", Colors::Green),
+					color("--fps", Colors::Green),
+					color(&MAX_FRAMES.to_string(), Colors::Green)
+				));
+			}
+			frames
+		}
+		None => vec![Frame {
+			text: render(options.clone()).text,
+			delay: Duration::ZERO,
+		}],
+	};
+
+	frames2cast(&frames, &options)
+}
+
+/// Turn frames into an asciinema v2 cast file
+///
+/// The terminal is sized to fit the widest and tallest frame and each frame is shown for its delay
+/// the same way [`crate::animate::play()`] would show it.
+///
+/// ```rust
+/// extern crate cfonts;
+///
+/// use std::time::Duration;
+///
+/// use cfonts::Options;
+/// use cfonts::animate::Frame;
+/// use cfonts::cast::frames2cast;
+///
+/// let frames = vec![
+///     Frame { text: String::from("a"), delay: Duration::from_millis(500) },
+///     Frame { text: String::from("ab"), delay: Duration::ZERO },
+/// ];
+///
+/// assert_eq!(
+///     frames2cast(&frames, &Options::default()).unwrap(),
+///     "{\"version\":2,\"width\":2,\"height\":1}\n\
+///     [0.0,\"o\",\"\\u001b[?25la\"]\n\
+///     [0.5,\"o\",\"\\r\\u001b[Jab\"]\n\
+///     [0.5,\"o\",\"\\u001b[?25h\"]\n"
+/// );
+/// ```
+pub fn frames2cast(frames: &[Frame], options: &Options) -> Result<String, String> {
+	d("cast::frames2cast()", 1, Dt::Head, options, &mut std::io::stdout());
+
+	let grids = frames.iter().map(|frame| Grid::from_ansi(&frame.text, options)).collect::<Vec<Grid>>();
+	let width = grids.iter().map(|grid| grid.width()).max().unwrap_or(0);
+	let height = grids.iter().map(|grid| grid.height()).max().unwrap_or(0);
+	d(
+		&format!("cast::frames2cast()\nframes:{}\nwidth:{}\nheight:{}", frames.len(), width, height),
+		1,
+		Dt::Log,
+		options,
+		&mut std::io::stdout(),
+	);
+
+	let mut cast = to_json(&CastHeader {
+		version: 2,
+		width,
+		height,
+	})? + "\n";
+	let mut time = Duration::ZERO;
+	let mut previous_height = 0;
+	for (index, frame) in frames.iter().enumerate() {
+		let prefix = match index {
+			0 => String::from(HIDE_CURSOR),
+			_ => clear_frame(previous_height),
+		};
+		// a terminal moves back to the start of the line on a new line but a recording needs to do that itself
+		let data = prefix + &frame.text.replace('\n', "\r\n");
+		cast += &(to_json(&(time.as_secs_f64(), "o", data))? + "\n");
+
+		time += frame.delay;
+		previous_height = frame.height();
+	}
+	cast += &(to_json(&(time.as_secs_f64(), "o", SHOW_CURSOR))? + "\n");
+
+	Ok(cast)
+}
+
+/// Serialize a single line of a cast file
+fn to_json<T: Serialize>(value: &T) -> Result<String, String> {
+	serde_json::to_string(value)
+		.map_err(|error| format!("Could not serialize the cast: {}", color(&error.to_string(), Colors::Green)))
+}
@@ -1,5 +1,5 @@
 //! The contents of this module is all about transforming letters on the output vector
-use crate::color::{color2hex, get_foreground_color};
+use crate::color::{color2hex, get_candy_color, get_foreground_color_for, CandyPosition};
 use crate::config::{Align, Colors, Env, Options};
 use crate::debug::{d, Dt};
 
@@ -383,11 +383,42 @@ pub fn get_letter_length(letter: &[String], font_color_count: usize, options: &O
 /// );
 /// ```
 pub fn paint_letter(letter: &[String], font_color_count: usize, options: &Options) -> Vec<String> {
-	d("chars::paint_letter()", 5, Dt::Head, options, &mut std::io::stdout());
+	paint_letter_at(letter, font_color_count, &CandyPosition::default(), options)
+}
+
+/// Add color meta info to a letter just like [`paint_letter()`] but with the position of the letter in the output
+/// so [`Colors::Candy`] can pick its colors depending on the `candy_mode` of [`Options`]
+///
+/// ```rust
+/// extern crate cfonts;
+///
+/// use cfonts::{CandyMode, Colors, ColorMode, Options};
+/// use cfonts::chars::paint_letter_at;
+/// use cfonts::color::CandyPosition;
+///
+/// let mut options = Options::default();
+/// options.colors = vec![Colors::Candy];
+/// options.color_mode = ColorMode::Ansi16;
+/// options.candy_mode = CandyMode::Glyph;
+/// options.seed = Some(1);
+/// let letter = vec![String::from("row1"), String::from("row2")];
+/// let position = CandyPosition { letter: 1, ..CandyPosition::default() };
+///
+/// let painted = paint_letter_at(&letter, 1, &position, &options);
+/// assert_eq!(painted[0].replace("row1", "row2"), painted[1]);
+/// assert_eq!(painted, paint_letter_at(&letter, 1, &position, &options));
+/// ```
+pub fn paint_letter_at(
+	letter: &[String],
+	font_color_count: usize,
+	position: &CandyPosition,
+	options: &Options,
+) -> Vec<String> {
+	d("chars::paint_letter_at()", 5, Dt::Head, options, &mut std::io::stdout());
 	d(
 		&format!(
-			"chars::paint_letter()\nletter:{:?}\ncolors:{:?}\nfont_color_count:{:?}",
-			letter, options.colors, font_color_count
+			"chars::paint_letter_at()\nletter:{:?}\ncolors:{:?}\nfont_color_count:{:?}\nposition:{:?}",
+			letter, options.colors, font_color_count, position
 		),
 		5,
 		Dt::Log,
@@ -397,7 +428,8 @@ pub fn paint_letter(letter: &[String], font_color_count: usize, options: &Option
 
 	let painted_letter = letter
 		.iter()
-		.map(|line| {
+		.enumerate()
+		.map(|(row, line)| {
 			let mut new_line = line.clone();
 			if line.is_empty() {
 				new_line
@@ -413,11 +445,26 @@ pub fn paint_letter(letter: &[String], font_color_count: usize, options: &Option
 						true => &Colors::System,
 						false => colors.get(i).unwrap_or(&Colors::System),
 					};
+					let candy_color;
+					let color_name = match color_name {
+						Colors::Candy => {
+							candy_color = get_candy_color(
+								&CandyPosition {
+									row,
+									layer: i,
+									..position.clone()
+								},
+								options,
+							);
+							&candy_color
+						}
+						color => color,
+					};
 					let (color_start, color_end) = match options.env {
 						Env::Cli => {
 							let (color_start, color_end) = match color_name {
 								Colors::System => (String::from(""), String::from("")),
-								color => get_foreground_color(color),
+								color => get_foreground_color_for(color, options),
 							};
 							(color_start, color_end)
 						}
@@ -451,7 +498,7 @@ pub fn paint_letter(letter: &[String], font_color_count: usize, options: &Option
 		})
 		.collect();
 
-	d(&format!("chars::paint_letter() -> {:?}", painted_letter), 5, Dt::Log, options, &mut std::io::stdout());
+	d(&format!("chars::paint_letter_at() -> {:?}", painted_letter), 5, Dt::Log, options, &mut std::io::stdout());
 	painted_letter
 }
 
@@ -503,7 +550,7 @@ pub fn align_last_line(
 
 	if options.env == Env::Cli {
 		let space = match options.align {
-			Align::Right => format!("{0:>width$}", "", width = (max_length - line_length)),
+			Align::Right => format!("{0:>width$}", "", width = max_length.saturating_sub(line_length)),
 			Align::Center => {
 				format!("{0:>width$}", "", width = (((max_length as f64 - line_length as f64) / 2.0).round() as usize))
 			}
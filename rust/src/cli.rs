@@ -3,9 +3,52 @@ use std::env;
 use std::fmt::Write as _;
 
 use crate::color::{color, get_term_color_support, TermColorSupport};
-use crate::config::{Align, BgColors, Colors, Env, Fonts, OptionType, Options, CLIOPTIONS};
+use crate::config::{
+	Align, BgColors, Border, CandyMode, CliOption, ColorMode, Colors, Cvd, Effect, Env, Fonts, OptionType, Options,
+	OutputFormat, Shell, TerminalBg, CLIOPTIONS,
+};
 use crate::debug::{d, Dt};
+use crate::defaults::{PROJECT_FILE, USER_FILE};
+use crate::gradient::{add_gradient_colors, GradientPresetRegistry};
 use crate::render::render;
+use crate::theme::ThemeRegistry;
+
+/// What cfonts is, shown in the help and the man page
+pub const DESCRIPTION: &str = "This is a tool for sexy fonts in the console. Give your cli some love.";
+
+/// How to call cfonts, shown in the help and the man page
+pub const USAGE: &str = "cfonts \"<value>\" [option1] <input1> [option2] <input1>,<input2> [option3]";
+
+/// Example calls and what they do, the first one is shown in the help and all of them in the man page
+pub const EXAMPLES: [(&str, &str); 6] = [
+	(
+		"cfonts \"sexy font\" -f chrome -a center -c red,green,gray",
+		"Print \"sexy font\" in the chrome font, centered and in red, green and gray",
+	),
+	("cfonts \"hello|world\" -g pride", "Print two lines of text in the colors of the pride gradient preset"),
+	("cfonts \"hi\" -f tiny --output hi.png", "Write the output into a png image instead of the console"),
+	("cfonts \"hi\" --animate typewriter", "Type the text out one letter at a time"),
+	("cfonts -f tiny -- \"-10%\"", "Use -- to end the options so the text can start with a dash"),
+	("cfonts --completions zsh > _cfonts", "Generate a completion script for zsh"),
+];
+
+/// The environment variables cfonts reads and what they do, used in the man page
+pub const ENVIRONMENT: [(&str, &str); 5] = [
+	("NO_COLOR", "Disables all colors when set to any value unless FORCE_COLOR is set too"),
+	(
+		"FORCE_COLOR",
+		"Forces the color support of the terminal: 0 for no colors, 1 for 16 colors, 2 for 256 colors and 3 for truecolor",
+	),
+	("COLORFGBG", "Used to detect the background of the terminal when it can't be asked"),
+	(
+		"XDG_CONFIG_HOME",
+		"The folder to look for the cfonts folder with config.toml, gradients.toml and themes in, falling back to $HOME/.config and %APPDATA% on windows",
+	),
+	(
+		"CFONTS_*",
+		"Sets the default of an option by its name in upper case, e.g. CFONTS_FONT=tiny or CFONTS_LETTER_SPACING=2",
+	),
+];
 
 /// Return the string to display when the `version` option is passed in via the [`Options`] struct
 ///
@@ -42,6 +85,10 @@ pub fn help(options: &Options) -> String {
 	d("cli::help()", 5, Dt::Head, options, &mut std::io::stdout());
 
 	let mut output = String::new();
+	// help should always show so we only list user presets that could be read
+	let mut presets = GradientPresetRegistry::default();
+	let _ = presets.load_config(options);
+
 	let render_options = render(Options {
 		text: String::from("cfonts"),
 		align: Align::Left,
@@ -59,42 +106,200 @@ pub fn help(options: &Options) -> String {
 	output += "\n\n";
 	output += &render_options.text;
 	output += "\n\n";
-	output += "This is a tool for sexy fonts in the console. Give your cli some love.\n";
+	let _ = writeln!(output, "{}", DESCRIPTION);
 	output += "\n";
-	output += "Usage: cfonts \"<value>\" [option1] <input1> [option2] <input1>,<input2> [option3]\n";
-	let _ =
-		writeln!(output, "Example: {}$ cfonts \"sexy font\" -f chrome -a center -c red,green,gray{}", bold_start, bold_end);
+	let _ = writeln!(output, "Usage: {}", USAGE);
+	let _ = writeln!(output, "Example: {}$ {}{}", bold_start, EXAMPLES[0].0, bold_end);
 	output += "\n";
 	output += "Options:\n";
 
 	for option in CLIOPTIONS {
-		let _ = write!(output, "\n{}{}, {}", bold_start, option.name, option.shortcut);
+		let _ = write!(output, "\n{}{}", bold_start, option.name);
+		if !option.shortcut.is_empty() {
+			let _ = write!(output, ", {}", option.shortcut);
+		}
 		if !option.fallback_shortcut.is_empty() {
 			let _ = write!(output, ", {}", option.fallback_shortcut);
 		}
 		let _ = writeln!(output, "{}", bold_end);
 		let _ = writeln!(output, "{}", option.description);
 		let _ = write!(output, "{}${} cfonts {}", bold_start, bold_end, option.example);
-		match option.kind {
-			OptionType::Font => {
-				output += &color(&format!(" [ {} ]", Fonts::list()), Colors::Green).to_string();
-			}
-			OptionType::Colors => {
-				output += &color(&format!(" [ {} ]", Colors::list()), Colors::Green).to_string();
-			}
-			OptionType::BgColor => {
-				output += &color(&format!(" [ {} ]", BgColors::list()), Colors::Green).to_string();
-			}
-			OptionType::Align => {
-				output += &color(&format!(" [ {} ]", Align::list()), Colors::Green).to_string();
-			}
-			OptionType::Env => {
-				output += &color(&format!(" [ {} ]", Env::list()), Colors::Green).to_string();
-			}
-			_ => {}
+		if let Some(values) = get_option_values(&option, &presets) {
+			output += &color(&format!(" [ {} ]", values), Colors::Green).to_string();
 		}
 		output += "\n";
 	}
 
 	output
 }
+
+/// Escape text for roff so dashes, backslashes and leading dots are printed as they are
+fn roff_escape(text: &str) -> String {
+	let escaped = text.replace('\\', "\\e").replace('-', "\\-");
+	match escaped.starts_with('.') || escaped.starts_with('\'') {
+		true => format!("\\&{}", escaped),
+		false => escaped,
+	}
+}
+
+/// Return the man page in roff to display when the `man` option is passed in via the [`Options`] struct
+///
+/// Only the built-in gradient presets and themes are listed so the page is the same on every machine.
+///
+/// ```rust
+/// extern crate cfonts;
+///
+/// use cfonts::Options;
+/// use cfonts::cli::man;
+///
+/// let options = Options::default();
+/// let page = man(&options);
+/// assert!(page.starts_with(".TH CFONTS 1"));
+/// assert!(page.contains(".SH ENVIRONMENT\n"));
+/// assert!(page.contains("\\fB\\-\\-font\\fR, \\fB\\-f\\fR\n"));
+/// ```
+pub fn man(options: &Options) -> String {
+	d("cli::man()", 5, Dt::Head, options, &mut std::io::stdout());
+
+	let presets = GradientPresetRegistry::default();
+	let mut output = String::new();
+
+	let _ = writeln!(output, ".TH CFONTS 1 \"\" \"cfonts {}\" \"User Commands\"", env!("CARGO_PKG_VERSION"));
+	output += ".SH NAME\n";
+	output += "cfonts \\- sexy fonts for the console\n";
+	output += ".SH SYNOPSIS\n";
+	let _ = writeln!(output, "{}", roff_escape(USAGE));
+	output += ".SH DESCRIPTION\n";
+	let _ = writeln!(output, "{}", roff_escape(DESCRIPTION));
+
+	output += ".SH OPTIONS\n";
+	for option in CLIOPTIONS {
+		let flags = [option.name, option.shortcut, option.fallback_shortcut]
+			.iter()
+			.filter(|flag| !flag.is_empty())
+			.map(|flag| format!("\\fB{}\\fR", roff_escape(flag)))
+			.collect::<Vec<String>>();
+
+		output += ".TP\n";
+		let _ = writeln!(output, "{}", flags.join(", "));
+		let _ = writeln!(output, "{}", roff_escape(option.description));
+		if let Some(values) = get_option_values(&option, &presets) {
+			let _ = writeln!(output, ".br\nAllowed values: {}", roff_escape(&values));
+		}
+		let _ = writeln!(output, ".br\nExample: \\fB{}\\fR", roff_escape(&format!("cfonts {}", option.example)));
+	}
+
+	output += ".SH EXAMPLES\n";
+	for (example, description) in EXAMPLES {
+		let _ = writeln!(output, ".TP\n\\fB{}\\fR\n{}", roff_escape(example), roff_escape(description));
+	}
+
+	output += ".SH ENVIRONMENT\n";
+	for (name, description) in ENVIRONMENT {
+		let _ = writeln!(output, ".TP\n\\fB{}\\fR\n{}", roff_escape(name), roff_escape(description));
+	}
+
+	output += ".SH FILES\n";
+	for (file, description) in [
+		(format!("$XDG_CONFIG_HOME/cfonts/{}", USER_FILE), "Your default options"),
+		(String::from(PROJECT_FILE), "The default options of a project, looked up from the current folder upwards"),
+		(String::from("$XDG_CONFIG_HOME/cfonts/gradients.toml"), "Your own gradient presets"),
+		(String::from("$XDG_CONFIG_HOME/cfonts/themes/"), "Your own themes"),
+	] {
+		let _ = writeln!(output, ".TP\n\\fI{}\\fR\n{}", roff_escape(&file), roff_escape(description));
+	}
+
+	output += ".SH SEE ALSO\n";
+	let _ = writeln!(output, "{}", roff_escape(env!("CARGO_PKG_HOMEPAGE")));
+
+	d(&format!("cli::man() -> {:?}", output), 5, Dt::Log, options, &mut std::io::stdout());
+	output
+}
+
+/// The values an option takes in plain text, shared by the help and the man page
+///
+/// ```rust
+/// extern crate cfonts;
+///
+/// use cfonts::config::CLIOPTIONS;
+/// use cfonts::cli::get_option_values;
+/// use cfonts::gradient::GradientPresetRegistry;
+///
+/// let presets = GradientPresetRegistry::default();
+/// let option = |key: &str| CLIOPTIONS.iter().find(|option| option.key == key).unwrap();
+///
+/// assert_eq!(get_option_values(option("align"), &presets), Some(String::from("left, center, right, top, bottom")));
+/// assert_eq!(get_option_values(option("spaceless"), &presets), None);
+/// ```
+pub fn get_option_values(option: &CliOption, presets: &GradientPresetRegistry) -> Option<String> {
+	match option.kind {
+		OptionType::Font => Some(Fonts::list()),
+		OptionType::Colors | OptionType::BorderColor | OptionType::ShadowColor => Some(Colors::list()),
+		OptionType::BgColor => Some(BgColors::list()),
+		OptionType::Align => Some(Align::list()),
+		OptionType::Env => Some(Env::list()),
+		OptionType::ColorMode => Some(ColorMode::list()),
+		OptionType::CandyMode => Some(CandyMode::list()),
+		OptionType::Simulate => Some(format!("none, {}", Cvd::list())),
+		OptionType::TerminalBg => Some(TerminalBg::list()),
+		OptionType::Gradient => Some(format!("presets: {}, see --list-gradients", presets.list().join(", "))),
+		OptionType::Output => Some(String::from("png, html, json, txt, cast")),
+		OptionType::Animate => Some(Effect::list()),
+		OptionType::OutputFormat => Some(OutputFormat::list()),
+		OptionType::Theme => Some(ThemeRegistry::default().list().join(", ")),
+		OptionType::Shell => Some(Shell::list()),
+		OptionType::Border => Some(format!("none, {}", Border::list())),
+		_ => None,
+	}
+}
+
+/// Return the string to display when the `list_gradients` option is passed in via the [`Options`] struct
+///
+/// Each preset is listed with its aliases and a preview of the gradient.
+///
+/// ```rust
+/// extern crate cfonts;
+///
+/// use cfonts::Options;
+/// use cfonts::cli::list_gradients;
+/// use cfonts::gradient::GradientPresetRegistry;
+///
+/// let options = Options::default();
+/// let output = list_gradients(&GradientPresetRegistry::default(), &options);
+/// assert!(output.contains("pride"));
+/// assert!(output.contains("lgbt, lgbtq, lgbtqa"));
+/// ```
+pub fn list_gradients(presets: &GradientPresetRegistry, options: &Options) -> String {
+	d("cli::list_gradients()", 5, Dt::Head, options, &mut std::io::stdout());
+
+	let mut output = String::new();
+	output += "\n";
+	output += "Gradient presets:\n";
+
+	for name in presets.list() {
+		// the name is known to be in the registry as we just listed it
+		let preset = presets.get(&name).unwrap();
+		let aliases = presets.aliases_of(&name);
+
+		let _ = write!(output, "\n{}", name);
+		if !aliases.is_empty() {
+			output += &color(&format!(" ({})", aliases.join(", ")), Colors::Green).to_string();
+		}
+		output += "\n";
+
+		let preview_options = Options {
+			gradient: preset.colors.clone(),
+			transition_gradient: preset.transition,
+			line_height: 0,
+			..options.clone()
+		};
+		let preview = add_gradient_colors(&[String::from("\u{2588}").repeat(40)], 1, 1, &preview_options);
+		output += &preview.join("\n");
+		output += "\n";
+	}
+
+	let _ = writeln!(output, "\nUse a preset with: {}", color("cfonts \"Text\" --gradient pride", Colors::Green));
+	d(&format!("cli::list_gradients() -> {:?}", output), 5, Dt::Log, options, &mut std::io::stdout());
+
+	output
+}
@@ -0,0 +1,382 @@
+//! The contents of this module is all about showing a live clock or countdown that updates every second
+use chrono::{Datelike, Local, TimeZone, Timelike};
+use std::fmt::Write as _;
+use std::io::Write;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use crate::animate::{clear_frame, HIDE_CURSOR, SHOW_CURSOR};
+use crate::config::{Align, Options};
+use crate::debug::{d, Dt};
+use crate::grid::Grid;
+use crate::marquee::slice_line;
+use crate::render::{render, resolve_options};
+
+/// The format of the clock when none is given
+pub const DEFAULT_CLOCK_FORMAT: &str = "%H:%M:%S";
+
+/// A point in time with its date and time of day broken down into parts
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct LocalTime {
+	/// Milliseconds since the unix epoch
+	pub timestamp: u64,
+	/// The year like `2024`
+	pub year: i64,
+	/// The month from `1` to `12`
+	pub month: u8,
+	/// The day of the month from `1` to `31`
+	pub day: u8,
+	/// The hour from `0` to `23`
+	pub hour: u8,
+	/// The minute from `0` to `59`
+	pub minute: u8,
+	/// The second from `0` to `59`
+	pub second: u8,
+}
+
+impl LocalTime {
+	/// Break a unix timestamp in milliseconds down into its parts in UTC
+	///
+	/// ```rust
+	/// extern crate cfonts;
+	///
+	/// use cfonts::clock::LocalTime;
+	///
+	/// let time = LocalTime::from_utc(951_827_696_000);
+	/// assert_eq!((time.year, time.month, time.day), (2000, 2, 29));
+	/// assert_eq!((time.hour, time.minute, time.second), (12, 34, 56));
+	/// ```
+	pub fn from_utc(timestamp: u64) -> Self {
+		let days = (timestamp / 86_400_000) as i64;
+		let seconds = timestamp / 1000 % 86_400;
+
+		// turning days into a date, see http://howardhinnant.github.io/date_algorithms.html#civil_from_days
+		let days = days + 719_468;
+		let era = days.div_euclid(146_097);
+		let day_of_era = days.rem_euclid(146_097);
+		let year_of_era = (day_of_era - day_of_era / 1_460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+		let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+		let month_index = (5 * day_of_year + 2) / 153;
+		let day = day_of_year - (153 * month_index + 2) / 5 + 1;
+		let month = if month_index < 10 {
+			month_index + 3
+		} else {
+			month_index - 9
+		};
+		let year = year_of_era + era * 400 + if month <= 2 { 1 } else { 0 };
+
+		Self {
+			timestamp,
+			year,
+			month: month as u8,
+			day: day as u8,
+			hour: (seconds / 3600) as u8,
+			minute: (seconds / 60 % 60) as u8,
+			second: (seconds % 60) as u8,
+		}
+	}
+
+	/// Break a unix timestamp in milliseconds down into its parts in the local timezone
+	///
+	/// We fall back to UTC for timestamps the local timezone can't express.
+	///
+	/// ```rust
+	/// extern crate cfonts;
+	///
+	/// use cfonts::clock::LocalTime;
+	///
+	/// let time = LocalTime::from_local(951_827_696_000);
+	/// assert_eq!(time.timestamp, 951_827_696_000);
+	/// assert_eq!(time.second, 56);
+	/// ```
+	pub fn from_local(timestamp: u64) -> Self {
+		let time = i64::try_from(timestamp).ok().and_then(|millis| Local.timestamp_millis_opt(millis).single());
+
+		match time {
+			Some(time) => Self {
+				timestamp,
+				year: time.year().into(),
+				month: time.month() as u8,
+				day: time.day() as u8,
+				hour: time.hour() as u8,
+				minute: time.minute() as u8,
+				second: time.second() as u8,
+			},
+			None => Self::from_utc(timestamp),
+		}
+	}
+}
+
+/// Where the clock gets the time from and how it waits, so tests can drive it with a fake clock
+pub trait TimeSource {
+	/// The current time
+	fn now(&mut self) -> LocalTime;
+	/// Wait for `duration` to pass
+	fn sleep(&mut self, duration: Duration);
+}
+
+/// The clock of the system in the local timezone
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct SystemClock;
+
+impl TimeSource for SystemClock {
+	fn now(&mut self) -> LocalTime {
+		let timestamp = SystemTime::now().duration_since(UNIX_EPOCH).map(|time| time.as_millis() as u64).unwrap_or(0);
+		LocalTime::from_local(timestamp)
+	}
+
+	fn sleep(&mut self, duration: Duration) {
+		std::thread::sleep(duration);
+	}
+}
+
+/// Format a time with a subset of the `strftime` placeholders
+///
+/// Supported are `%H`, `%I`, `%M`, `%S`, `%p`, `%Y`, `%y`, `%m`, `%d` and `%%`, everything else is kept as is.
+///
+/// ```rust
+/// extern crate cfonts;
+///
+/// use cfonts::clock::{format_time, LocalTime};
+///
+/// let time = LocalTime::from_utc(951_827_696_000);
+/// assert_eq!(format_time("%H:%M:%S", &time), "12:34:56");
+/// assert_eq!(format_time("%d.%m.%Y %I%p", &time), "29.02.2000 12PM");
+/// ```
+pub fn format_time(format: &str, time: &LocalTime) -> String {
+	let mut result = String::with_capacity(format.len());
+	let mut chars = format.chars();
+
+	while let Some(character) = chars.next() {
+		if character != '%' {
+			result.push(character);
+			continue;
+		}
+
+		let _ = match chars.next() {
+			Some('H') => write!(result, "{:02}", time.hour),
+			Some('I') => write!(
+				result,
+				"{:02}",
+				match time.hour % 12 {
+					0 => 12,
+					hour => hour,
+				}
+			),
+			Some('M') => write!(result, "{:02}", time.minute),
+			Some('S') => write!(result, "{:02}", time.second),
+			Some('p') => write!(result, "{}", if time.hour < 12 { "AM" } else { "PM" }),
+			Some('Y') => write!(result, "{:04}", time.year),
+			Some('y') => write!(result, "{:02}", time.year.rem_euclid(100)),
+			Some('m') => write!(result, "{:02}", time.month),
+			Some('d') => write!(result, "{:02}", time.day),
+			Some('%') => write!(result, "%"),
+			Some(other) => write!(result, "%{}", other),
+			None => write!(result, "%"),
+		};
+	}
+
+	result
+}
+
+/// Format the remaining time of a countdown given in milliseconds
+///
+/// Started seconds are counted as full seconds so `00:00` is only shown once the countdown is over.
+///
+/// ```rust
+/// extern crate cfonts;
+///
+/// use cfonts::clock::format_countdown;
+///
+/// assert_eq!(format_countdown(300_000), "05:00");
+/// assert_eq!(format_countdown(1), "00:01");
+/// assert_eq!(format_countdown(3_723_000), "1:02:03");
+/// ```
+pub fn format_countdown(remaining: u64) -> String {
+	let seconds = remaining.div_ceil(1000);
+	match seconds / 3600 {
+		0 => format!("{:02}:{:02}", seconds / 60, seconds % 60),
+		hours => format!("{}:{:02}:{:02}", hours, seconds / 60 % 60, seconds % 60),
+	}
+}
+
+/// The text to show at `now` and whether the countdown ending at the timestamp `end` is over
+///
+/// Without an `end` we show the clock in the format of [`Options`].
+///
+/// ```rust
+/// extern crate cfonts;
+///
+/// use cfonts::Options;
+/// use cfonts::clock::{get_text, LocalTime};
+///
+/// let options = Options::default();
+/// let now = LocalTime::from_utc(951_827_696_000);
+///
+/// assert_eq!(get_text(&options, &now, None), (String::from("12:34:56"), false));
+/// assert_eq!(get_text(&options, &now, Some(951_827_756_000)), (String::from("01:00"), false));
+/// assert_eq!(get_text(&options, &now, Some(951_827_696_000)), (String::from("00:00"), true));
+/// ```
+pub fn get_text(options: &Options, now: &LocalTime, end: Option<u64>) -> (String, bool) {
+	match end {
+		Some(end) => {
+			let remaining = end.saturating_sub(now.timestamp);
+			(format_countdown(remaining), remaining == 0)
+		}
+		None => (format_time(options.clock.as_deref().unwrap_or(DEFAULT_CLOCK_FORMAT), now), false),
+	}
+}
+
+/// Move each row of some output to the right so it sits at `align` within `width` columns
+///
+/// ```rust
+/// extern crate cfonts;
+///
+/// use cfonts::{Align, Options};
+/// use cfonts::clock::align_text;
+///
+/// let options = Options::default();
+/// assert_eq!(align_text("ab\nc", 6, &Align::Center, &options), "  ab\n  c");
+/// assert_eq!(align_text("ab\nc", 6, &Align::Right, &options), "    ab\n    c");
+/// assert_eq!(align_text("ab\nc", 6, &Align::Left, &options), "ab\nc");
+/// ```
+pub fn align_text(text: &str, width: usize, align: &Align, options: &Options) -> String {
+	let free = width.saturating_sub(Grid::from_ansi(text, options).width());
+	let space = match align {
+		Align::Center => " ".repeat(free.div_ceil(2)),
+		Align::Right => " ".repeat(free),
+		Align::Left | Align::Top | Align::Bottom => return text.to_string(),
+	};
+
+	text.split('\n').map(|row| format!("{}{}", space, row)).collect::<Vec<String>>().join("\n")
+}
+
+/// Keeps track of what is in the console so we only redraw the rows that changed
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Display {
+	rows: Vec<String>,
+}
+
+impl Display {
+	/// Draw `text` in place of what we drew before
+	///
+	/// The cursor is expected to be where we left it after the last draw.
+	///
+	/// ```rust
+	/// extern crate cfonts;
+	///
+	/// use cfonts::clock::Display;
+	///
+	/// let mut display = Display::default();
+	/// let mut output = Vec::new();
+	///
+	/// display.draw("a\nb", &mut output).unwrap();
+	/// display.draw("c\nb", &mut output).unwrap();
+	/// assert_eq!(String::from_utf8(output).unwrap(), "a\nb\r\x1b[1A\x1b[0m\x1b[2Kc\x1b[0m\x1b[1B");
+	/// ```
+	pub fn draw<W: Write>(&mut self, text: &str, out: &mut W) -> std::io::Result<()> {
+		let rows = text.split('\n').map(String::from).collect::<Vec<String>>();
+
+		if rows.len() != self.rows.len() {
+			write!(out, "{}{}", clear_frame(self.rows.len()), text)?;
+		} else {
+			for (index, row) in rows.iter().enumerate() {
+				if *row == self.rows[index] {
+					continue;
+				}
+
+				let up = rows.len() - 1 - index;
+				let (move_up, move_down) = match up {
+					0 => (String::new(), String::new()),
+					_ => (format!("\x1b[{}A", up), format!("\x1b[{}B", up)),
+				};
+				// the escape sequences of the rows above decide which colors this row starts with
+				let state = rows[..index].iter().map(|row| slice_line(row, 0, 0)).collect::<String>();
+				write!(out, "\r{}\x1b[0m\x1b[2K{}{}\x1b[0m{}", move_up, state, row, move_down)?;
+			}
+		}
+
+		self.rows = rows;
+		Ok(())
+	}
+
+	/// Remove what we drew so the next draw starts fresh
+	pub fn clear<W: Write>(&mut self, out: &mut W) -> std::io::Result<()> {
+		write!(out, "{}", clear_frame(self.rows.len()))?;
+		self.rows.clear();
+		Ok(())
+	}
+}
+
+/// Show a live clock or, with the `countdown` option of [`Options`], a countdown until it's over or `stop` is set
+///
+/// The output is redrawn each second with the time of `source` and aligned within the width `terminal_width`
+/// returns so a resized terminal gets the output re-aligned.
+/// Returns `true` if we were stopped.
+pub fn run<T: TimeSource, W: Write, F: Fn() -> usize>(
+	options: &Options,
+	source: &mut T,
+	terminal_width: F,
+	out: &mut W,
+	stop: &AtomicBool,
+) -> std::io::Result<bool> {
+	d("clock::run()", 1, Dt::Head, options, &mut std::io::stdout());
+
+	// we align the output ourselves so we can follow the terminal when it's resized
+	let align = options.align.clone();
+	let options = resolve_options(&Options {
+		no_wrap: true,
+		align: match align {
+			Align::Center | Align::Right => Align::Left,
+			ref align => align.clone(),
+		},
+		..options.clone()
+	});
+	let end = options.countdown.map(|countdown| source.now().timestamp.saturating_add(countdown));
+	let mut display = Display::default();
+	let mut width = terminal_width();
+	d(&format!("clock::run()\nend:{:?}\nwidth:{}", end, width), 1, Dt::Log, &options, &mut std::io::stdout());
+
+	write!(out, "{}", HIDE_CURSOR)?;
+	'ticks: while !stop.load(Ordering::Relaxed) {
+		let now = source.now();
+		let (text, done) = get_text(&options, &now, end);
+		let output = render(Options {
+			text,
+			..options.clone()
+		})
+		.text;
+		display.draw(&align_text(&output, width, &align, &options), out)?;
+		out.flush()?;
+
+		if done {
+			break;
+		}
+
+		// we wait for the next full second so the output changes right when the time does
+		let mut wait = Duration::from_millis(match end {
+			Some(end) => match end.saturating_sub(now.timestamp) % 1000 {
+				0 => 1000,
+				rest => rest,
+			},
+			None => 1000 - now.timestamp % 1000,
+		});
+		while !wait.is_zero() {
+			if stop.load(Ordering::Relaxed) {
+				break 'ticks;
+			}
+			if terminal_width() != width {
+				width = terminal_width();
+				display.clear(out)?;
+				break;
+			}
+			let step = wait.min(Duration::from_millis(10));
+			source.sleep(step);
+			wait -= step;
+		}
+	}
+
+	writeln!(out, "{}", SHOW_CURSOR)?;
+	out.flush()?;
+	Ok(stop.load(Ordering::Relaxed))
+}
@@ -1,14 +1,17 @@
 //! The contents of this module is all about colors, color-transformation and color-conversion
 use rand::seq::SliceRandom;
+use rand::SeedableRng;
+use rand_chacha::ChaCha8Rng;
+use serde::{Deserialize, Serialize};
 use std::env;
 use supports_color::Stream;
 
 use crate::config::Options;
-use crate::config::{BgColors, Colors};
+use crate::config::{BgColors, CandyMode, ColorMode, Colors, Cvd, TerminalBg};
 use crate::debug::{d, Dt};
 
 /// An enum to list the available ANSI color support in the consumers console/terminal
-#[derive(PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub enum TermColorSupport {
 	/// 16 million colors via truecolor RGB
 	Ansi16m,
@@ -20,6 +23,46 @@ pub enum TermColorSupport {
 	NoColor,
 }
 
+/// The set of bright candy-like colors [`Colors::Candy`] picks from
+pub const CANDY_COLORS: [Colors; 11] = [
+	Colors::Red,
+	Colors::Green,
+	Colors::Yellow,
+	Colors::Magenta,
+	Colors::Cyan,
+	Colors::RedBright,
+	Colors::GreenBright,
+	Colors::YellowBright,
+	Colors::BlueBright,
+	Colors::MagentaBright,
+	Colors::CyanBright,
+];
+
+/// The set of darker candy colors [`Colors::Candy`] picks from on light terminal backgrounds
+pub const LIGHT_CANDY_COLORS: [Colors; 8] = [
+	Colors::Red,
+	Colors::Green,
+	Colors::Blue,
+	Colors::Magenta,
+	Colors::Cyan,
+	Colors::RedBright,
+	Colors::BlueBright,
+	Colors::MagentaBright,
+];
+
+/// The position of a colored segment inside the output so [`Colors::Candy`] can be picked deterministically
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct CandyPosition {
+	/// The index of the letter within the text
+	pub letter: usize,
+	/// The line of cfonts text the letter is on
+	pub line: usize,
+	/// The row within the font
+	pub row: usize,
+	/// The color layer within the font
+	pub layer: usize,
+}
+
 /// An enum to list the two color layers: foreground and background
 pub enum ColorLayer {
 	Foreground,
@@ -27,7 +70,7 @@ pub enum ColorLayer {
 }
 
 /// The `Rgb` enum is being used to store [RGB](https://en.wikipedia.org/wiki/RGB_color_model) values
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub enum Rgb {
 	Val(u8, u8, u8),
 }
@@ -412,18 +455,14 @@ pub fn color2hex(color: &Colors, options: &Options) -> String {
 		Colors::BlueBright => String::from("#6974f6"),
 		Colors::MagentaBright => String::from("#ee82f8"),
 		Colors::CyanBright => String::from("#8dfafd"),
-		Colors::Candy => {
-			let colors = [
-				"#ea3223", "#377d22", "#fffd54", "#ea3df7", "#74fbfd", "#ee776d", "#8cf57b", "#fffb7f", "#6974f6", "#ee82f8",
-				"#8dfafd",
-			];
-			String::from(*colors.choose(&mut rand::thread_rng()).unwrap())
-		}
+		// the candy color is simulated in the recursive call already
+		Colors::Candy => return color2hex(&get_candy_color(&CandyPosition::default(), options), options),
 		Colors::Rgb(rgb) => {
 			let (r, g, b) = rgb.get_value();
 			rgb2hex(&Rgb::Val(r, g, b), options)
 		}
 	};
+	let hex = simulate_hex(hex, options);
 
 	d(&format!("color::color2hex() -> {:?}", hex), 5, Dt::Log, options, &mut std::io::stdout());
 	hex
@@ -469,11 +508,101 @@ pub fn bgcolor2hex(color: &BgColors, options: &Options) -> String {
 			rgb2hex(&Rgb::Val(r, g, b), options)
 		}
 	};
+	let hex = simulate_hex(hex, options);
 
 	d(&format!("color::bgcolor2hex() -> {:?}", hex), 5, Dt::Log, options, &mut std::io::stdout());
 	hex
 }
 
+/// Transform an RGB color into what a viewer with the color vision deficiency in the `simulate` option would see
+///
+/// We use the matrices from [Machado et al. 2009](https://www.inf.ufrgs.br/~oliveira/pubs_files/CVD_Simulation/CVD_Simulation.html)
+/// applied in linear RGB space.
+///
+/// ```rust
+/// extern crate cfonts;
+///
+/// use cfonts::{Cvd, Options, Rgb};
+/// use cfonts::color::simulate_rgb;
+///
+/// let mut options = Options::default();
+/// assert_eq!(simulate_rgb(&Rgb::Val(255, 0, 0), &options), Rgb::Val(255, 0, 0));
+///
+/// options.simulate = Some(Cvd::Achromatopsia);
+/// assert_eq!(simulate_rgb(&Rgb::Val(255, 0, 0), &options), Rgb::Val(127, 127, 127));
+/// assert_eq!(simulate_rgb(&Rgb::Val(255, 255, 255), &options), Rgb::Val(255, 255, 255));
+/// ```
+pub fn simulate_rgb(rgb: &Rgb, options: &Options) -> Rgb {
+	let matrix = match options.simulate {
+		None => return rgb.clone(),
+		Some(Cvd::Protanopia) => [
+			[0.152286, 1.052583, -0.204868],
+			[0.114503, 0.786281, 0.099216],
+			[-0.003882, -0.048116, 1.051998],
+		],
+		Some(Cvd::Deuteranopia) => [
+			[0.367322, 0.860646, -0.227968],
+			[0.280085, 0.672501, 0.047413],
+			[-0.011820, 0.042940, 0.968881],
+		],
+		Some(Cvd::Tritanopia) => [
+			[1.255528, -0.076749, -0.178779],
+			[-0.078411, 0.930809, 0.147602],
+			[0.004733, 0.691367, 0.303900],
+		],
+		Some(Cvd::Achromatopsia) => [
+			[0.2126, 0.7152, 0.0722],
+			[0.2126, 0.7152, 0.0722],
+			[0.2126, 0.7152, 0.0722],
+		],
+	};
+
+	let to_linear = |channel: u8| {
+		let value = channel as f64 / 255.0;
+		if value <= 0.04045 {
+			value / 12.92
+		} else {
+			((value + 0.055) / 1.055).powf(2.4)
+		}
+	};
+	let to_srgb = |value: f64| {
+		let value = value.clamp(0.0, 1.0);
+		let value = if value <= 0.0031308 {
+			value * 12.92
+		} else {
+			1.055 * value.powf(1.0 / 2.4) - 0.055
+		};
+		(value * 255.0).round() as u8
+	};
+
+	let (r, g, b) = rgb.get_value();
+	let linear = [to_linear(r), to_linear(g), to_linear(b)];
+	let [new_r, new_g, new_b] = matrix.map(|row| to_srgb(row[0] * linear[0] + row[1] * linear[1] + row[2] * linear[2]));
+
+	let result = Rgb::Val(new_r, new_g, new_b);
+	d(&format!("color::simulate_rgb() {:?} -> {:?}", rgb, result), 5, Dt::Log, options, &mut std::io::stdout());
+	result
+}
+
+/// Run a hex color through [`simulate_rgb()`] and leave `transparent` alone
+fn simulate_hex(hex: String, options: &Options) -> String {
+	if options.simulate.is_none() || hex == "transparent" {
+		hex
+	} else {
+		rgb2hex(&simulate_rgb(&hex2rgb(&hex, options), options), options)
+	}
+}
+
+/// Convert RGB colors to the opening ansi escape sequence for the color support given
+fn rgb2ansi(rgb: &Rgb, layer: ColorLayer, color_support: &TermColorSupport) -> String {
+	match color_support {
+		TermColorSupport::NoColor => String::from(""),
+		TermColorSupport::Ansi16 => rgb2ansi_16(rgb, layer),
+		TermColorSupport::Ansi256 => rgb2ansi_256(rgb, layer),
+		TermColorSupport::Ansi16m => rgb2ansi_16m(rgb, layer),
+	}
+}
+
 /// Convert RGB colors to the opening ansi escape sequence for consoles supporting 16 million colors (`truecolor`)
 ///
 /// ```rust
@@ -679,6 +808,151 @@ pub fn get_term_color_support() -> TermColorSupport {
 	}
 }
 
+/// Swap colors that can't be read on the `terminal_bg` of [`Options`] for ones that can
+///
+/// On a light terminal the system color, white and bright white turn black, on a dark terminal black turns white.
+/// Colors are only swapped when the terminal shows through, i.e. for a transparent `background`.
+///
+/// ```rust
+/// extern crate cfonts;
+///
+/// use cfonts::{BgColors, Colors, Options, TerminalBg};
+/// use cfonts::color::get_readable_color;
+///
+/// let mut options = Options::default();
+/// options.terminal_bg = TerminalBg::Light;
+/// assert_eq!(get_readable_color(&Colors::System, &options), Colors::Black);
+/// assert_eq!(get_readable_color(&Colors::WhiteBright, &options), Colors::Black);
+/// assert_eq!(get_readable_color(&Colors::Red, &options), Colors::Red);
+///
+/// options.terminal_bg = TerminalBg::Dark;
+/// assert_eq!(get_readable_color(&Colors::Black, &options), Colors::White);
+/// assert_eq!(get_readable_color(&Colors::System, &options), Colors::System);
+///
+/// options.background = BgColors::Black;
+/// assert_eq!(get_readable_color(&Colors::Black, &options), Colors::Black);
+/// ```
+pub fn get_readable_color(color: &Colors, options: &Options) -> Colors {
+	if options.background != BgColors::Transparent {
+		return color.clone();
+	}
+
+	match (color, &options.terminal_bg) {
+		(Colors::System | Colors::White | Colors::WhiteBright, TerminalBg::Light) => Colors::Black,
+		(Colors::Black, TerminalBg::Dark) => Colors::White,
+		(color, _) => color.clone(),
+	}
+}
+
+/// Pick a color out of [`CANDY_COLORS`] for a [`CandyPosition`]
+///
+/// On a light `terminal_bg` the color is picked out of [`LIGHT_CANDY_COLORS`] instead.
+/// With a `seed` in [`Options`] the same position will always get the same color.
+/// The `candy_mode` of [`Options`] decides which parts of the position are taken into account.
+///
+/// ```rust
+/// extern crate cfonts;
+///
+/// use cfonts::{CandyMode, Options};
+/// use cfonts::color::{get_candy_color, CandyPosition, CANDY_COLORS};
+///
+/// let mut options = Options::default();
+/// options.seed = Some(42);
+/// options.candy_mode = CandyMode::Glyph;
+///
+/// let first_row = CandyPosition { letter: 3, row: 0, ..CandyPosition::default() };
+/// let second_row = CandyPosition { letter: 3, row: 1, ..CandyPosition::default() };
+///
+/// assert!(CANDY_COLORS.contains(&get_candy_color(&first_row, &options)));
+/// assert_eq!(get_candy_color(&first_row, &options), get_candy_color(&second_row, &options));
+/// ```
+pub fn get_candy_color(position: &CandyPosition, options: &Options) -> Colors {
+	d("color::get_candy_color()", 5, Dt::Head, options, &mut std::io::stdout());
+	d(
+		&format!("color::get_candy_color()\nposition:{:?}\ncandy_mode:{:?}", position, options.candy_mode),
+		5,
+		Dt::Log,
+		options,
+		&mut std::io::stdout(),
+	);
+
+	let palette = match options.terminal_bg {
+		TerminalBg::Light => &LIGHT_CANDY_COLORS[..],
+		TerminalBg::Auto | TerminalBg::Dark => &CANDY_COLORS[..],
+	};
+
+	let result = match options.seed {
+		None => palette.choose(&mut rand::thread_rng()).unwrap().clone(),
+		Some(seed) => {
+			// each position gets its own stream of the seeded rng so the order we paint in doesn't matter
+			let stream = match options.candy_mode {
+				CandyMode::Segment => ((position.letter as u64) << 32) | ((position.row as u64) << 16) | position.layer as u64,
+				CandyMode::Glyph => position.letter as u64,
+				CandyMode::Row => ((position.line as u64) << 16) | position.row as u64,
+				CandyMode::Layer => position.layer as u64,
+			};
+			let mut rng = ChaCha8Rng::seed_from_u64(seed);
+			rng.set_stream(stream);
+			palette.choose(&mut rng).unwrap().clone()
+		}
+	};
+
+	d(&format!("color::get_candy_color() -> {:?}", result), 5, Dt::Log, options, &mut std::io::stdout());
+	result
+}
+
+/// Return the color mode a render should use by resolving [`ColorMode::Auto`] via [`get_term_color_support()`]
+///
+/// ```rust
+/// extern crate cfonts;
+///
+/// use cfonts::{ColorMode, Options};
+/// use cfonts::color::resolve_color_mode;
+///
+/// let mut options = Options::default();
+/// options.color_mode = ColorMode::Ansi256;
+/// assert_eq!(resolve_color_mode(&options), ColorMode::Ansi256);
+///
+/// options.color_mode = ColorMode::Auto;
+/// assert_ne!(resolve_color_mode(&options), ColorMode::Auto);
+/// ```
+pub fn resolve_color_mode(options: &Options) -> ColorMode {
+	match options.color_mode {
+		ColorMode::Auto => match get_term_color_support() {
+			TermColorSupport::Ansi16m => ColorMode::Truecolor,
+			TermColorSupport::Ansi256 => ColorMode::Ansi256,
+			TermColorSupport::Ansi16 => ColorMode::Ansi16,
+			TermColorSupport::NoColor => ColorMode::NoColor,
+		},
+		ref mode => mode.clone(),
+	}
+}
+
+/// Return the color support for the `color_mode` of the given [`Options`]
+///
+/// ```rust
+/// extern crate cfonts;
+///
+/// use cfonts::{ColorMode, Options};
+/// use cfonts::color::{get_color_support, TermColorSupport};
+///
+/// let mut options = Options::default();
+/// options.color_mode = ColorMode::NoColor;
+/// assert_eq!(get_color_support(&options), TermColorSupport::NoColor);
+///
+/// options.color_mode = ColorMode::Truecolor;
+/// assert_eq!(get_color_support(&options), TermColorSupport::Ansi16m);
+/// ```
+pub fn get_color_support(options: &Options) -> TermColorSupport {
+	match options.color_mode {
+		ColorMode::Auto => get_term_color_support(),
+		ColorMode::NoColor => TermColorSupport::NoColor,
+		ColorMode::Ansi16 => TermColorSupport::Ansi16,
+		ColorMode::Ansi256 => TermColorSupport::Ansi256,
+		ColorMode::Truecolor => TermColorSupport::Ansi16m,
+	}
+}
+
 /// Return the start and end of an ansi escape sequence for a given [`Colors`]
 ///
 /// ```rust
@@ -693,11 +967,47 @@ pub fn get_term_color_support() -> TermColorSupport {
 /// assert_eq!(get_foreground_color(&Colors::Blue), (String::from("\x1b[34m"), String::from("\x1b[39m")));
 /// ```
 pub fn get_foreground_color(color: &Colors) -> (String, String) {
-	let color_support = get_term_color_support();
+	get_foreground_color_for(color, &Options::default())
+}
+
+/// Return the start and end of an ansi escape sequence for a given [`Colors`] using the `color_mode` of [`Options`]
+///
+/// ```rust
+/// extern crate cfonts;
+///
+/// use cfonts::{ColorMode, Colors, Options, Rgb};
+/// use cfonts::color::get_foreground_color_for;
+///
+/// let mut options = Options::default();
+/// options.color_mode = ColorMode::Ansi256;
+///
+/// assert_eq!(
+///     get_foreground_color_for(&Colors::Rgb(Rgb::Val(255, 0, 0)), &options),
+///     (String::from("\x1b[38;5;196m"), String::from("\x1b[39m"))
+/// );
+///
+/// options.color_mode = ColorMode::NoColor;
+/// assert_eq!(get_foreground_color_for(&Colors::Red, &options), (String::from(""), String::from("")));
+/// ```
+pub fn get_foreground_color_for(color: &Colors, options: &Options) -> (String, String) {
+	let color_support = get_color_support(options);
 	if color_support == TermColorSupport::NoColor {
 		return (String::from(""), String::from(""));
 	}
 
+	// a simulated color vision deficiency turns every color into the rgb color the viewer would see
+	if options.simulate.is_some() {
+		match color {
+			Colors::System => {}
+			Colors::Candy => {
+				return get_foreground_color_for(&get_candy_color(&CandyPosition::default(), options), options);
+			}
+			_ => {
+				let rgb = hex2rgb(&color2hex(color, options), options);
+				return (rgb2ansi(&rgb, ColorLayer::Foreground, &color_support), String::from("\x1b[39m"));
+			}
+		}
+	}
 	let start = match color {
 		Colors::System => String::from("\x1b[39m"),
 		Colors::Black => String::from("\x1b[30m"),
@@ -716,19 +1026,8 @@ pub fn get_foreground_color(color: &Colors) -> (String, String) {
 		Colors::MagentaBright => String::from("\x1b[95m"),
 		Colors::CyanBright => String::from("\x1b[96m"),
 		Colors::WhiteBright => String::from("\x1b[97m"),
-		Colors::Candy => {
-			let colors = [
-				"\x1b[31m", "\x1b[32m", "\x1b[33m", "\x1b[35m", "\x1b[36m", "\x1b[91m", "\x1b[92m", "\x1b[93m", "\x1b[94m",
-				"\x1b[95m", "\x1b[96m",
-			];
-			String::from(*colors.choose(&mut rand::thread_rng()).unwrap())
-		}
-		Colors::Rgb(rgb) => match color_support {
-			TermColorSupport::NoColor => String::from(""),
-			TermColorSupport::Ansi16 => rgb2ansi_16(rgb, ColorLayer::Foreground),
-			TermColorSupport::Ansi256 => rgb2ansi_256(rgb, ColorLayer::Foreground),
-			TermColorSupport::Ansi16m => rgb2ansi_16m(rgb, ColorLayer::Foreground),
-		},
+		Colors::Candy => get_foreground_color_for(&get_candy_color(&CandyPosition::default(), options), options).0,
+		Colors::Rgb(rgb) => rgb2ansi(rgb, ColorLayer::Foreground, &color_support),
 	};
 
 	// we use the same "reset code" for all foreground colors and it's to set the color (only) back to system color
@@ -753,11 +1052,39 @@ pub fn get_foreground_color(color: &Colors) -> (String, String) {
 /// assert_eq!(get_background_color(&BgColors::Blue), (String::from("\x1b[44m"), String::from("\x1b[49m")));
 /// ```
 pub fn get_background_color(color: &BgColors) -> (String, String) {
-	let color_support = get_term_color_support();
+	get_background_color_for(color, &Options::default())
+}
+
+/// Return the start and end of an ansi escape sequence for a given [`BgColors`] using the `color_mode` of [`Options`]
+///
+/// ```rust
+/// extern crate cfonts;
+///
+/// use cfonts::{BgColors, ColorMode, Options, Rgb};
+/// use cfonts::color::get_background_color_for;
+///
+/// let mut options = Options::default();
+/// options.color_mode = ColorMode::Ansi16;
+///
+/// assert_eq!(
+///     get_background_color_for(&BgColors::Rgb(Rgb::Val(255, 0, 0)), &options),
+///     (String::from("\x1b[101m"), String::from("\x1b[49m"))
+/// );
+///
+/// options.color_mode = ColorMode::NoColor;
+/// assert_eq!(get_background_color_for(&BgColors::Red, &options), (String::from(""), String::from("")));
+/// ```
+pub fn get_background_color_for(color: &BgColors, options: &Options) -> (String, String) {
+	let color_support = get_color_support(options);
 	if color_support == TermColorSupport::NoColor {
 		return (String::from(""), String::from(""));
 	}
 
+	// a simulated color vision deficiency turns every color into the rgb color the viewer would see
+	if options.simulate.is_some() && *color != BgColors::Transparent {
+		let rgb = hex2rgb(&bgcolor2hex(color, options), options);
+		return (rgb2ansi(&rgb, ColorLayer::Background, &color_support), String::from("\x1b[49m"));
+	}
 	let start = match color {
 		BgColors::Transparent => String::from("\x1b[49m"),
 		BgColors::Black => String::from("\x1b[40m"),
@@ -776,12 +1103,7 @@ pub fn get_background_color(color: &BgColors) -> (String, String) {
 		BgColors::MagentaBright => String::from("\x1b[105m"),
 		BgColors::CyanBright => String::from("\x1b[106m"),
 		BgColors::WhiteBright => String::from("\x1b[107m"),
-		BgColors::Rgb(rgb) => match color_support {
-			TermColorSupport::NoColor => String::from(""),
-			TermColorSupport::Ansi16 => rgb2ansi_16(rgb, ColorLayer::Background),
-			TermColorSupport::Ansi256 => rgb2ansi_256(rgb, ColorLayer::Background),
-			TermColorSupport::Ansi16m => rgb2ansi_16m(rgb, ColorLayer::Background),
-		},
+		BgColors::Rgb(rgb) => rgb2ansi(rgb, ColorLayer::Background, &color_support),
 	};
 
 	// reset only background to system color
@@ -0,0 +1,386 @@
+//! The contents of this module is all about generating shell completion scripts out of our [`CLIOPTIONS`]
+use crate::config::{
+	Align, BgColors, Border, CandyMode, CliOption, ColorMode, Colors, Cvd, Effect, Env, Fonts, OptionType, Options,
+	OutputFormat, Shell, TerminalBg, CLIOPTIONS,
+};
+use crate::debug::{d, Dt};
+use crate::gradient::GradientPresetRegistry;
+use crate::helpers::get_config_dir;
+use crate::theme::ThemeRegistry;
+
+/// What the value of an option can be completed with
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Completion {
+	/// The option is a flag without a value
+	Flag,
+	/// The option takes a value we can't suggest anything for
+	Any,
+	/// The option may take a value we can't suggest anything for
+	Optional,
+	/// The option takes a file path
+	File,
+	/// The option takes one of these values
+	Values(Vec<String>),
+}
+
+/// The values we can only know at runtime because users can add their own
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct Completions {
+	/// The names of all gradient presets
+	pub gradients: Vec<String>,
+	/// The names of all themes
+	pub themes: Vec<String>,
+}
+
+/// Split one of our `list()` strings into its values, leaving out descriptions like the one for hex colors
+fn split_list(list: &str) -> Vec<String> {
+	list.split(", ").filter(|value| !value.contains(' ')).map(String::from).collect()
+}
+
+/// All flags of an option with the shortcuts first
+fn get_flags<'a>(option: &CliOption<'a>) -> Vec<&'a str> {
+	[option.shortcut, option.fallback_shortcut, option.name].into_iter().filter(|flag| !flag.is_empty()).collect()
+}
+
+/// Quote a value in single quotes for shells that escape a single quote by closing the quotes
+fn quote_posix(value: &str) -> String {
+	format!("'{}'", value.replace('\'', "'\\''"))
+}
+
+/// Quote a value in single quotes for shells that escape a single quote by doubling it
+fn quote_double(value: &str) -> String {
+	format!("'{}'", value.replace('\'', "''"))
+}
+
+impl Completions {
+	/// Collect the built-in gradient presets and themes plus the ones inside [`get_config_dir()`]
+	pub fn load(options: &Options) -> Result<Self, String> {
+		d("completions::Completions::load()", 3, Dt::Head, options, &mut std::io::stdout());
+
+		let mut presets = GradientPresetRegistry::default();
+		// presets that can't be read are left out so completions always work
+		let _ = presets.load_config(options);
+
+		let mut themes = ThemeRegistry::default();
+		if let Some(dir) = get_config_dir() {
+			themes.load_dir(&dir.join("themes"), options);
+		}
+
+		Ok(Completions {
+			gradients: presets.list(),
+			themes: themes.list(),
+		})
+	}
+
+	/// What the value of an option can be completed with
+	///
+	/// ```rust
+	/// extern crate cfonts;
+	///
+	/// use cfonts::config::CLIOPTIONS;
+	/// use cfonts::completions::{Completion, Completions};
+	///
+	/// let completions = Completions::default();
+	/// let option = |key: &str| CLIOPTIONS.iter().find(|option| option.key == key).unwrap();
+	///
+	/// assert_eq!(completions.get(option("spaceless")), Completion::Flag);
+	/// assert_eq!(completions.get(option("output")), Completion::File);
+	/// assert_eq!(
+	///     completions.get(option("align")),
+	///     Completion::Values(vec![
+	///         String::from("left"),
+	///         String::from("center"),
+	///         String::from("right"),
+	///         String::from("top"),
+	///         String::from("bottom"),
+	///     ])
+	/// );
+	/// ```
+	pub fn get(&self, option: &CliOption) -> Completion {
+		match option.kind {
+			OptionType::Bool => Completion::Flag,
+			OptionType::Text
+			| OptionType::Number
+			| OptionType::Seed
+			| OptionType::Duration
+			| OptionType::Padding
+			| OptionType::ShadowOffset
+			| OptionType::ShadowChar => Completion::Any,
+			OptionType::Clock => Completion::Optional,
+			OptionType::Output => Completion::File,
+			OptionType::Font => Completion::Values(split_list(&Fonts::list())),
+			OptionType::Colors | OptionType::BorderColor | OptionType::ShadowColor => {
+				Completion::Values(split_list(&Colors::list()))
+			}
+			OptionType::BgColor => Completion::Values(split_list(&BgColors::list())),
+			OptionType::Align => Completion::Values(split_list(&Align::list())),
+			OptionType::Env => Completion::Values(split_list(&Env::list())),
+			OptionType::ColorMode => Completion::Values(split_list(&ColorMode::list())),
+			OptionType::CandyMode => Completion::Values(split_list(&CandyMode::list())),
+			OptionType::TerminalBg => Completion::Values(split_list(&TerminalBg::list())),
+			OptionType::Simulate => Completion::Values(split_list(&Cvd::list())),
+			OptionType::OutputFormat => Completion::Values(split_list(&OutputFormat::list())),
+			OptionType::Animate => Completion::Values(split_list(&Effect::list())),
+			OptionType::Shell => Completion::Values(split_list(&Shell::list())),
+			OptionType::Border => Completion::Values(split_list(&format!("none, {}", Border::list()))),
+			OptionType::Gradient => Completion::Values(self.gradients.clone()),
+			OptionType::Theme => Completion::Values(self.themes.clone()),
+		}
+	}
+
+	/// The completion script for bash
+	pub fn bash(&self) -> String {
+		let mut cases = String::new();
+		let mut no_values = Vec::new();
+
+		for option in CLIOPTIONS.iter() {
+			let flags = get_flags(option).join("|");
+			match self.get(option) {
+				Completion::Values(values) => {
+					cases += &format!(
+						"\t\t{})\n\t\t\tCOMPREPLY=($(compgen -W {} -- \"${{cur}}\"))\n\t\t\treturn 0\n\t\t\t;;\n",
+						flags,
+						quote_posix(&values.join(" "))
+					);
+				}
+				Completion::File => {
+					cases +=
+						&format!("\t\t{})\n\t\t\tCOMPREPLY=($(compgen -f -- \"${{cur}}\"))\n\t\t\treturn 0\n\t\t\t;;\n", flags);
+				}
+				Completion::Any => no_values.push(flags),
+				Completion::Flag | Completion::Optional => {}
+			}
+		}
+		if !no_values.is_empty() {
+			cases += &format!("\t\t{})\n\t\t\treturn 0\n\t\t\t;;\n", no_values.join("|"));
+		}
+
+		let all_flags = CLIOPTIONS.iter().flat_map(get_flags).collect::<Vec<&str>>().join(" ");
+
+		format!(
+			"_cfonts() {{\n\
+			\tlocal cur prev\n\
+			\tcur=\"${{COMP_WORDS[COMP_CWORD]}}\"\n\
+			\tprev=\"${{COMP_WORDS[COMP_CWORD-1]}}\"\n\
+			\n\
+			\tcase \"${{prev}}\" in\n\
+			{}\
+			\tesac\n\
+			\n\
+			\tif [[ \"${{cur}}\" == -* ]]; then\n\
+			\t\tCOMPREPLY=($(compgen -W {} -- \"${{cur}}\"))\n\
+			\tfi\n\
+			\treturn 0\n\
+			}}\n\
+			\n\
+			complete -F _cfonts cfonts\n",
+			cases,
+			quote_posix(&all_flags)
+		)
+	}
+
+	/// The completion script for zsh
+	pub fn zsh(&self) -> String {
+		let mut specs = String::new();
+
+		for option in CLIOPTIONS.iter() {
+			let flags = get_flags(option);
+			let description = option.description.replace('\\', "\\\\").replace('[', "\\[").replace(']', "\\]");
+			let message = option.key.replace('_', " ");
+			let value = match self.get(option) {
+				Completion::Flag => String::new(),
+				Completion::Any => format!(":{}:", message),
+				Completion::Optional => format!("::{}:", message),
+				Completion::File => format!(":{}:_files", message),
+				Completion::Values(values) => format!(":{}:({})", message, values.join(" ")),
+			};
+
+			let spec = match flags.len() {
+				1 => quote_posix(&format!("{}[{}]{}", flags[0], description, value)),
+				_ => format!(
+					"{}{{{}}}{}",
+					quote_posix(&format!("({})", flags.join(" "))),
+					flags.join(","),
+					quote_posix(&format!("[{}]{}", description, value))
+				),
+			};
+			specs += &format!("\t\t{} \\\n", spec);
+		}
+
+		format!(
+			"#compdef cfonts\n\
+			\n\
+			_cfonts() {{\n\
+			\t_arguments -s \\\n\
+			{}\
+			\t\t'1:text:'\n\
+			}}\n\
+			\n\
+			if [ \"$funcstack[1]\" = \"_cfonts\" ]; then\n\
+			\t_cfonts \"$@\"\n\
+			else\n\
+			\tcompdef _cfonts cfonts\n\
+			fi\n",
+			specs
+		)
+	}
+
+	/// The completion script for fish
+	pub fn fish(&self) -> String {
+		let quote = |value: &str| format!("'{}'", value.replace('\\', "\\\\").replace('\'', "\\'"));
+		let mut output = String::from("complete -c cfonts -f\n");
+
+		for option in CLIOPTIONS.iter() {
+			let mut line = String::from("complete -c cfonts");
+			for flag in get_flags(option) {
+				match flag.strip_prefix("--") {
+					Some(long) => line += &format!(" -l {}", long),
+					None => line += &format!(" -s {}", flag.trim_start_matches('-')),
+				}
+			}
+			line += &format!(" -d {}", quote(option.description));
+			match self.get(option) {
+				Completion::Flag | Completion::Optional => {}
+				Completion::Any => line += " -x",
+				Completion::File => line += " -r -F",
+				Completion::Values(values) => line += &format!(" -x -a {}", quote(&values.join(" "))),
+			}
+			output += &line;
+			output += "\n";
+		}
+
+		output
+	}
+
+	/// The completion script for elvish
+	pub fn elvish(&self) -> String {
+		let list = |values: &[String]| values.iter().map(|value| quote_double(value)).collect::<Vec<String>>().join(" ");
+		let mut files = Vec::new();
+		let mut values = String::new();
+
+		for option in CLIOPTIONS.iter() {
+			for flag in get_flags(option) {
+				match self.get(option) {
+					Completion::File => files.push(flag.to_string()),
+					Completion::Any => values += &format!("\t\t&{}=[]\n", quote_double(flag)),
+					Completion::Values(option_values) => {
+						values += &format!("\t\t&{}=[{}]\n", quote_double(flag), list(&option_values));
+					}
+					Completion::Flag | Completion::Optional => {}
+				}
+			}
+		}
+		let all_flags = CLIOPTIONS.iter().flat_map(get_flags).map(String::from).collect::<Vec<String>>();
+
+		format!(
+			"use str\n\
+			\n\
+			set edit:completion:arg-completer[cfonts] = {{|@words|\n\
+			\tvar current = $words[-1]\n\
+			\tvar previous = $words[-2]\n\
+			\tvar files = [{}]\n\
+			\tvar values = [\n\
+			{}\
+			\t]\n\
+			\tvar flags = [{}]\n\
+			\n\
+			\tif (has-value $files $previous) {{\n\
+			\t\tedit:complete-filename $current\n\
+			\t}} elif (has-key $values $previous) {{\n\
+			\t\tall $values[$previous]\n\
+			\t}} elif (str:has-prefix $current '-') {{\n\
+			\t\tall $flags\n\
+			\t}}\n\
+			}}\n",
+			list(&files),
+			values,
+			list(&all_flags)
+		)
+	}
+
+	/// The completion script for PowerShell
+	pub fn powershell(&self) -> String {
+		let list = |values: &[String]| values.iter().map(|value| quote_double(value)).collect::<Vec<String>>().join(", ");
+		let mut files = Vec::new();
+		let mut values = String::new();
+
+		for option in CLIOPTIONS.iter() {
+			for flag in get_flags(option) {
+				match self.get(option) {
+					Completion::File => files.push(flag.to_string()),
+					Completion::Any => values += &format!("\t\t{} = @()\n", quote_double(flag)),
+					Completion::Values(option_values) => {
+						values += &format!("\t\t{} = @({})\n", quote_double(flag), list(&option_values));
+					}
+					Completion::Flag | Completion::Optional => {}
+				}
+			}
+		}
+		let all_flags = CLIOPTIONS.iter().flat_map(get_flags).map(String::from).collect::<Vec<String>>();
+
+		format!(
+			"Register-ArgumentCompleter -Native -CommandName 'cfonts' -ScriptBlock {{\n\
+			\tparam($wordToComplete, $commandAst, $cursorPosition)\n\
+			\n\
+			\t$words = @($commandAst.CommandElements | Where-Object {{ $_.Extent.StartOffset -lt $cursorPosition }} | ForEach-Object {{ $_.ToString() }})\n\
+			\tif ($wordToComplete) {{\n\
+			\t\t$previous = [string]$words[-2]\n\
+			\t}} else {{\n\
+			\t\t$previous = [string]$words[-1]\n\
+			\t}}\n\
+			\n\
+			\t$files = @({})\n\
+			\t$values = @{{\n\
+			{}\
+			\t}}\n\
+			\t$flags = @({})\n\
+			\n\
+			\tif ($files -contains $previous) {{\n\
+			\t\treturn\n\
+			\t}} elseif ($values.ContainsKey($previous)) {{\n\
+			\t\t$candidates = $values[$previous]\n\
+			\t}} elseif ($wordToComplete.StartsWith('-')) {{\n\
+			\t\t$candidates = $flags\n\
+			\t}} else {{\n\
+			\t\t$candidates = @()\n\
+			\t}}\n\
+			\n\
+			\t$candidates | Where-Object {{ $_ -like \"$wordToComplete*\" }} | ForEach-Object {{\n\
+			\t\t[System.Management.Automation.CompletionResult]::new($_, $_, 'ParameterValue', $_)\n\
+			\t}}\n\
+			}}\n",
+			list(&files),
+			values,
+			list(&all_flags)
+		)
+	}
+}
+
+/// Generate the completion script for a shell
+///
+/// ```rust
+/// extern crate cfonts;
+///
+/// use cfonts::{Options, Shell};
+/// use cfonts::completions::generate;
+///
+/// let script = generate(&Shell::Bash, &Options::default()).unwrap();
+/// assert!(script.contains("complete -F _cfonts cfonts"));
+/// assert!(script.contains("-f|--font)"));
+/// ```
+pub fn generate(shell: &Shell, options: &Options) -> Result<String, String> {
+	d("completions::generate()", 1, Dt::Head, options, &mut std::io::stdout());
+	d(&format!("completions::generate()\nshell:{:?}", shell), 1, Dt::Log, options, &mut std::io::stdout());
+
+	let completions = Completions::load(options)?;
+	let script = match shell {
+		Shell::Bash => completions.bash(),
+		Shell::Zsh => completions.zsh(),
+		Shell::Fish => completions.fish(),
+		Shell::Elvish => completions.elvish(),
+		Shell::Powershell => completions.powershell(),
+	};
+
+	d(&format!("completions::generate() -> {:?}", script), 1, Dt::Log, options, &mut std::io::stdout());
+	Ok(script)
+}
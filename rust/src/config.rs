@@ -1,5 +1,6 @@
 //! The contents of this module is all about the configuration of this package
 extern crate strum;
+use serde::{Deserialize, Serialize};
 use strum::IntoEnumIterator;
 use strum_macros::EnumIter;
 
@@ -9,7 +10,7 @@ use crate::helpers::first_letter_to_lowercase;
 /// The `Fonts` enum includes all font options you have for the cfonts output
 ///
 /// Find out more about what each font looks like in the [`Readme`](https://github.com/dominikwilkowski/cfonts/blob/released/README.md)
-#[derive(EnumIter, Debug, Clone, PartialEq, Eq, Hash)]
+#[derive(EnumIter, Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum Fonts {
 	/// ![The "console" cfonts font](https://raw.githubusercontent.com/dominikwilkowski/cfonts/released/img/console.png)
 	FontConsole,
@@ -44,8 +45,8 @@ pub enum Fonts {
 /// ![The color usage and output of cfonts](https://raw.githubusercontent.com/dominikwilkowski/cfonts/released/img/colors.png)
 ///
 /// > 💡  Ansi color support is automatically detected and down-scaled if needed.
-/// Colors also respect both `NO_COLOR` and `FORCE_COLOR` env vars.
-#[derive(EnumIter, Debug, Clone, PartialEq, Eq)]
+/// > Colors also respect both `NO_COLOR` and `FORCE_COLOR` env vars.
+#[derive(EnumIter, Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub enum Colors {
 	/// Uses the system font defined by your console
 	System,
@@ -77,8 +78,8 @@ pub enum Colors {
 /// ![The background color usage and output of cfonts](https://raw.githubusercontent.com/dominikwilkowski/cfonts/released/img/background.png)
 ///
 /// > 💡  Ansi color support is automatically detected and down-scaled if needed.
-/// Colors also respect both `NO_COLOR` and `FORCE_COLOR` env vars.
-#[derive(EnumIter, Debug, Clone, PartialEq, Eq)]
+/// > Colors also respect both `NO_COLOR` and `FORCE_COLOR` env vars.
+#[derive(EnumIter, Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub enum BgColors {
 	/// Use the system background defined in your console
 	Transparent,
@@ -106,7 +107,7 @@ pub enum BgColors {
 /// The `Env` enum includes all supported environment options.
 ///
 /// ![The env option and it's output with cfonts](https://raw.githubusercontent.com/dominikwilkowski/cfonts/released/img/env.png)
-#[derive(EnumIter, Debug, Clone, PartialEq, Eq)]
+#[derive(EnumIter, Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub enum Env {
 	/// A cli environment means we render colors as ansi escape sequences
 	Cli,
@@ -118,7 +119,7 @@ pub enum Env {
 /// The `Align` enum includes all supported alignment options.
 ///
 /// ![The align option and it's output with cfonts](https://raw.githubusercontent.com/dominikwilkowski/cfonts/released/img/align.png)
-#[derive(EnumIter, Debug, Clone, PartialEq, Eq)]
+#[derive(EnumIter, Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub enum Align {
 	Left,
 	Center,
@@ -129,6 +130,39 @@ pub enum Align {
 	Bottom,
 }
 
+/// The `ColorMode` enum includes all color depth options you can pin the output to.
+///
+/// > 💡  `Auto` detects the color support of `stdout` and respects both `NO_COLOR` and `FORCE_COLOR` env vars.
+/// > Any other mode ignores both env vars and renders for that color depth.
+#[derive(EnumIter, Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ColorMode {
+	/// Detect the color support of the current console
+	Auto,
+	/// No colors at all
+	NoColor,
+	/// 8 base colors + 8 bright colors
+	Ansi16,
+	/// 256 colors
+	Ansi256,
+	/// 16 million colors via truecolor RGB
+	Truecolor,
+}
+
+/// The `CandyMode` enum includes all options of how [`Colors::Candy`] picks its colors.
+///
+/// > 💡  Combine it with the `seed` option of [`Options`] to get the same colors on every run.
+#[derive(EnumIter, Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum CandyMode {
+	/// Each colored segment of each letter gets its own color
+	Segment,
+	/// Each letter gets its own color
+	Glyph,
+	/// Each row of the output gets its own color
+	Row,
+	/// Each color layer of the font gets its own color
+	Layer,
+}
+
 impl Fonts {
 	/// Implementing a list method for each enum so we can communicate in plain text what is supported
 	pub fn list() -> String {
@@ -198,37 +232,227 @@ impl Align {
 	}
 }
 
-/// Presets for transitions - undocumented
+impl ColorMode {
+	/// Implementing a list method for each enum so we can communicate in plain text what is supported
+	pub fn list() -> String {
+		let mut list = vec![];
+		for mode in ColorMode::iter() {
+			let name = match mode {
+				ColorMode::Auto => "auto",
+				ColorMode::NoColor => "none",
+				ColorMode::Ansi16 => "16",
+				ColorMode::Ansi256 => "256",
+				ColorMode::Truecolor => "truecolor",
+			};
+			list.push(name.to_string());
+		}
+		list.join(", ")
+	}
+}
+
+impl CandyMode {
+	/// Implementing a list method for each enum so we can communicate in plain text what is supported
+	pub fn list() -> String {
+		let mut list = vec![];
+		for mode in CandyMode::iter() {
+			let name = format!("{:?}", mode);
+			list.push(name.to_lowercase());
+		}
+		list.join(", ")
+	}
+}
+
+/// The `TerminalBg` enum includes all options for the background of the terminal cfonts renders into.
+///
+/// > 💡  With `Auto` cfonts asks the terminal for its background color and falls back to the `COLORFGBG`
+/// > environment variable before it assumes a dark terminal.
+#[derive(EnumIter, Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum TerminalBg {
+	/// Detect the background of the terminal
+	Auto,
+	/// A light terminal background
+	Light,
+	/// A dark terminal background
+	Dark,
+}
+
+impl TerminalBg {
+	/// Implementing a list method for each enum so we can communicate in plain text what is supported
+	pub fn list() -> String {
+		let mut list = vec![];
+		for bg in TerminalBg::iter() {
+			let name = format!("{:?}", bg);
+			list.push(name.to_lowercase());
+		}
+		list.join(", ")
+	}
+}
+
+/// The `Cvd` enum includes all color vision deficiencies cfonts can simulate.
+///
+/// > 💡  Use it to check if your output is readable for everyone.
+#[derive(EnumIter, Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Cvd {
+	/// No red cones
+	Protanopia,
+	/// No green cones
+	Deuteranopia,
+	/// No blue cones
+	Tritanopia,
+	/// No color vision at all
+	Achromatopsia,
+}
+
+impl Cvd {
+	/// Implementing a list method for each enum so we can communicate in plain text what is supported
+	pub fn list() -> String {
+		let mut list = vec![];
+		for cvd in Cvd::iter() {
+			let name = format!("{:?}", cvd);
+			list.push(name.to_lowercase());
+		}
+		list.join(", ")
+	}
+}
+
+/// The `OutputFormat` enum includes all formats cfonts can write its output in.
+#[derive(EnumIter, Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum OutputFormat {
+	/// The output as ansi escape sequences or inline styled HTML depending on the [`Env`]
+	Text,
+	/// HTML with class names for each color and a separate stylesheet
+	Html,
+	/// JSON with the rows, the size, the resolved options and the colors of each cell
+	Json,
+	/// Plain text without any ansi escape sequences or HTML
+	Plain,
+}
+
+impl OutputFormat {
+	/// Implementing a list method for each enum so we can communicate in plain text what is supported
+	pub fn list() -> String {
+		let mut list = vec![];
+		for format in OutputFormat::iter() {
+			let name = format!("{:?}", format);
+			list.push(name.to_lowercase());
+		}
+		list.join(", ")
+	}
+}
+
+/// The `Effect` enum includes all animations cfonts can play.
+#[derive(EnumIter, Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Effect {
+	/// Type the text one glyph at a time
+	Typewriter,
+	/// Reveal the output one line at a time from the top
+	Lines,
+	/// Reveal the output one column at a time from the left
+	Wipe,
+	/// Shift the colors of the gradient through the text
+	Cycle,
+}
+
+impl Effect {
+	/// Implementing a list method for each enum so we can communicate in plain text what is supported
+	pub fn list() -> String {
+		let mut list = vec![];
+		for effect in Effect::iter() {
+			let name = format!("{:?}", effect);
+			list.push(name.to_lowercase());
+		}
+		list.join(", ")
+	}
+}
+
+/// The `Border` enum includes all styles of frames that can be drawn around the output.
+#[derive(EnumIter, Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Border {
+	/// Thin lines with square corners `┌─┐`
+	Single,
+	/// Double lines `╔═╗`
+	Double,
+	/// Thin lines with rounded corners `╭─╮`
+	Rounded,
+	/// Thick lines `┏━┓`
+	Heavy,
+	/// Plain ASCII characters `+-+`
+	Ascii,
+}
+
+impl Border {
+	/// Implementing a list method for each enum so we can communicate in plain text what is supported
+	pub fn list() -> String {
+		let mut list = vec![];
+		for border in Border::iter() {
+			let name = format!("{:?}", border);
+			list.push(name.to_lowercase());
+		}
+		list.join(", ")
+	}
+}
+
+/// The `Shell` enum includes all shells cfonts can generate completions for.
+#[derive(EnumIter, Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Shell {
+	/// The Bourne Again SHell
+	Bash,
+	/// The Z shell
+	Zsh,
+	/// The friendly interactive shell
+	Fish,
+	/// The Elvish shell
+	Elvish,
+	/// Windows PowerShell and PowerShell Core
+	Powershell,
+}
+
+impl Shell {
+	/// Implementing a list method for each enum so we can communicate in plain text what is supported
+	pub fn list() -> String {
+		let mut list = vec![];
+		for shell in Shell::iter() {
+			let name = format!("{:?}", shell);
+			list.push(name.to_lowercase());
+		}
+		list.join(", ")
+	}
+}
+
+/// Presets for transitions - registered as built-in themes in [`crate::ThemeRegistry`]
 pub const GRADIENTS_PRIDE: [&str; 6] = ["#750787", "#004dff", "#008026", "#ffed00", "#ff8c00", "#e40303"];
-/// Presets for transitions - undocumented
+/// Presets for transitions - registered as built-in themes in [`crate::ThemeRegistry`]
 pub const GRADIENTS_AGENDER: [&str; 7] = [
 	"#000000", "#b9b9b9", "#ffffff", "#b8f483", "#ffffff", "#b9b9b9", "#000000",
 ];
-/// Presets for transitions - undocumented
+/// Presets for transitions - registered as built-in themes in [`crate::ThemeRegistry`]
 pub const GRADIENTS_AROMANTIC: [&str; 5] = ["#3da542", "#a7d379", "#ffffff", "#a9a9a9", "#000000"];
-/// Presets for transitions - undocumented
+/// Presets for transitions - registered as built-in themes in [`crate::ThemeRegistry`]
 pub const GRADIENTS_ASEXUAL: [&str; 4] = ["#000000", "#a3a3a3", "#ffffff", "#800080"];
-/// Presets for transitions - undocumented
+/// Presets for transitions - registered as built-in themes in [`crate::ThemeRegistry`]
 pub const GRADIENTS_BISEXUAL: [&str; 5] = ["#d60270", "#d60270", "#9b4f96", "#0038a8", "#0038a8"];
-/// Presets for transitions - undocumented
+/// Presets for transitions - registered as built-in themes in [`crate::ThemeRegistry`]
 pub const GRADIENTS_GENDERFLUID: [&str; 5] = ["#ff75a2", "#ffffff", "#be18d6", "#000000", "#333ebd"];
-/// Presets for transitions - undocumented
+/// Presets for transitions - registered as built-in themes in [`crate::ThemeRegistry`]
 pub const GRADIENTS_GENDERQUEER: [&str; 3] = ["#b57edc", "#ffffff", "#4a8123"];
-/// Presets for transitions - undocumented
+/// Presets for transitions - registered as built-in themes in [`crate::ThemeRegistry`]
 pub const GRADIENTS_INTERSEX: [&str; 5] = ["#ffd800", "#ffd800", "#7902aa", "#ffd800", "#ffd800"];
-/// Presets for transitions - undocumented
+/// Presets for transitions - registered as built-in themes in [`crate::ThemeRegistry`]
 pub const GRADIENTS_LESBIAN: [&str; 5] = ["#d52d00", "#ff9a56", "#ffffff", "#d362a4", "#a30262"];
-/// Presets for transitions - undocumented
+/// Presets for transitions - registered as built-in themes in [`crate::ThemeRegistry`]
 pub const GRADIENTS_NONBINARY: [&str; 4] = ["#fcf434", "#ffffff", "#9c5cd4", "#2c2c2c"];
-/// Presets for transitions - undocumented
+/// Presets for transitions - registered as built-in themes in [`crate::ThemeRegistry`]
 pub const GRADIENTS_PANSEXUAL: [&str; 3] = ["#ff218c", "#ffd800", "#21b1ff"];
-/// Presets for transitions - undocumented
+/// Presets for transitions - registered as built-in themes in [`crate::ThemeRegistry`]
 pub const GRADIENTS_POLYSEXUAL: [&str; 3] = ["#f61cb9", "#07d569", "#1c92f6"];
-/// Presets for transitions - undocumented
+/// Presets for transitions - registered as built-in themes in [`crate::ThemeRegistry`]
 pub const GRADIENTS_TRANSGENDER: [&str; 5] = ["#5bcefa", "#f5a9b8", "#ffffff", "#f5a9b8", "#5bcefa"];
 
 /// The `Options` struct includes all options cfonts takes to control it's output
-#[derive(Debug, Clone, PartialEq, Eq)]
+///
+/// Missing fields are filled in with their default when deserializing.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
 pub struct Options {
 	/// The text to be converted
 	pub text: String,
@@ -256,6 +480,22 @@ pub struct Options {
 	/// The maximum amount of letters to be printed per line
 	/// ![The max length option of cfonts](https://raw.githubusercontent.com/dominikwilkowski/cfonts/released/img/max-length.png)
 	pub max_length: u16,
+	/// To keep each line of text in a single row instead of breaking it at the width of the terminal
+	pub no_wrap: bool,
+	/// The style of the frame drawn around the output
+	pub border: Option<Border>,
+	/// The color of the frame; more than one color paints a gradient from left to right
+	pub border_color: Vec<Colors>,
+	/// The space between the frame and the text in the order top, right, bottom, left
+	pub padding: [u16; 4],
+	/// To draw a shadow behind the glyphs
+	pub shadow: bool,
+	/// How far the shadow is moved to the right and down
+	pub shadow_offset: [u16; 2],
+	/// The character the shadow is drawn with
+	pub shadow_char: char,
+	/// The color of the shadow
+	pub shadow_color: Colors,
 	/// Colors to be printed gradients between
 	/// ![The gradient option of cfonts](https://raw.githubusercontent.com/dominikwilkowski/cfonts/released/img/gradient.png)
 	pub gradient: Vec<String>,
@@ -265,15 +505,63 @@ pub struct Options {
 	/// An option to enable transitional gradients
 	/// ![The transition gradient option of cfonts](https://raw.githubusercontent.com/dominikwilkowski/cfonts/released/img/transition-gradient.png)
 	pub transition_gradient: bool,
+	/// How many characters the colors of a gradient are shifted to the right, used to animate gradients
+	pub gradient_phase: usize,
 	/// The environment to render for
 	/// ![The env option of cfonts](https://raw.githubusercontent.com/dominikwilkowski/cfonts/released/img/env.png)
 	pub env: Env,
+	/// The color depth to render for
+	pub color_mode: ColorMode,
+	/// The seed for the random colors of [`Colors::Candy`]; a random seed is picked for each render if `None`
+	pub seed: Option<u64>,
+	/// How [`Colors::Candy`] picks its colors
+	pub candy_mode: CandyMode,
+	/// The background of the terminal, used to pick readable colors
+	pub terminal_bg: TerminalBg,
+	/// To fail instead of warn when colors don't have enough contrast against the background
+	pub strict_contrast: bool,
+	/// The color vision deficiency all colors are transformed through
+	pub simulate: Option<Cvd>,
+	/// The file to write the output to instead of printing it, the type of file is picked by its extension
+	pub output: Option<String>,
+	/// The format of the output
+	pub output_format: OutputFormat,
+	/// To leave out the outer `<div>` of the html output format
+	pub html_fragment: bool,
+	/// To print the stylesheet of the html output format instead of its markup
+	pub html_css: bool,
+	/// To remove trailing whitespace from each line of the plain output format
+	pub trim: bool,
+	/// The animation to play instead of printing the output once
+	pub animate: Option<Effect>,
+	/// The frames per second of the animation
+	pub fps: u16,
+	/// How long the animation takes in milliseconds; each step of the animation gets its own frame if `None`
+	pub duration: Option<u64>,
+	/// To scroll the output through the width of the terminal in a loop instead of wrapping it
+	pub marquee: bool,
+	/// How many columns per second the marquee moves
+	pub speed: u16,
+	/// The format of a live clock to show instead of the text, see [`crate::clock::format_time()`]
+	pub clock: Option<String>,
+	/// How long a live countdown to show instead of the text runs in milliseconds
+	pub countdown: Option<u64>,
 	/// To show the help
 	/// ![The help option of cfonts](https://raw.githubusercontent.com/dominikwilkowski/cfonts/released/img/help.png)
 	pub help: bool,
 	/// To show the version
 	/// ![The version option of cfonts](https://raw.githubusercontent.com/dominikwilkowski/cfonts/released/img/version.png)
 	pub version: bool,
+	/// To show a preview of all gradient presets
+	pub list_gradients: bool,
+	/// To show the options after reading config files and the environment and where each came from
+	pub print_config: bool,
+	/// The shell to print a completion script for
+	pub completions: Option<Shell>,
+	/// To show the man page
+	pub man: bool,
+	/// To ignore command line flags we don't know instead of failing
+	pub lenient: bool,
 	/// To print debug infos
 	pub debug: bool,
 	/// The depth of the debug infos
@@ -293,12 +581,44 @@ impl Default for Options {
 			line_height: 1,
 			spaceless: false,
 			max_length: 0,
+			no_wrap: false,
+			border: None,
+			border_color: vec![Colors::System],
+			padding: [0; 4],
+			shadow: false,
+			shadow_offset: [1, 1],
+			shadow_char: '░',
+			shadow_color: Colors::Gray,
 			gradient: Vec::new(),
 			independent_gradient: false,
 			transition_gradient: false,
+			gradient_phase: 0,
 			env: Env::Cli,
+			color_mode: ColorMode::Auto,
+			seed: None,
+			candy_mode: CandyMode::Segment,
+			terminal_bg: TerminalBg::Auto,
+			strict_contrast: false,
+			simulate: None,
+			output: None,
+			output_format: OutputFormat::Text,
+			html_fragment: false,
+			html_css: false,
+			trim: false,
+			animate: None,
+			fps: 30,
+			duration: None,
+			marquee: false,
+			speed: 10,
+			clock: None,
+			countdown: None,
 			help: false,
 			version: false,
+			list_gradients: false,
+			print_config: false,
+			completions: None,
+			man: false,
+			lenient: false,
 			debug: false,
 			debug_level: 1,
 		}
@@ -308,7 +628,7 @@ impl Default for Options {
 /// The type of options our [`CLIOPTIONS`] can have
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum OptionType {
-	/// There is only one text option which is for the text
+	/// Option where another text to convert is expected
 	Text,
 	/// Font option
 	Font,
@@ -326,6 +646,42 @@ pub enum OptionType {
 	Bool,
 	/// Environment option
 	Env,
+	/// Color mode option
+	ColorMode,
+	/// Option where a seed is expected
+	Seed,
+	/// Candy mode option
+	CandyMode,
+	/// Theme option
+	Theme,
+	/// Terminal background option
+	TerminalBg,
+	/// Color vision deficiency simulation option
+	Simulate,
+	/// Option where a file path is expected
+	Output,
+	/// Output format option
+	OutputFormat,
+	/// Animation effect option
+	Animate,
+	/// Option where a duration is expected
+	Duration,
+	/// Clock option with an optional time format
+	Clock,
+	/// Shell option
+	Shell,
+	/// Border style option
+	Border,
+	/// Border color option
+	BorderColor,
+	/// Padding option
+	Padding,
+	/// Shadow offset option
+	ShadowOffset,
+	/// Shadow character option
+	ShadowChar,
+	/// Shadow color option
+	ShadowColor,
 }
 
 /// The struct of a single option inside our [`CLIOPTIONS`]
@@ -337,7 +693,7 @@ pub struct CliOption<'a> {
 	pub name: &'a str,
 	/// The description of this option
 	pub description: &'a str,
-	/// The shortcut flag; e.g.: -a instead of --align
+	/// The shortcut flag; e.g.: -a instead of --align (can be empty for long-form only options)
 	pub shortcut: &'a str,
 	/// An alternative shortcut flag in case where we have multiple
 	pub fallback_shortcut: &'a str,
@@ -350,7 +706,7 @@ pub struct CliOption<'a> {
 /// The `CLIOPTIONS` define each of the flags our cli app respects.
 ///
 /// It's also used to generate the help
-pub const CLIOPTIONS: [CliOption; 16] = [
+pub const CLIOPTIONS: [CliOption; 48] = [
 	CliOption {
 		key: "version",
 		name: "--version",
@@ -468,6 +824,69 @@ pub const CLIOPTIONS: [CliOption; 16] = [
 		example: "--gradient red,blue,green --transition-gradient",
 		kind: OptionType::Bool,
 	},
+	CliOption {
+		key: "border",
+		name: "--border",
+		shortcut: "",
+		fallback_shortcut: "",
+		description: "Use to draw a frame around your text output",
+		example: "--border rounded",
+		kind: OptionType::Border,
+	},
+	CliOption {
+		key: "border_color",
+		name: "--border-color",
+		shortcut: "",
+		fallback_shortcut: "",
+		description: "Use to define the color of the frame, more than one color paints a gradient",
+		example: "--border rounded --border-color cyan",
+		kind: OptionType::BorderColor,
+	},
+	CliOption {
+		key: "padding",
+		name: "--padding",
+		shortcut: "",
+		fallback_shortcut: "",
+		description: "Use to define the space around your text inside the frame like in CSS: all, vertical,horizontal or top,right,bottom,left",
+		example: "--border single --padding 1,2",
+		kind: OptionType::Padding,
+	},
+	CliOption {
+		key: "shadow",
+		name: "--shadow",
+		shortcut: "",
+		fallback_shortcut: "",
+		description: "Use to draw a shadow behind your text output",
+		example: "--shadow",
+		kind: OptionType::Bool,
+	},
+	CliOption {
+		key: "shadow_offset",
+		name: "--shadow-offset",
+		shortcut: "",
+		fallback_shortcut: "",
+		description: "Use to define how far the shadow is moved: both or right,down",
+		example: "--shadow --shadow-offset 2,1",
+		kind: OptionType::ShadowOffset,
+	},
+	CliOption {
+		key: "shadow_char",
+		name: "--shadow-char",
+		shortcut: "",
+		fallback_shortcut: "",
+		description: "Use to define the character the shadow is drawn with",
+		example: "--shadow --shadow-char ▒",
+		kind: OptionType::ShadowChar,
+	},
+	CliOption {
+		key: "shadow_color",
+		name: "--shadow-color",
+		shortcut: "",
+		fallback_shortcut: "",
+		description: "Use to define the color of the shadow",
+		example: "--shadow --shadow-color blue",
+		kind: OptionType::ShadowColor,
+	},
 	CliOption {
 		key: "env",
 		name: "--env",
@@ -477,6 +896,232 @@ pub const CLIOPTIONS: [CliOption; 16] = [
 		example: "--env browser",
 		kind: OptionType::Env,
 	},
+	CliOption {
+		key: "color_mode",
+		name: "--color-mode",
+		shortcut: "",
+		fallback_shortcut: "",
+		description: "Use to pin the color depth instead of detecting it from your console",
+		example: "--color-mode 256",
+		kind: OptionType::ColorMode,
+	},
+	CliOption {
+		key: "seed",
+		name: "--seed",
+		shortcut: "",
+		fallback_shortcut: "",
+		description: "Use to get the same candy colors on every run",
+		example: "--colors candy --seed 42",
+		kind: OptionType::Seed,
+	},
+	CliOption {
+		key: "candy_mode",
+		name: "--candy-mode",
+		shortcut: "",
+		fallback_shortcut: "",
+		description: "Use to define what gets its own color when using the candy color",
+		example: "--colors candy --candy-mode glyph",
+		kind: OptionType::CandyMode,
+	},
+	CliOption {
+		key: "terminal_bg",
+		name: "--terminal-bg",
+		shortcut: "",
+		fallback_shortcut: "",
+		description: "Use to tell cfonts if your console has a light or dark background instead of detecting it",
+		example: "--terminal-bg light",
+		kind: OptionType::TerminalBg,
+	},
+	CliOption {
+		key: "simulate",
+		name: "--simulate",
+		shortcut: "",
+		fallback_shortcut: "",
+		description: "Use to see how your output looks with a color vision deficiency",
+		example: "--colors red,green --simulate deuteranopia",
+		kind: OptionType::Simulate,
+	},
+	CliOption {
+		key: "strict_contrast",
+		name: "--strict-contrast",
+		shortcut: "",
+		fallback_shortcut: "",
+		description: "Use to fail when a color is hard to read on the background instead of just warning",
+		example: "--colors blue --background blue --strict-contrast",
+		kind: OptionType::Bool,
+	},
+	CliOption {
+		key: "theme",
+		name: "--theme",
+		shortcut: "",
+		fallback_shortcut: "",
+		description: "Use to apply a theme from your themes folder, a theme file or one of the built-in themes",
+		example: "--theme pride",
+		kind: OptionType::Theme,
+	},
+	CliOption {
+		key: "list_gradients",
+		name: "--list-gradients",
+		shortcut: "",
+		fallback_shortcut: "",
+		description: "Use to preview all presets you can use with the gradient option",
+		example: "--list-gradients",
+		kind: OptionType::Bool,
+	},
+	CliOption {
+		key: "print_config",
+		name: "--print-config",
+		shortcut: "",
+		fallback_shortcut: "",
+		description: "Use to show the options from your config files and environment and where each came from",
+		example: "--print-config",
+		kind: OptionType::Bool,
+	},
+	CliOption {
+		key: "completions",
+		name: "--completions",
+		shortcut: "",
+		fallback_shortcut: "",
+		description: "Use to print a completion script for your shell",
+		example: "--completions bash",
+		kind: OptionType::Shell,
+	},
+	CliOption {
+		key: "man",
+		name: "--man",
+		shortcut: "",
+		fallback_shortcut: "",
+		description: "Use to print the man page of cfonts in roff",
+		example: "--man > cfonts.1",
+		kind: OptionType::Bool,
+	},
+	CliOption {
+		key: "lenient",
+		name: "--lenient",
+		shortcut: "",
+		fallback_shortcut: "",
+		description: "Use to ignore options cfonts doesn't know instead of failing",
+		example: "--lenient",
+		kind: OptionType::Bool,
+	},
+	CliOption {
+		key: "output",
+		name: "--output",
+		shortcut: "",
+		fallback_shortcut: "",
+		description: "Use to write the output into a file instead of the console",
+		example: "--output banner.png",
+		kind: OptionType::Output,
+	},
+	CliOption {
+		key: "output_format",
+		name: "--output-format",
+		shortcut: "",
+		fallback_shortcut: "",
+		description: "Use to define the format of the output",
+		example: "--output-format html",
+		kind: OptionType::OutputFormat,
+	},
+	CliOption {
+		key: "html_fragment",
+		name: "--html-fragment",
+		shortcut: "",
+		fallback_shortcut: "",
+		description: "Use to leave out the outer div of the html output format",
+		example: "--output-format html --html-fragment",
+		kind: OptionType::Bool,
+	},
+	CliOption {
+		key: "html_css",
+		name: "--html-css",
+		shortcut: "",
+		fallback_shortcut: "",
+		description: "Use to print the stylesheet of the html output format instead of its markup",
+		example: "--output-format html --html-css > cfonts.css",
+		kind: OptionType::Bool,
+	},
+	CliOption {
+		key: "trim",
+		name: "--trim",
+		shortcut: "",
+		fallback_shortcut: "",
+		description: "Use to remove trailing whitespace from each line of the plain output format",
+		example: "--output-format plain --trim",
+		kind: OptionType::Bool,
+	},
+	CliOption {
+		key: "then",
+		name: "--then",
+		shortcut: "",
+		fallback_shortcut: "",
+		description:
+			"Use to add another text below with its own options, sharing the alignment and background of the first",
+		example: "--then \"subtitle\" -f tiny -c gray",
+		kind: OptionType::Text,
+	},
+	CliOption {
+		key: "animate",
+		name: "--animate",
+		shortcut: "",
+		fallback_shortcut: "",
+		description: "Use to animate the output in the console",
+		example: "--animate typewriter",
+		kind: OptionType::Animate,
+	},
+	CliOption {
+		key: "fps",
+		name: "--fps",
+		shortcut: "",
+		fallback_shortcut: "",
+		description: "Use to define the frames per second of an animation",
+		example: "--animate wipe --fps 60",
+		kind: OptionType::Number,
+	},
+	CliOption {
+		key: "duration",
+		name: "--duration",
+		shortcut: "",
+		fallback_shortcut: "",
+		description: "Use to define how long an animation takes in ms, s or m",
+		example: "--animate lines --duration 2s",
+		kind: OptionType::Duration,
+	},
+	CliOption {
+		key: "marquee",
+		name: "--marquee",
+		shortcut: "",
+		fallback_shortcut: "",
+		description: "Use to scroll wide output through the console in a loop instead of wrapping it",
+		example: "--marquee",
+		kind: OptionType::Bool,
+	},
+	CliOption {
+		key: "speed",
+		name: "--speed",
+		shortcut: "",
+		fallback_shortcut: "",
+		description: "Use to define how many columns per second the marquee moves",
+		example: "--marquee --speed 20",
+		kind: OptionType::Number,
+	},
+	CliOption {
+		key: "clock",
+		name: "--clock",
+		shortcut: "",
+		fallback_shortcut: "",
+		description: "Use to show a live clock instead of text, with an optional time format",
+		example: "--clock \"%H:%M:%S\"",
+		kind: OptionType::Clock,
+	},
+	CliOption {
+		key: "countdown",
+		name: "--countdown",
+		shortcut: "",
+		fallback_shortcut: "",
+		description: "Use to show a live countdown instead of text",
+		example: "--countdown 5m",
+		kind: OptionType::Duration,
+	},
 	CliOption {
 		key: "debug",
 		name: "--debug",
@@ -0,0 +1,142 @@
+//! The contents of this module is all about checking if the colors of the output are readable on its background
+use crate::color::{bgcolor2hex, color, color2hex, get_readable_color, hex2rgb, Rgb};
+use crate::config::{BgColors, Colors, Options, TerminalBg};
+use crate::debug::{d, Dt};
+use crate::helpers::first_letter_to_lowercase;
+
+/// The lowest contrast ratio we accept between a color and its background
+///
+/// This is the [WCAG](https://www.w3.org/TR/WCAG21/#contrast-minimum) minimum for large text which all cfonts fonts
+/// are, apart from the console font.
+pub const MIN_CONTRAST_RATIO: f64 = 3.0;
+
+/// Calculate the relative luminance of a color as defined by WCAG
+///
+/// ```rust
+/// extern crate cfonts;
+///
+/// use cfonts::color::Rgb;
+/// use cfonts::contrast::relative_luminance;
+///
+/// assert_eq!(relative_luminance(&Rgb::Val(0, 0, 0)), 0.0);
+/// assert_eq!(relative_luminance(&Rgb::Val(255, 255, 255)), 1.0);
+/// ```
+pub fn relative_luminance(rgb: &Rgb) -> f64 {
+	let (r, g, b) = rgb.get_value();
+	let linear = |channel: u8| {
+		let value = channel as f64 / 255.0;
+		if value <= 0.03928 {
+			value / 12.92
+		} else {
+			((value + 0.055) / 1.055).powf(2.4)
+		}
+	};
+
+	0.2126 * linear(r) + 0.7152 * linear(g) + 0.0722 * linear(b)
+}
+
+/// Calculate the WCAG contrast ratio between two colors which ranges from `1.0` to `21.0`
+///
+/// ```rust
+/// extern crate cfonts;
+///
+/// use cfonts::color::Rgb;
+/// use cfonts::contrast::contrast_ratio;
+///
+/// assert_eq!(contrast_ratio(&Rgb::Val(0, 0, 0), &Rgb::Val(255, 255, 255)), 21.0);
+/// assert_eq!(contrast_ratio(&Rgb::Val(0, 0, 255), &Rgb::Val(0, 0, 255)), 1.0);
+/// ```
+pub fn contrast_ratio(a: &Rgb, b: &Rgb) -> f64 {
+	let luminance_a = relative_luminance(a);
+	let luminance_b = relative_luminance(b);
+	let (lighter, darker) = if luminance_a > luminance_b {
+		(luminance_a, luminance_b)
+	} else {
+		(luminance_b, luminance_a)
+	};
+
+	(lighter + 0.05) / (darker + 0.05)
+}
+
+/// Check each color and gradient stop of the [`Options`] against the background
+///
+/// Returns a message for each color that falls below [`MIN_CONTRAST_RATIO`].
+/// Without a background color we check against the `terminal_bg` option if it has been set.
+/// [`Colors::System`] and [`Colors::Candy`] are skipped as we don't know what they will look like.
+///
+/// ```rust
+/// extern crate cfonts;
+///
+/// use cfonts::{BgColors, Colors, Options};
+/// use cfonts::contrast::check_contrast;
+///
+/// let mut options = Options::default();
+/// options.colors = vec![Colors::Blue];
+/// options.background = BgColors::Blue;
+/// assert_eq!(check_contrast(&options).len(), 1);
+///
+/// options.background = BgColors::White;
+/// assert!(check_contrast(&options).is_empty());
+/// ```
+pub fn check_contrast(options: &Options) -> Vec<String> {
+	d("contrast::check_contrast()", 3, Dt::Head, options, &mut std::io::stdout());
+
+	let (background_name, background) = match (&options.background, &options.terminal_bg) {
+		(BgColors::Transparent, TerminalBg::Light) => (String::from("light terminal"), Rgb::Val(255, 255, 255)),
+		(BgColors::Transparent, TerminalBg::Dark) => (String::from("dark terminal"), Rgb::Val(0, 0, 0)),
+		(BgColors::Transparent, TerminalBg::Auto) => return Vec::new(),
+		(background, _) => {
+			let hex = bgcolor2hex(background, options);
+			(name_of(&format!("{:?}", background), &hex), hex2rgb(&hex, options))
+		}
+	};
+
+	let foregrounds = if options.gradient.is_empty() {
+		options
+			.colors
+			.iter()
+			// colors that get swapped for readable ones when rendering don't need a warning
+			.filter(|this_color| {
+				!matches!(this_color, Colors::System | Colors::Candy) && get_readable_color(this_color, options) == **this_color
+			})
+			.map(|this_color| {
+				let hex = color2hex(this_color, options);
+				(name_of(&format!("{:?}", this_color), &hex), hex)
+			})
+			.collect::<Vec<(String, String)>>()
+	} else {
+		options.gradient.iter().map(|stop| (stop.clone(), stop.clone())).collect::<Vec<(String, String)>>()
+	};
+
+	let mut messages = Vec::new();
+	let mut checked = Vec::new();
+
+	for (name, hex) in foregrounds {
+		if checked.contains(&hex) {
+			continue;
+		}
+		let ratio = contrast_ratio(&hex2rgb(&hex, options), &background);
+		if ratio < MIN_CONTRAST_RATIO {
+			messages.push(format!(
+				"The color \"{}\" has a contrast ratio of {} against the background \"{}\" which is below {}",
+				color(&name, Colors::Green),
+				color(&format!("{:.2}:1", ratio), Colors::Green),
+				color(&background_name, Colors::Green),
+				color(&format!("{}:1", MIN_CONTRAST_RATIO), Colors::Green)
+			));
+		}
+		checked.push(hex);
+	}
+
+	d(&format!("contrast::check_contrast() -> {:?}", messages), 3, Dt::Log, options, &mut std::io::stdout());
+	messages
+}
+
+/// Name a color the way users write it, which is the hex value for rgb colors
+fn name_of(debug_name: &str, hex: &str) -> String {
+	if debug_name.starts_with("Rgb") {
+		hex.to_string()
+	} else {
+		first_letter_to_lowercase(debug_name)
+	}
+}
@@ -0,0 +1,431 @@
+//! The contents of this module is all about reading default options from config files and the environment
+use std::collections::HashMap;
+use std::fmt;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::args::{apply_option, validate_gradient};
+use crate::color::{color, rgb2hex};
+use crate::config::{BgColors, CliOption, ColorMode, Colors, Fonts, OptionType, Options, CLIOPTIONS};
+use crate::debug::{d, Dt};
+use crate::helpers::{first_letter_to_lowercase, get_config_dir};
+
+/// The name of the file inside [`get_config_dir()`] with the defaults of a user
+pub const USER_FILE: &str = "config.toml";
+
+/// The name of the file with the defaults of a project, looked up from the current folder upwards
+pub const PROJECT_FILE: &str = ".cfontsrc";
+
+/// The prefix of environment variables that set defaults, e.g. `CFONTS_FONT` or `CFONTS_LETTER_SPACING`
+pub const ENV_PREFIX: &str = "CFONTS_";
+
+/// Where the value of an option came from
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Source {
+	/// The value of [`Options::default()`]
+	Default,
+	/// The config file of the user
+	User(PathBuf),
+	/// The config file of the project
+	Project(PathBuf),
+	/// A `CFONTS_*` environment variable
+	Env,
+	/// A command line flag
+	Cli,
+}
+
+impl fmt::Display for Source {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		match self {
+			Source::Default => write!(f, "default"),
+			Source::User(path) | Source::Project(path) => write!(f, "{}", path.display()),
+			Source::Env => write!(f, "environment"),
+			Source::Cli => write!(f, "command line"),
+		}
+	}
+}
+
+/// The source of each option that doesn't come from [`Options::default()`] by its key
+pub type Sources = HashMap<String, Source>;
+
+/// A set of option values by their key as they came from a single [`Source`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Layer {
+	/// Where these values came from
+	pub source: Source,
+	/// The key of each option and its value the same way it's given on the command line
+	pub values: Vec<(String, String)>,
+}
+
+/// Options that only make sense for a single run or have side effects like writing files can't have a default
+///
+/// A project file may come from any parent folder, e.g. of a checkout we don't trust, so it must not be able to make
+/// every run write a file or never exit.
+fn is_configurable(option: &CliOption) -> bool {
+	option.kind != OptionType::Text
+		&& ![
+			"version",
+			"help",
+			"list_gradients",
+			"print_config",
+			"completions",
+			"man",
+			"output",
+			"clock",
+			"countdown",
+			"animate",
+			"marquee",
+			"lenient",
+		]
+		.contains(&option.key)
+}
+
+/// Find the option of a key, keys may be written with dashes like on the command line
+fn get_option(key: &str) -> Option<&'static CliOption<'static>> {
+	let key = key.replace('-', "_");
+	CLIOPTIONS.iter().find(|option| option.key == key && is_configurable(option))
+}
+
+/// Convert a value of a config file into the string we would get on the command line
+fn toml2value(value: &toml::Value) -> Option<String> {
+	match value {
+		toml::Value::String(text) => Some(text.clone()),
+		toml::Value::Integer(number) => Some(number.to_string()),
+		toml::Value::Boolean(flag) => Some(flag.to_string()),
+		toml::Value::Array(items) => {
+			items.iter().map(toml2value).collect::<Option<Vec<String>>>().map(|items| items.join(","))
+		}
+		_ => None,
+	}
+}
+
+/// Read a config file in TOML with the keys of our [`CLIOPTIONS`]
+///
+/// ```rust
+/// extern crate cfonts;
+///
+/// use std::path::Path;
+///
+/// use cfonts::Options;
+/// use cfonts::defaults::{read_file, Source};
+///
+/// let path = Path::new("no/such/config.toml");
+/// assert!(read_file(path, Source::User(path.to_path_buf()), &Options::default()).is_err());
+/// ```
+pub fn read_file(path: &Path, source: Source, options: &Options) -> Result<Layer, String> {
+	d("defaults::read_file()", 3, Dt::Head, options, &mut std::io::stdout());
+	d(&format!("defaults::read_file()\npath:{:?}", path), 3, Dt::Log, options, &mut std::io::stdout());
+
+	let file_name = path.display().to_string();
+	let content = fs::read_to_string(path)
+		.map_err(|error| format!("Could not read config file \"{}\": {}", color(&file_name, Colors::Green), error))?;
+	let table = toml::from_str::<toml::Table>(&content).map_err(|error| {
+		format!("Could not read config file \"{}\": {}", color(&file_name, Colors::Green), error.message())
+	})?;
+
+	let mut values = Vec::new();
+	for (key, value) in table {
+		let option = get_option(&key).ok_or_else(|| {
+			format!(
+				"The option \"{}\" in config file \"{}\" is not supported.\nAllowed options are: {}",
+				color(&key, Colors::Green),
+				color(&file_name, Colors::Green),
+				color(
+					&CLIOPTIONS
+						.iter()
+						.filter(|option| is_configurable(option))
+						.map(|option| option.key)
+						.collect::<Vec<&str>>()
+						.join(", "),
+					Colors::Green
+				)
+			)
+		})?;
+		let value = toml2value(&value).ok_or_else(|| {
+			format!(
+				"The value of \"{}\" in config file \"{}\" is not supported.\nUse a string, number, boolean or a list of those",
+				color(&key, Colors::Green),
+				color(&file_name, Colors::Green)
+			)
+		})?;
+		values.push((option.key.to_string(), value));
+	}
+
+	d(&format!("defaults::read_file() -> {:?}", values), 3, Dt::Log, options, &mut std::io::stdout());
+	Ok(Layer { source, values })
+}
+
+/// Collect the `CFONTS_*` variables out of a set of environment variables
+///
+/// Empty variables and variables we don't know are ignored.
+///
+/// ```rust
+/// extern crate cfonts;
+///
+/// use cfonts::defaults::{read_env, Source};
+///
+/// let layer = read_env(vec![
+///     (String::from("CFONTS_FONT"), String::from("tiny")),
+///     (String::from("CFONTS_LETTER_SPACING"), String::from("2")),
+///     (String::from("CFONTS_COLORS"), String::new()),
+///     (String::from("HOME"), String::from("/home/me")),
+/// ]);
+///
+/// assert_eq!(layer.source, Source::Env);
+/// assert_eq!(
+///     layer.values,
+///     vec![(String::from("font"), String::from("tiny")), (String::from("letter_spacing"), String::from("2"))]
+/// );
+/// ```
+pub fn read_env<I: IntoIterator<Item = (String, String)>>(vars: I) -> Layer {
+	let vars = vars.into_iter().collect::<HashMap<String, String>>();
+	let values = CLIOPTIONS
+		.iter()
+		.filter(|option| is_configurable(option))
+		.filter_map(|option| {
+			vars
+				.get(&format!("{}{}", ENV_PREFIX, option.key.to_uppercase()))
+				.filter(|value| !value.is_empty())
+				.map(|value| (option.key.to_string(), value.clone()))
+		})
+		.collect();
+
+	Layer {
+		source: Source::Env,
+		values,
+	}
+}
+
+/// Find the closest [`PROJECT_FILE`] in `dir` or any of its parent folders
+pub fn find_project_file(dir: &Path) -> Option<PathBuf> {
+	dir.ancestors().map(|dir| dir.join(PROJECT_FILE)).find(|path| path.is_file())
+}
+
+/// Read all layers of defaults in the order they are applied: the user config, the project config and the environment
+pub fn load_layers(dir: &Path, options: &Options) -> Result<Vec<Layer>, String> {
+	d("defaults::load_layers()", 1, Dt::Head, options, &mut std::io::stdout());
+
+	let mut layers = Vec::new();
+
+	if let Some(path) = get_config_dir().map(|dir| dir.join(USER_FILE)).filter(|path| path.is_file()) {
+		layers.push(read_file(&path, Source::User(path.clone()), options)?);
+	}
+
+	if let Some(path) = find_project_file(dir) {
+		layers.push(read_file(&path, Source::Project(path.clone()), options)?);
+	}
+
+	layers.push(read_env(
+		std::env::vars_os().filter_map(|(key, value)| Some((key.into_string().ok()?, value.into_string().ok()?))),
+	));
+
+	d(&format!("defaults::load_layers() -> {:?}", layers), 1, Dt::Log, options, &mut std::io::stdout());
+	Ok(layers)
+}
+
+/// Apply layers of defaults in order on top of `options` and record where each value came from
+///
+/// ```rust
+/// extern crate cfonts;
+///
+/// use cfonts::{Fonts, Options};
+/// use cfonts::defaults::{apply_layers, Layer, Source};
+///
+/// let layers = vec![
+///     Layer { source: Source::Env, values: vec![(String::from("font"), String::from("console"))] },
+/// ];
+/// let (options, sources) = apply_layers(&layers, Options::default()).unwrap();
+///
+/// assert_eq!(options.font, Fonts::FontConsole);
+/// assert_eq!(options.line_height, 0);
+/// assert_eq!(sources.get("font"), Some(&Source::Env));
+/// ```
+pub fn apply_layers(layers: &[Layer], options: Options) -> Result<(Options, Sources), String> {
+	d("defaults::apply_layers()", 1, Dt::Head, &options, &mut std::io::stdout());
+
+	let mut options = options;
+	let mut sources = Sources::new();
+
+	for layer in layers {
+		let before = options.clone();
+		// like on the command line a theme is applied first so the other options of the same layer can override it
+		let (themes, values): (Vec<_>, Vec<_>) = layer.values.iter().partition(|(key, _)| key == "theme");
+		for (key, value) in themes.into_iter().chain(values) {
+			let option =
+				get_option(key).ok_or_else(|| format!("The option \"{}\" is not supported", color(key, Colors::Green)))?;
+			apply_option(option, value, &mut options)
+				.map_err(|error| format!("{}\nSet in: {}", error, color(&layer.source.to_string(), Colors::Green)))?;
+		}
+
+		// the console font has no line height unless one was picked along with it
+		let sets = |key: &str| layer.values.iter().any(|(this_key, _)| this_key == key);
+		if sets("font") && options.font == Fonts::FontConsole && !sets("line_height") {
+			options.line_height = 0;
+		}
+
+		track_changes(&before, &options, &layer.source, &mut sources);
+	}
+
+	if !options.gradient.is_empty() {
+		validate_gradient(&options.gradient, options.transition_gradient)?;
+	}
+
+	d(
+		&format!("defaults::apply_layers() -> {:?}\nsources:{:?}", options, sources),
+		1,
+		Dt::Log,
+		&options,
+		&mut std::io::stdout(),
+	);
+	Ok((options, sources))
+}
+
+/// Record `source` for each option that is different between `before` and `after`
+pub fn track_changes(before: &Options, after: &Options, source: &Source, sources: &mut Sources) {
+	for option in CLIOPTIONS.iter().filter(|option| is_configurable(option)) {
+		if get_value(option.key, before) != get_value(option.key, after) {
+			sources.insert(option.key.to_string(), source.clone());
+		}
+	}
+}
+
+/// The value of an option the way it would be written in a config file, `None` if it isn't set
+///
+/// ```rust
+/// extern crate cfonts;
+///
+/// use cfonts::{Colors, Options};
+/// use cfonts::color::Rgb;
+/// use cfonts::defaults::get_value;
+///
+/// let options = Options {
+///     colors: vec![Colors::RedBright, Colors::Rgb(Rgb::Val(255, 136, 0))],
+///     ..Options::default()
+/// };
+///
+/// assert_eq!(get_value("font", &options), Some(toml::Value::String(String::from("block"))));
+/// assert_eq!(get_value("colors", &options).unwrap().to_string(), "[\"redBright\", \"#ff8800\"]");
+/// assert_eq!(get_value("seed", &options), None);
+/// ```
+pub fn get_value(key: &str, options: &Options) -> Option<toml::Value> {
+	let text = |value: String| Some(toml::Value::String(value));
+	let number = |value: u64| Some(toml::Value::Integer(value as i64));
+	let flag = |value: bool| Some(toml::Value::Boolean(value));
+	let name = |value: &dyn fmt::Debug| format!("{:?}", value).to_lowercase();
+	let colors = |value: &[Colors]| {
+		Some(toml::Value::Array(
+			value
+				.iter()
+				.map(|item| {
+					toml::Value::String(match item {
+						Colors::Rgb(rgb) => rgb2hex(rgb, options),
+						_ => first_letter_to_lowercase(&format!("{:?}", item)),
+					})
+				})
+				.collect(),
+		))
+	};
+
+	match key {
+		"font" => text(first_letter_to_lowercase(format!("{:?}", options.font).strip_prefix("Font").unwrap_or_default())),
+		"colors" => colors(&options.colors),
+		"background" => text(match &options.background {
+			BgColors::Rgb(rgb) => rgb2hex(rgb, options),
+			background => first_letter_to_lowercase(&format!("{:?}", background)),
+		}),
+		"align" => text(name(&options.align)),
+		"letter_spacing" => number(options.letter_spacing.into()),
+		"line_height" => number(options.line_height.into()),
+		"spaceless" => flag(options.spaceless),
+		"max_length" => number(options.max_length.into()),
+		"border" => options.border.as_ref().and_then(|border| text(name(border))),
+		"border_color" => colors(&options.border_color),
+		"padding" => {
+			Some(toml::Value::Array(options.padding.iter().map(|side| toml::Value::Integer((*side).into())).collect()))
+		}
+		"shadow" => flag(options.shadow),
+		"shadow_offset" => {
+			Some(toml::Value::Array(options.shadow_offset.iter().map(|side| toml::Value::Integer((*side).into())).collect()))
+		}
+		"shadow_char" => text(options.shadow_char.to_string()),
+		"shadow_color" => text(match &options.shadow_color {
+			Colors::Rgb(rgb) => rgb2hex(rgb, options),
+			shadow_color => first_letter_to_lowercase(&format!("{:?}", shadow_color)),
+		}),
+		"gradient" => match options.gradient.is_empty() {
+			true => None,
+			false => Some(toml::Value::Array(options.gradient.iter().cloned().map(toml::Value::String).collect())),
+		},
+		"independent_gradient" => flag(options.independent_gradient),
+		"transition_gradient" => flag(options.transition_gradient),
+		"env" => text(name(&options.env)),
+		"color_mode" => text(String::from(match options.color_mode {
+			ColorMode::Auto => "auto",
+			ColorMode::NoColor => "none",
+			ColorMode::Ansi16 => "16",
+			ColorMode::Ansi256 => "256",
+			ColorMode::Truecolor => "truecolor",
+		})),
+		// seeds are too big for TOML integers
+		"seed" => options.seed.and_then(|seed| text(seed.to_string())),
+		"candy_mode" => text(name(&options.candy_mode)),
+		"terminal_bg" => text(name(&options.terminal_bg)),
+		"strict_contrast" => flag(options.strict_contrast),
+		"simulate" => options.simulate.as_ref().and_then(|simulate| text(name(simulate))),
+		"output_format" => text(name(&options.output_format)),
+		"html_fragment" => flag(options.html_fragment),
+		"html_css" => flag(options.html_css),
+		"trim" => flag(options.trim),
+		"fps" => number(options.fps.into()),
+		"duration" => options.duration.and_then(|duration| text(format!("{}ms", duration))),
+		"speed" => number(options.speed.into()),
+		"debug" => flag(options.debug),
+		"debug_level" => number(options.debug_level.into()),
+		_ => None,
+	}
+}
+
+/// Return the string to display when the `print_config` option is passed in via the [`Options`] struct
+///
+/// Each option is printed the way it would be written in a config file followed by where its value came from.
+///
+/// ```rust
+/// extern crate cfonts;
+///
+/// use cfonts::{Fonts, Options};
+/// use cfonts::defaults::{print_config, Source, Sources};
+///
+/// let options = Options {
+///     font: Fonts::FontTiny,
+///     ..Options::default()
+/// };
+/// let mut sources = Sources::new();
+/// sources.insert(String::from("font"), Source::Cli);
+///
+/// let output = print_config(&options, &sources);
+/// assert!(output.contains("font                 = \"tiny\" # command line\n"));
+/// assert!(output.contains("align                = \"left\" # default\n"));
+/// ```
+pub fn print_config(options: &Options, sources: &Sources) -> String {
+	d("defaults::print_config()", 1, Dt::Head, options, &mut std::io::stdout());
+
+	let lines = CLIOPTIONS
+		.iter()
+		.filter(|option| is_configurable(option))
+		.filter_map(|option| {
+			get_value(option.key, options).map(|value| {
+				let source = sources.get(option.key).unwrap_or(&Source::Default);
+				(option.key, value.to_string(), source.to_string())
+			})
+		})
+		.collect::<Vec<(&str, String, String)>>();
+	let key_width = lines.iter().map(|(key, _, _)| key.len()).max().unwrap_or(0);
+
+	let mut output = String::new();
+	for (key, value, source) in lines {
+		output += &format!("{:<key_width$} = {} # {}\n", key, value, source);
+	}
+
+	d(&format!("defaults::print_config() -> {:?}", output), 1, Dt::Log, options, &mut std::io::stdout());
+	output
+}
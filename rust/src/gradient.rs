@@ -1,12 +1,23 @@
 //! The contents of this module is all about functions to add gradients to the output of cfonts.
 //!
 //! This module depends heavily on the color module
+use std::collections::HashMap;
 use std::f64;
+use std::fs;
+use std::path::Path;
 
+use serde::Deserialize;
+
+use crate::args::{parse_gradient, validate_gradient};
 use crate::chars::{get_first_char_position, get_longest_line_len};
-use crate::color::{color, hex2rgb, hex2rsv, rgb2hex, rsv2hex, Rgb, Rsv};
-use crate::config::{Colors, Options};
+use crate::color::{color, get_foreground_color_for, hex2rgb, hex2rsv, rgb2hex, rsv2hex, Rgb, Rsv};
+use crate::config::{
+	Colors, Options, GRADIENTS_AGENDER, GRADIENTS_AROMANTIC, GRADIENTS_ASEXUAL, GRADIENTS_BISEXUAL,
+	GRADIENTS_GENDERFLUID, GRADIENTS_GENDERQUEER, GRADIENTS_INTERSEX, GRADIENTS_LESBIAN, GRADIENTS_NONBINARY,
+	GRADIENTS_PANSEXUAL, GRADIENTS_POLYSEXUAL, GRADIENTS_PRIDE, GRADIENTS_TRANSGENDER,
+};
 use crate::debug::{d, Dt};
+use crate::helpers::get_config_dir;
 
 /// Get _linear_ interpolation of two points at a certain step of `steps`
 ///
@@ -104,7 +115,7 @@ pub fn get_theta(point_a: f64, point_b: f64, this_step: usize, steps: usize, opt
 	} else if point_b - point_a < std::f64::consts::PI {
 		long_distance = (point_b - point_a) - std::f64::consts::TAU;
 	} else {
-		long_distance = -1.0 * (point_a - point_b);
+		long_distance = -(point_a - point_b);
 	}
 
 	let mut result = point_a + (this_step as f64 * (long_distance / steps as f64));
@@ -226,6 +237,8 @@ pub fn get_transition_colors(from: &str, to: &str, steps: i8, options: &Options)
 
 /// Take a bunch of lines and color them in the colors provided
 ///
+/// The `phase` shifts the colors to the right by that many characters and wraps them around at the end.
+///
 /// ```rust
 /// extern crate cfonts;
 ///
@@ -237,17 +250,32 @@ pub fn get_transition_colors(from: &str, to: &str, steps: i8, options: &Options)
 /// let colors = vec!["#ff0000".to_string(), "#00ff00".to_string(), "#0000ff".to_string()];
 ///
 /// assert_eq!(
-///     paint_lines(&lines, &colors, 0, &options),
+///     paint_lines(&lines, &colors, 0, 0, &options),
 ///     vec![
 ///         "\x1b[38;2;255;0;0m#\x1b[39m\x1b[38;2;0;255;0m#\x1b[39m\x1b[38;2;0;0;255m#\x1b[39m".to_string(),
 ///         "\x1b[38;2;255;0;0m#\x1b[39m\x1b[38;2;0;255;0m#\x1b[39m\x1b[38;2;0;0;255m#\x1b[39m".to_string(),
 ///     ]
 /// );
+/// assert_eq!(
+///     paint_lines(&lines[0..1], &colors, 0, 1, &options),
+///     vec![
+///         "\x1b[38;2;0;0;255m#\x1b[39m\x1b[38;2;255;0;0m#\x1b[39m\x1b[38;2;0;255;0m#\x1b[39m".to_string(),
+///     ]
+/// );
 /// ```
-pub fn paint_lines(lines: &[String], colors: &[String], first_char_pos: usize, options: &Options) -> Vec<String> {
+pub fn paint_lines(
+	lines: &[String],
+	colors: &[String],
+	first_char_pos: usize,
+	phase: usize,
+	options: &Options,
+) -> Vec<String> {
 	d("gradient::paint_lines()", 5, Dt::Head, options, &mut std::io::stdout());
 	d(
-		&format!("gradient::paint_lines()\nlines:{:#?}\ncolors:{:#?}\nfirst_char_pos:{}", lines, colors, first_char_pos),
+		&format!(
+			"gradient::paint_lines()\nlines:{:#?}\ncolors:{:#?}\nfirst_char_pos:{}\nphase:{}",
+			lines, colors, first_char_pos, phase
+		),
 		5,
 		Dt::Log,
 		options,
@@ -261,8 +289,9 @@ pub fn paint_lines(lines: &[String], colors: &[String], first_char_pos: usize, o
 		let mut i = 0;
 		colored_lines.push(String::from(&space));
 		line.split_at(first_char_pos).1.chars().for_each(|c| {
-			let this_color = hex2rgb(&colors[i], options);
-			colored_lines[l] += &color(&c.to_string(), Colors::Rgb(this_color));
+			let this_color = hex2rgb(&colors[(i + colors.len() - phase % colors.len()) % colors.len()], options);
+			let (start, end) = get_foreground_color_for(&Colors::Rgb(this_color), options);
+			colored_lines[l] += &format!("{}{}{}", start, c, end);
 			i += 1;
 		});
 	}
@@ -459,9 +488,288 @@ pub fn add_gradient_colors(output: &[String], lines: usize, font_lines: usize, o
 		};
 
 		output_with_gradient.append(&mut lines_inbetween);
-		output_with_gradient.append(&mut paint_lines(this_line, &colors, first_char_pos, options));
+		output_with_gradient.append(&mut paint_lines(this_line, &colors, first_char_pos, options.gradient_phase, options));
 	}
 
 	d(&format!("gradient::gradient() -> {:?}", output_with_gradient), 5, Dt::Log, options, &mut std::io::stdout());
 	output_with_gradient
 }
+
+/// A named set of gradient colors that can be used instead of colors with the gradient option
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct GradientPreset {
+	/// The colors of the gradient, each item can be a color name or a hex color
+	pub colors: Vec<String>,
+	/// Whether this preset is a transition gradient
+	#[serde(default)]
+	pub transition: bool,
+}
+
+impl GradientPreset {
+	/// Create a transition gradient preset from a list of colors
+	///
+	/// ```rust
+	/// extern crate cfonts;
+	///
+	/// use cfonts::gradient::GradientPreset;
+	///
+	/// let preset = GradientPreset::transition(&["#ff0000", "#00ff00", "#0000ff"]);
+	/// assert_eq!(preset.colors.len(), 3);
+	/// assert!(preset.transition);
+	/// ```
+	pub fn transition(colors: &[&str]) -> Self {
+		GradientPreset {
+			colors: colors.iter().map(|item| item.to_string()).collect(),
+			transition: true,
+		}
+	}
+}
+
+/// A registry of named [`GradientPreset`]s
+///
+/// The default registry comes with the built-in presets.
+/// Use [`GradientPresetRegistry::new()`] for an empty one.
+///
+/// ```rust
+/// extern crate cfonts;
+///
+/// use cfonts::Options;
+/// use cfonts::gradient::{GradientPreset, GradientPresetRegistry};
+///
+/// let mut registry = GradientPresetRegistry::default();
+/// registry
+///     .register(
+///         "sunset",
+///         GradientPreset {
+///             colors: vec![String::from("#ff5f6d"), String::from("#ffc371")],
+///             transition: false,
+///         },
+///         &Options::default(),
+///     )
+///     .unwrap();
+///
+/// assert!(registry.get("Sunset").is_some());
+/// assert_eq!(registry.get("lgbt"), registry.get("pride"));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GradientPresetRegistry {
+	presets: HashMap<String, GradientPreset>,
+	aliases: HashMap<String, String>,
+}
+
+impl Default for GradientPresetRegistry {
+	fn default() -> Self {
+		let mut registry = GradientPresetRegistry::new();
+		let builtin = [
+			("pride", GRADIENTS_PRIDE.to_vec()),
+			("agender", GRADIENTS_AGENDER.to_vec()),
+			("aromantic", GRADIENTS_AROMANTIC.to_vec()),
+			("asexual", GRADIENTS_ASEXUAL.to_vec()),
+			("bisexual", GRADIENTS_BISEXUAL.to_vec()),
+			("genderfluid", GRADIENTS_GENDERFLUID.to_vec()),
+			("genderqueer", GRADIENTS_GENDERQUEER.to_vec()),
+			("intersex", GRADIENTS_INTERSEX.to_vec()),
+			("lesbian", GRADIENTS_LESBIAN.to_vec()),
+			("nonbinary", GRADIENTS_NONBINARY.to_vec()),
+			("pansexual", GRADIENTS_PANSEXUAL.to_vec()),
+			("polysexual", GRADIENTS_POLYSEXUAL.to_vec()),
+			("transgender", GRADIENTS_TRANSGENDER.to_vec()),
+		];
+
+		// the built-in presets are already valid so we skip validation here
+		for (name, colors) in builtin {
+			registry.presets.insert(String::from(name), GradientPreset::transition(&colors));
+		}
+
+		registry.alias("lgbt", "pride");
+		registry.alias("lgbtq", "pride");
+		registry.alias("lgbtqa", "pride");
+		registry.alias("bi", "bisexual");
+		registry.alias("pan", "pansexual");
+		registry.alias("poly", "polysexual");
+		registry.alias("trans", "transgender");
+
+		registry
+	}
+}
+
+impl GradientPresetRegistry {
+	/// Create an empty registry without the built-in presets
+	pub fn new() -> Self {
+		GradientPresetRegistry {
+			presets: HashMap::new(),
+			aliases: HashMap::new(),
+		}
+	}
+
+	/// Register a preset under a name, replacing any preset with the same name
+	///
+	/// The colors are validated and converted to hex colors.
+	pub fn register(&mut self, name: &str, preset: GradientPreset, options: &Options) -> Result<(), String> {
+		d("gradient::GradientPresetRegistry::register()", 3, Dt::Head, options, &mut std::io::stdout());
+		d(
+			&format!("gradient::GradientPresetRegistry::register()\nname:{:?}\npreset:{:?}", name, preset),
+			3,
+			Dt::Log,
+			options,
+			&mut std::io::stdout(),
+		);
+
+		let colors = parse_gradient(&preset.colors.join(","), options)
+			.and_then(|colors| validate_gradient(&colors, preset.transition).map(|_| colors))
+			.map_err(|error| format!("{}\nin the gradient preset \"{}\"", error, color(name, Colors::Green)))?;
+
+		let name = name.to_lowercase();
+		self.aliases.remove(&name);
+		self.presets.insert(
+			name,
+			GradientPreset {
+				colors,
+				transition: preset.transition,
+			},
+		);
+
+		Ok(())
+	}
+
+	/// Register another name for an existing preset
+	pub fn alias(&mut self, alias: &str, name: &str) {
+		self.aliases.insert(alias.to_lowercase(), name.to_lowercase());
+	}
+
+	/// Get a preset by its name or alias, names are case insensitive
+	pub fn get(&self, name: &str) -> Option<&GradientPreset> {
+		let name = name.to_lowercase();
+		match self.presets.get(&name) {
+			Some(preset) => Some(preset),
+			None => self.aliases.get(&name).and_then(|name| self.presets.get(name)),
+		}
+	}
+
+	/// List the names of all registered presets in alphabetical order
+	pub fn list(&self) -> Vec<String> {
+		let mut names = self.presets.keys().cloned().collect::<Vec<String>>();
+		names.sort();
+		names
+	}
+
+	/// List all aliases of a preset in alphabetical order
+	///
+	/// ```rust
+	/// extern crate cfonts;
+	///
+	/// use cfonts::gradient::GradientPresetRegistry;
+	///
+	/// let registry = GradientPresetRegistry::default();
+	/// assert_eq!(registry.aliases_of("pride"), vec![String::from("lgbt"), String::from("lgbtq"), String::from("lgbtqa")]);
+	/// assert!(registry.aliases_of("lesbian").is_empty());
+	/// ```
+	pub fn aliases_of(&self, name: &str) -> Vec<String> {
+		let name = name.to_lowercase();
+		let mut aliases = self
+			.aliases
+			.iter()
+			.filter(|(alias, target)| **target == name && !self.presets.contains_key(*alias))
+			.map(|(alias, _)| alias.clone())
+			.collect::<Vec<String>>();
+		aliases.sort();
+		aliases
+	}
+
+	/// Load a `.toml` or `.json` file of presets where each preset is a table under its name
+	///
+	/// Presets that can't be read are skipped so they don't get in the way of all other presets.
+	/// Returns the error of each skipped preset by its name, a file that can't be read at all is an error.
+	///
+	/// ```toml
+	/// [sunset]
+	/// colors = ["#ff5f6d", "#ffc371"]
+	///
+	/// [ocean]
+	/// colors = ["#00c6ff", "#0072ff", "#001f3f"]
+	/// transition = true
+	/// ```
+	pub fn load_file(&mut self, path: &Path, options: &Options) -> Result<HashMap<String, String>, String> {
+		d("gradient::GradientPresetRegistry::load_file()", 3, Dt::Head, options, &mut std::io::stdout());
+		d(
+			&format!("gradient::GradientPresetRegistry::load_file()\npath:{:?}", path),
+			3,
+			Dt::Log,
+			options,
+			&mut std::io::stdout(),
+		);
+
+		let file_name = path.display().to_string();
+		let content = fs::read_to_string(path)
+			.map_err(|error| format!("Could not read gradient file \"{}\": {}", color(&file_name, Colors::Green), error))?;
+
+		// each preset is read on its own so one broken preset doesn't take the others with it
+		let presets = match path.extension().and_then(|extension| extension.to_str()) {
+			Some("toml") => {
+				toml::from_str::<toml::Table>(&content).map_err(|error| error.message().to_string()).map(|table| {
+					table
+						.into_iter()
+						.map(|(name, value)| {
+							(name, value.try_into::<GradientPreset>().map_err(|error| error.message().to_string()))
+						})
+						.collect::<Vec<_>>()
+				})
+			}
+			Some("json") => serde_json::from_str::<serde_json::Map<String, serde_json::Value>>(&content)
+				.map_err(|error| error.to_string())
+				.map(|map| {
+					map
+						.into_iter()
+						.map(|(name, value)| {
+							(name, serde_json::from_value::<GradientPreset>(value).map_err(|error| error.to_string()))
+						})
+						.collect::<Vec<_>>()
+				}),
+			_ => {
+				return Err(format!(
+					"The gradient file \"{}\" is not supported.\nAllowed options are: {}",
+					color(&file_name, Colors::Green),
+					color(".toml, .json", Colors::Green)
+				))
+			}
+		}
+		.map_err(|error| format!("Could not read gradient file \"{}\": {}", color(&file_name, Colors::Green), error))?;
+
+		let mut broken = HashMap::new();
+		for (name, preset) in presets {
+			let result = preset
+				.map_err(|error| {
+					format!(
+						"Could not read the gradient preset \"{}\" in \"{}\": {}",
+						color(&name, Colors::Green),
+						color(&file_name, Colors::Green),
+						error
+					)
+				})
+				.and_then(|preset| self.register(&name, preset, options));
+			if let Err(error) = result {
+				d(
+					&format!("gradient::GradientPresetRegistry::load_file() skipped {:?}\n{}", name, error),
+					3,
+					Dt::Log,
+					options,
+					&mut std::io::stdout(),
+				);
+				broken.insert(name.to_lowercase(), error);
+			}
+		}
+
+		Ok(broken)
+	}
+
+	/// Load the `gradients.toml` file inside [`get_config_dir()`] if there is one
+	///
+	/// Returns the error of each preset that couldn't be loaded by its name, see [`GradientPresetRegistry::load_file()`].
+	pub fn load_config(&mut self, options: &Options) -> Result<HashMap<String, String>, String> {
+		match get_config_dir() {
+			Some(dir) if dir.join("gradients.toml").is_file() => self.load_file(&dir.join("gradients.toml"), options),
+			_ => Ok(HashMap::new()),
+		}
+	}
+}
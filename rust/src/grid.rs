@@ -0,0 +1,435 @@
+//! The contents of this module is all about turning our rendered output back into a grid of colored cells
+use crate::color::{color2hex, get_background_color_for, get_foreground_color_for, hex2rgb, rgb2hex, Rgb};
+use crate::config::{BgColors, Colors, Options};
+use crate::debug::{d, Dt};
+
+/// The 16 named colors in the order of their ansi 256 color codes
+const NAMED_COLORS: [Colors; 16] = [
+	Colors::Black,
+	Colors::Red,
+	Colors::Green,
+	Colors::Yellow,
+	Colors::Blue,
+	Colors::Magenta,
+	Colors::Cyan,
+	Colors::White,
+	Colors::Gray,
+	Colors::RedBright,
+	Colors::GreenBright,
+	Colors::YellowBright,
+	Colors::BlueBright,
+	Colors::MagentaBright,
+	Colors::CyanBright,
+	Colors::WhiteBright,
+];
+
+/// The 16 named background colors in the order of their ansi 256 color codes
+const NAMED_BG_COLORS: [BgColors; 16] = [
+	BgColors::Black,
+	BgColors::Red,
+	BgColors::Green,
+	BgColors::Yellow,
+	BgColors::Blue,
+	BgColors::Magenta,
+	BgColors::Cyan,
+	BgColors::White,
+	BgColors::Gray,
+	BgColors::RedBright,
+	BgColors::GreenBright,
+	BgColors::YellowBright,
+	BgColors::BlueBright,
+	BgColors::MagentaBright,
+	BgColors::CyanBright,
+	BgColors::WhiteBright,
+];
+
+/// A single character of the output with the colors it is painted in
+///
+/// A color of `None` means the cell uses the default color of the terminal.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Cell {
+	/// The character within this cell
+	pub character: char,
+	/// The foreground color
+	pub fg: Option<Rgb>,
+	/// The background color
+	pub bg: Option<Rgb>,
+}
+
+impl Default for Cell {
+	/// An empty cell is a space without colors
+	fn default() -> Self {
+		Cell {
+			character: ' ',
+			fg: None,
+			bg: None,
+		}
+	}
+}
+
+/// How [`Grid::composite()`] combines a layer with the cells already in a grid
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub enum Blend {
+	/// The layer is drawn on top of the grid
+	#[default]
+	Over,
+	/// The layer only shows through the empty cells of the grid
+	Behind,
+}
+
+/// The output as rows of [`Cell`]s
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct Grid {
+	/// Each row of the output; rows can have different lengths
+	pub rows: Vec<Vec<Cell>>,
+}
+
+impl Grid {
+	/// Parse text with ansi escape sequences into a grid
+	///
+	/// We understand the SGR sequences cfonts writes: resets, the 16 named colors, 256 colors and truecolor.
+	/// Colors carry over line breaks the same way they would in a terminal.
+	///
+	/// ```rust
+	/// extern crate cfonts;
+	///
+	/// use cfonts::{Options, Rgb};
+	/// use cfonts::grid::{Cell, Grid};
+	///
+	/// let grid = Grid::from_ansi("\x1b[38;2;255;0;0mA\x1b[39m\nb", &Options::default());
+	///
+	/// assert_eq!(grid.rows[0], vec![Cell { character: 'A', fg: Some(Rgb::Val(255, 0, 0)), bg: None }]);
+	/// assert_eq!(grid.rows[1], vec![Cell { character: 'b', fg: None, bg: None }]);
+	/// ```
+	pub fn from_ansi(text: &str, options: &Options) -> Grid {
+		d("grid::Grid::from_ansi()", 3, Dt::Head, options, &mut std::io::stdout());
+
+		let mut rows = vec![Vec::new()];
+		let mut fg = None;
+		let mut bg = None;
+		let mut chars = text.chars().peekable();
+
+		while let Some(character) = chars.next() {
+			match character {
+				'\x1b' if chars.peek() == Some(&'[') => {
+					chars.next();
+					let mut sequence = String::new();
+					for next in chars.by_ref() {
+						if next.is_ascii_alphabetic() {
+							if next == 'm' {
+								apply_sgr(&sequence, &mut fg, &mut bg);
+							}
+							break;
+						}
+						sequence.push(next);
+					}
+				}
+				'\n' => rows.push(Vec::new()),
+				'\r' => {}
+				_ => rows.last_mut().unwrap().push(Cell {
+					character,
+					fg: fg.clone(),
+					bg: bg.clone(),
+				}),
+			}
+		}
+
+		d(&format!("grid::Grid::from_ansi() -> {} rows", rows.len()), 3, Dt::Log, options, &mut std::io::stdout());
+		Grid { rows }
+	}
+
+	/// The length of the longest row
+	pub fn width(&self) -> usize {
+		self.rows.iter().map(|row| row.len()).max().unwrap_or(0)
+	}
+
+	/// The number of rows
+	pub fn height(&self) -> usize {
+		self.rows.len()
+	}
+
+	/// A copy of the grid where every character that isn't a space is replaced by `character` in the color `fg`
+	///
+	/// The copy has no background so it can be composited onto another grid.
+	///
+	/// ```rust
+	/// extern crate cfonts;
+	///
+	/// use cfonts::Options;
+	/// use cfonts::grid::{Cell, Grid};
+	///
+	/// let grid = Grid::from_ansi("a b", &Options::default()).silhouette('#', None);
+	///
+	/// assert_eq!(grid.rows[0].iter().map(|cell| cell.character).collect::<String>(), "# #");
+	/// ```
+	pub fn silhouette(&self, character: char, fg: Option<Rgb>) -> Grid {
+		Grid {
+			rows: self
+				.rows
+				.iter()
+				.map(|row| {
+					row
+						.iter()
+						.map(|cell| match cell.character {
+							' ' => Cell::default(),
+							_ => Cell {
+								character,
+								fg: fg.clone(),
+								bg: None,
+							},
+						})
+						.collect()
+				})
+				.collect(),
+		}
+	}
+
+	/// Composite another grid onto this one with its top left corner at column `x` and row `y`
+	///
+	/// Spaces within the layer are transparent and the grid grows to fit the layer.
+	/// A cell keeps its background when the layer has none.
+	///
+	/// ```rust
+	/// extern crate cfonts;
+	///
+	/// use cfonts::Options;
+	/// use cfonts::grid::{Blend, Grid};
+	///
+	/// let options = Options::default();
+	/// let layer = Grid::from_ansi("xx\nxx", &options);
+	///
+	/// let mut grid = Grid::from_ansi("a ", &options);
+	/// grid.composite(&layer, 1, 0, Blend::Over);
+	/// assert_eq!(grid, Grid::from_ansi("axx\n xx", &options));
+	///
+	/// let mut grid = Grid::from_ansi("a ", &options);
+	/// grid.composite(&layer, 0, 0, Blend::Behind);
+	/// assert_eq!(grid, Grid::from_ansi("ax\nxx", &options));
+	/// ```
+	pub fn composite(&mut self, layer: &Grid, x: usize, y: usize, blend: Blend) {
+		for (row_index, row) in layer.rows.iter().enumerate() {
+			if self.rows.len() <= y + row_index {
+				self.rows.resize(y + row_index + 1, Vec::new());
+			}
+			let target = &mut self.rows[y + row_index];
+
+			for (column, cell) in row.iter().enumerate().filter(|(_, cell)| cell.character != ' ') {
+				if target.len() <= x + column {
+					target.resize(x + column + 1, Cell::default());
+				}
+				let below = &mut target[x + column];
+				if blend == Blend::Over || below.character == ' ' {
+					*below = Cell {
+						bg: cell.bg.clone().or(below.bg.clone()),
+						..cell.clone()
+					};
+				}
+			}
+		}
+	}
+
+	/// Turn the grid back into text with ansi escape sequences
+	///
+	/// Each row opens and closes its own colors.
+	/// The named colors are written as their named escape sequences so they look the same as before.
+	///
+	/// ```rust
+	/// extern crate cfonts;
+	///
+	/// use cfonts::Options;
+	/// use cfonts::grid::Grid;
+	///
+	/// let options = Options::default();
+	/// let text = "\x1b[31mab\x1b[39m c\n\x1b[44md\x1b[49m";
+	///
+	/// assert_eq!(Grid::from_ansi(text, &options).to_ansi(&options), text);
+	/// ```
+	pub fn to_ansi(&self, options: &Options) -> String {
+		d("grid::Grid::to_ansi()", 3, Dt::Head, options, &mut std::io::stdout());
+
+		self
+			.rows
+			.iter()
+			.map(|row| {
+				let mut output = String::new();
+				let mut fg_end = String::new();
+				let mut bg_end = String::new();
+				let mut fg: Option<&Rgb> = None;
+				let mut bg: Option<&Rgb> = None;
+
+				for cell in row {
+					if cell.fg.as_ref() != fg {
+						output.push_str(&fg_end);
+						(fg_end, fg) = match &cell.fg {
+							Some(rgb) => {
+								let (start, end) = get_foreground_color_for(&rgb2color(rgb), options);
+								output.push_str(&start);
+								(end, Some(rgb))
+							}
+							None => (String::new(), None),
+						};
+					}
+					if cell.bg.as_ref() != bg {
+						output.push_str(&bg_end);
+						(bg_end, bg) = match &cell.bg {
+							Some(rgb) => {
+								let (start, end) = get_background_color_for(&rgb2bgcolor(rgb), options);
+								output.push_str(&start);
+								(end, Some(rgb))
+							}
+							None => (String::new(), None),
+						};
+					}
+					output.push(cell.character);
+				}
+
+				format!("{}{}{}", output, fg_end, bg_end)
+			})
+			.collect::<Vec<String>>()
+			.join("\n")
+	}
+
+	/// Turn the grid into rows of html with inline colors the way [`crate::Env::Browser`] paints its output
+	///
+	/// Each run of cells with the same colors becomes one span, cells without colors are written as they are.
+	///
+	/// ```rust
+	/// extern crate cfonts;
+	///
+	/// use cfonts::Options;
+	/// use cfonts::grid::Grid;
+	///
+	/// let options = Options::default();
+	/// let grid = Grid::from_ansi("\x1b[38;2;255;0;0mab\x1b[39m c\n\x1b[48;2;0;0;255md\x1b[49m", &options);
+	///
+	/// assert_eq!(
+	///     grid.to_spans(&options),
+	///     "<span style=\"color:#ff0000\">ab</span> c\n<span style=\"background:#0000ff\">d</span>"
+	/// );
+	/// ```
+	pub fn to_spans(&self, options: &Options) -> String {
+		d("grid::Grid::to_spans()", 3, Dt::Head, options, &mut std::io::stdout());
+
+		self
+			.rows
+			.iter()
+			.map(|row| {
+				let mut runs: Vec<(&Cell, String)> = Vec::new();
+				for cell in row {
+					match runs.last_mut() {
+						Some((last, run)) if last.fg == cell.fg && last.bg == cell.bg => run.push(cell.character),
+						_ => runs.push((cell, cell.character.to_string())),
+					}
+				}
+
+				runs
+					.iter()
+					.map(|(cell, run)| {
+						let style = [("color", &cell.fg), ("background", &cell.bg)]
+							.iter()
+							.filter_map(|(property, rgb)| rgb.as_ref().map(|rgb| format!("{}:{}", property, rgb2hex(rgb, options))))
+							.collect::<Vec<String>>()
+							.join(";");
+						if style.is_empty() {
+							run.clone()
+						} else {
+							format!("<span style=\"{}\">{}</span>", style, run)
+						}
+					})
+					.collect::<String>()
+			})
+			.collect::<Vec<String>>()
+			.join("\n")
+	}
+}
+
+/// The named color of an rgb value we got from [`ansi256_to_rgb()`] or the rgb value itself
+fn rgb2color(rgb: &Rgb) -> Colors {
+	match (0..16).find(|code| ansi256_to_rgb(*code) == *rgb) {
+		Some(code) => NAMED_COLORS[code as usize].clone(),
+		None => Colors::Rgb(rgb.clone()),
+	}
+}
+
+/// The named background color of an rgb value we got from [`ansi256_to_rgb()`] or the rgb value itself
+fn rgb2bgcolor(rgb: &Rgb) -> BgColors {
+	match (0..16).find(|code| ansi256_to_rgb(*code) == *rgb) {
+		Some(code) => NAMED_BG_COLORS[code as usize].clone(),
+		None => BgColors::Rgb(rgb.clone()),
+	}
+}
+
+/// Apply the parameters of an SGR escape sequence to the current colors
+fn apply_sgr(sequence: &str, fg: &mut Option<Rgb>, bg: &mut Option<Rgb>) {
+	let params = sequence.split(';').map(|param| param.parse::<u16>().unwrap_or(0)).collect::<Vec<u16>>();
+	let mut index = 0;
+
+	while index < params.len() {
+		match params[index] {
+			0 => {
+				*fg = None;
+				*bg = None;
+			}
+			code @ (38 | 48) => {
+				let color = match params.get(index + 1) {
+					Some(2) if index + 4 < params.len() => {
+						let rgb = Rgb::Val(params[index + 2] as u8, params[index + 3] as u8, params[index + 4] as u8);
+						index += 4;
+						Some(rgb)
+					}
+					Some(5) if index + 2 < params.len() => {
+						let rgb = ansi256_to_rgb(params[index + 2] as u8);
+						index += 2;
+						Some(rgb)
+					}
+					_ => None,
+				};
+				match code {
+					38 => *fg = color,
+					_ => *bg = color,
+				}
+			}
+			39 => *fg = None,
+			49 => *bg = None,
+			code @ 30..=37 => *fg = Some(ansi256_to_rgb((code - 30) as u8)),
+			code @ 90..=97 => *fg = Some(ansi256_to_rgb((code - 82) as u8)),
+			code @ 40..=47 => *bg = Some(ansi256_to_rgb((code - 40) as u8)),
+			code @ 100..=107 => *bg = Some(ansi256_to_rgb((code - 92) as u8)),
+			_ => {}
+		}
+		index += 1;
+	}
+}
+
+/// Convert an ansi 256 color code into RGB
+///
+/// The first 16 colors use the same values as [`color2hex()`].
+///
+/// ```rust
+/// extern crate cfonts;
+///
+/// use cfonts::Rgb;
+/// use cfonts::grid::ansi256_to_rgb;
+///
+/// assert_eq!(ansi256_to_rgb(1), Rgb::Val(234, 50, 35));
+/// assert_eq!(ansi256_to_rgb(196), Rgb::Val(255, 0, 0));
+/// assert_eq!(ansi256_to_rgb(232), Rgb::Val(8, 8, 8));
+/// ```
+pub fn ansi256_to_rgb(code: u8) -> Rgb {
+	const CUBE: [u8; 6] = [0, 95, 135, 175, 215, 255];
+
+	match code {
+		0..=15 => {
+			let options = Options::default();
+			hex2rgb(&color2hex(&NAMED_COLORS[code as usize], &options), &options)
+		}
+		16..=231 => {
+			let index = code - 16;
+			Rgb::Val(CUBE[(index / 36) as usize], CUBE[((index / 6) % 6) as usize], CUBE[(index % 6) as usize])
+		}
+		_ => {
+			let value = 8 + (code - 232) * 10;
+			Rgb::Val(value, value, value)
+		}
+	}
+}
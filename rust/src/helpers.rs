@@ -1,4 +1,5 @@
 //! The contents of this module is all about helper functions
+use std::path::PathBuf;
 
 /// A function to print the type of a thing
 ///
@@ -58,3 +59,113 @@ fn first_letter_to_lowercase_works() {
 	assert_eq!(first_letter_to_lowercase("1234"), "1234");
 	assert_eq!(first_letter_to_lowercase(""), "");
 }
+
+/// Get the folder cfonts reads user configuration like themes from
+///
+/// This is `$XDG_CONFIG_HOME/cfonts`, falling back to `$HOME/.config/cfonts` and `%APPDATA%\cfonts` on windows.
+///
+/// ```rust
+/// extern crate cfonts;
+///
+/// use cfonts::helpers::get_config_dir;
+///
+/// if let Some(dir) = get_config_dir() {
+///     assert!(dir.ends_with("cfonts"));
+/// }
+/// ```
+pub fn get_config_dir() -> Option<PathBuf> {
+	let non_empty = |key: &str| std::env::var_os(key).filter(|value| !value.is_empty());
+
+	if let Some(dir) = non_empty("XDG_CONFIG_HOME") {
+		return Some(PathBuf::from(dir).join("cfonts"));
+	}
+
+	if let Some(dir) = non_empty("HOME") {
+		return Some(PathBuf::from(dir).join(".config").join("cfonts"));
+	}
+
+	non_empty("APPDATA").map(|dir| PathBuf::from(dir).join("cfonts"))
+}
+
+/// The number of single character edits it takes to turn one string into another
+///
+/// Swapping two neighbouring characters counts as a single edit since that's a common typo.
+///
+/// ```rust
+/// extern crate cfonts;
+///
+/// use cfonts::helpers::edit_distance;
+///
+/// assert_eq!(edit_distance("colour", "colors"), 2);
+/// assert_eq!(edit_distance("rde", "red"), 1);
+/// assert_eq!(edit_distance("chrome", "chrome"), 0);
+/// assert_eq!(edit_distance("", "tiny"), 4);
+/// ```
+pub fn edit_distance(a: &str, b: &str) -> usize {
+	let a = a.chars().collect::<Vec<char>>();
+	let b = b.chars().collect::<Vec<char>>();
+	let mut rows = vec![(0..=b.len()).collect::<Vec<usize>>()];
+
+	for i in 1..=a.len() {
+		let mut row = vec![i; b.len() + 1];
+		for j in 1..=b.len() {
+			let cost = if a[i - 1] == b[j - 1] { 0 } else { 1 };
+			row[j] = (rows[i - 1][j - 1] + cost).min(rows[i - 1][j] + 1).min(row[j - 1] + 1);
+			if i > 1 && j > 1 && a[i - 1] == b[j - 2] && a[i - 2] == b[j - 1] {
+				row[j] = row[j].min(rows[i - 2][j - 2] + 1);
+			}
+		}
+		rows.push(row);
+	}
+
+	rows[a.len()][b.len()]
+}
+
+#[test]
+fn edit_distance_works() {
+	assert_eq!(edit_distance("colour", "colors"), 2);
+	assert_eq!(edit_distance("kitten", "sitting"), 3);
+	assert_eq!(edit_distance("fnot", "font"), 1);
+	assert_eq!(edit_distance("tiny", ""), 4);
+	assert_eq!(edit_distance("", ""), 0);
+}
+
+/// Find the candidate closest to a misspelled value, ignoring case
+///
+/// Candidates need to be within a third of the length of the value, or a single edit for short values,
+/// so values don't get random suggestions.
+///
+/// ```rust
+/// extern crate cfonts;
+///
+/// use cfonts::helpers::get_closest;
+///
+/// assert_eq!(get_closest("--colour", ["--colors", "--align"]), Some("--colors"));
+/// assert_eq!(get_closest("Chorme", ["block", "chrome"]), Some("chrome"));
+/// assert_eq!(get_closest("rd", ["red", "blue"]), Some("red"));
+/// assert_eq!(get_closest("-q", ["--align", "--colors"]), None);
+/// ```
+pub fn get_closest<'a, I: IntoIterator<Item = &'a str>>(value: &str, candidates: I) -> Option<&'a str> {
+	let value = value.to_lowercase();
+	// short values still get a suggestion for a single typo
+	let max_distance = (value.chars().count() / 3).max(1);
+
+	candidates
+		.into_iter()
+		.map(|candidate| (edit_distance(&value, &candidate.to_lowercase()), candidate))
+		.filter(|(distance, _)| *distance <= max_distance)
+		.min_by_key(|(distance, _)| *distance)
+		.map(|(_, candidate)| candidate)
+}
+
+#[test]
+fn get_closest_works() {
+	assert_eq!(get_closest("centre", ["left", "center", "right"]), Some("center"));
+	assert_eq!(get_closest("middle", ["left", "center", "right"]), None);
+	// the first of equally close candidates wins
+	assert_eq!(get_closest("abcd", ["abce", "abcf"]), Some("abce"));
+	assert_eq!(get_closest("abcd", []), None);
+	assert_eq!(get_closest("rd", ["red", "blue"]), Some("red"));
+	assert_eq!(get_closest("-o", ["-a", "--align"]), Some("-a"));
+	assert_eq!(get_closest("xy", ["red", "blue"]), None);
+}
@@ -0,0 +1,189 @@
+//! The contents of this module is all about rendering our output as HTML with class names instead of inline styles
+use std::fmt::Write as _;
+
+use crate::color::{bgcolor2hex, rgb2hex, Rgb};
+use crate::config::{Align, BgColors, ColorMode, Env, Options};
+use crate::debug::{d, Dt};
+use crate::grid::Grid;
+use crate::render::render;
+
+/// The return struct you get from [`render_html()`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RenderedHtml {
+	/// The markup of the output
+	pub html: String,
+	/// The stylesheet with a class for each color used in the markup
+	pub css: String,
+}
+
+/// A run of characters within a row that share the same colors
+struct Run {
+	fg: Option<Rgb>,
+	bg: Option<Rgb>,
+	text: String,
+}
+
+/// Render the text of [`Options`] into HTML that uses class names for colors
+///
+/// Each foreground color gets a `cf-c<n>` class, each background color within the output a `cf-b<n>` class
+/// and the background of the whole output the `cf-bg` class.
+/// The outer `<div>` is left out with the `html_fragment` option in which case you need to make sure whitespace is
+/// preserved yourself.
+///
+/// ```rust
+/// extern crate cfonts;
+///
+/// use cfonts::{Colors, Fonts, Options};
+/// use cfonts::html::render_html;
+///
+/// let output = render_html(&Options {
+///     text: String::from("hi"),
+///     font: Fonts::FontConsole,
+///     colors: vec![Colors::Red],
+///     spaceless: true,
+///     ..Options::default()
+/// });
+///
+/// assert_eq!(output.html, "<div class=\"cf\"><span class=\"cf-c1\">hi</span></div>");
+/// assert!(output.css.contains(".cf-c1 { color: #ea3223; }"));
+/// ```
+pub fn render_html(options: &Options) -> RenderedHtml {
+	d("html::render_html()", 1, Dt::Head, options, &mut std::io::stdout());
+
+	// we leave the horizontal alignment and the background to the stylesheet
+	let output = render(Options {
+		env: Env::Cli,
+		color_mode: ColorMode::Truecolor,
+		background: BgColors::Transparent,
+		align: match options.align {
+			Align::Center | Align::Right => Align::Left,
+			ref align => align.clone(),
+		},
+		..options.clone()
+	});
+	let grid = Grid::from_ansi(&output.text, options);
+
+	grid2html(&grid, options)
+}
+
+/// Turn a [`Grid`] into HTML that uses class names for colors
+///
+/// Adjacent cells with the same colors are merged into a single `<span>` and spaces join whatever run is next to them
+/// since their foreground color can't be seen.
+///
+/// ```rust
+/// extern crate cfonts;
+///
+/// use cfonts::Options;
+/// use cfonts::grid::Grid;
+/// use cfonts::html::grid2html;
+///
+/// let options = Options::default();
+/// let grid = Grid::from_ansi("\x1b[31m<a>\x1b[39m & b", &options);
+///
+/// assert_eq!(
+///     grid2html(&grid, &options).html,
+///     "<div class=\"cf\"><span class=\"cf-c1\">&lt;a&gt; </span>&amp; b</div>"
+/// );
+/// ```
+pub fn grid2html(grid: &Grid, options: &Options) -> RenderedHtml {
+	d("html::grid2html()", 2, Dt::Head, options, &mut std::io::stdout());
+
+	let mut colors: Vec<Rgb> = Vec::new();
+	let mut backgrounds: Vec<Rgb> = Vec::new();
+	let class_index = |list: &mut Vec<Rgb>, rgb: &Rgb| match list.iter().position(|item| item == rgb) {
+		Some(index) => index + 1,
+		None => {
+			list.push(rgb.clone());
+			list.len()
+		}
+	};
+
+	let mut rows = Vec::new();
+	for row in &grid.rows {
+		let mut runs: Vec<Run> = Vec::new();
+		for cell in row {
+			match runs.last_mut() {
+				Some(run)
+					if run.bg == cell.bg
+						&& (run.fg == cell.fg || cell.character == ' ' || run.text.chars().all(|character| character == ' ')) =>
+				{
+					if cell.character != ' ' {
+						run.fg = cell.fg.clone();
+					}
+					run.text.push(cell.character);
+				}
+				_ => runs.push(Run {
+					fg: cell.fg.clone(),
+					bg: cell.bg.clone(),
+					text: cell.character.to_string(),
+				}),
+			}
+		}
+
+		let mut markup = String::new();
+		for run in runs {
+			let mut classes = Vec::new();
+			if let Some(fg) = &run.fg {
+				classes.push(format!("cf-c{}", class_index(&mut colors, fg)));
+			}
+			if let Some(bg) = &run.bg {
+				classes.push(format!("cf-b{}", class_index(&mut backgrounds, bg)));
+			}
+
+			match classes.is_empty() {
+				true => markup += &escape_html(&run.text),
+				false => {
+					let _ = write!(markup, "<span class=\"{}\">{}</span>", classes.join(" "), escape_html(&run.text));
+				}
+			}
+		}
+		rows.push(markup);
+	}
+
+	let background = match options.background {
+		BgColors::Transparent => None,
+		ref background => Some(bgcolor2hex(background, options)),
+	};
+
+	let mut html = rows.join("\n");
+	if !options.html_fragment {
+		let class = match background {
+			Some(_) => "cf cf-bg",
+			None => "cf",
+		};
+		html = format!("<div class=\"{}\">{}</div>", class, html);
+	}
+
+	let text_align = match options.align {
+		Align::Right => "right",
+		Align::Center => "center",
+		Align::Left | Align::Top | Align::Bottom => "left",
+	};
+	let mut css = format!(".cf {{ font-family: monospace; white-space: pre; text-align: {}; }}\n", text_align);
+	if let Some(background) = background {
+		let _ = writeln!(css, ".cf-bg {{ background: {}; }}", background);
+	}
+	for (index, rgb) in colors.iter().enumerate() {
+		let _ = writeln!(css, ".cf-c{} {{ color: {}; }}", index + 1, rgb2hex(rgb, options));
+	}
+	for (index, rgb) in backgrounds.iter().enumerate() {
+		let _ = writeln!(css, ".cf-b{} {{ background: {}; }}", index + 1, rgb2hex(rgb, options));
+	}
+
+	d(&format!("html::grid2html() -> {:?}", html), 2, Dt::Log, options, &mut std::io::stdout());
+	RenderedHtml { html, css }
+}
+
+/// Escape the characters that have a meaning in HTML
+///
+/// ```rust
+/// extern crate cfonts;
+///
+/// use cfonts::html::escape_html;
+///
+/// assert_eq!(escape_html("<b>&</b>"), "&lt;b&gt;&amp;&lt;/b&gt;");
+/// ```
+pub fn escape_html(text: &str) -> String {
+	text.replace('&', "&amp;").replace('<', "&lt;").replace('>', "&gt;")
+}
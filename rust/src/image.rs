@@ -0,0 +1,159 @@
+//! The contents of this module is all about drawing our output into images
+use std::collections::HashMap;
+
+use crate::bitmap::{get_glyph, Glyph, CELL_HEIGHT, CELL_WIDTH};
+use crate::color::{bgcolor2hex, color, hex2rgb, Rgb};
+use crate::config::{BgColors, ColorMode, Colors, Env, Options, TerminalBg};
+use crate::contrast::contrast_ratio;
+use crate::debug::{d, Dt};
+use crate::grid::Grid;
+use crate::render::render;
+
+/// Render the text of [`Options`] into a PNG image
+///
+/// Each character is drawn into a cell of [`CELL_WIDTH`] x [`CELL_HEIGHT`] pixel with our embedded bitmap font.
+/// A transparent background stays transparent in the image.
+///
+/// ```rust
+/// extern crate cfonts;
+///
+/// use cfonts::{Fonts, Options};
+/// use cfonts::image::render_png;
+///
+/// let options = Options {
+///     text: String::from("hi"),
+///     font: Fonts::FontTiny,
+///     ..Options::default()
+/// };
+///
+/// let png = render_png(&options).unwrap();
+/// assert_eq!(&png[1..4], b"PNG");
+/// ```
+pub fn render_png(options: &Options) -> Result<Vec<u8>, String> {
+	d("image::render_png()", 1, Dt::Head, options, &mut std::io::stdout());
+
+	// we paint the background ourselves so we don't get the extra line the cli background adds
+	let output = render(Options {
+		env: Env::Cli,
+		color_mode: ColorMode::Truecolor,
+		background: BgColors::Transparent,
+		..options.clone()
+	});
+	let grid = Grid::from_ansi(&output.text, options);
+
+	let background = match options.background {
+		BgColors::Transparent => None,
+		ref background => Some(hex2rgb(&bgcolor2hex(background, options), options)),
+	};
+	let foreground = get_default_foreground(&background, options);
+	d(
+		&format!("image::render_png()\nbackground:{:?}\nforeground:{:?}", background, foreground),
+		2,
+		Dt::Log,
+		options,
+		&mut std::io::stdout(),
+	);
+
+	grid2png(&grid, &background, &foreground, options)
+}
+
+/// Pick the color for cells that use the default color of the terminal
+///
+/// We pick black or white, whichever is more readable on the background.
+/// Without a background we go by the `terminal_bg` option and assume a dark terminal if it is not known.
+///
+/// ```rust
+/// extern crate cfonts;
+///
+/// use cfonts::{Options, Rgb, TerminalBg};
+/// use cfonts::image::get_default_foreground;
+///
+/// let mut options = Options::default();
+/// assert_eq!(get_default_foreground(&None, &options), Rgb::Val(255, 255, 255));
+/// assert_eq!(get_default_foreground(&Some(Rgb::Val(255, 255, 0)), &options), Rgb::Val(0, 0, 0));
+///
+/// options.terminal_bg = TerminalBg::Light;
+/// assert_eq!(get_default_foreground(&None, &options), Rgb::Val(0, 0, 0));
+/// ```
+pub fn get_default_foreground(background: &Option<Rgb>, options: &Options) -> Rgb {
+	let black = Rgb::Val(0, 0, 0);
+	let white = Rgb::Val(255, 255, 255);
+
+	match background {
+		Some(background) if contrast_ratio(&black, background) > contrast_ratio(&white, background) => black,
+		Some(_) => white,
+		None => match options.terminal_bg {
+			TerminalBg::Light => black,
+			TerminalBg::Dark | TerminalBg::Auto => white,
+		},
+	}
+}
+
+/// Draw a [`Grid`] into a PNG image
+///
+/// Cells without colors use the `background` and `foreground` given, a `background` of `None` is transparent.
+///
+/// ```rust
+/// extern crate cfonts;
+///
+/// use cfonts::{Options, Rgb};
+/// use cfonts::grid::Grid;
+/// use cfonts::image::grid2png;
+///
+/// let options = Options::default();
+/// let grid = Grid::from_ansi("█", &options);
+///
+/// let png = grid2png(&grid, &None, &Rgb::Val(255, 0, 0), &options).unwrap();
+/// assert_eq!(&png[1..4], b"PNG");
+/// ```
+pub fn grid2png(grid: &Grid, background: &Option<Rgb>, foreground: &Rgb, options: &Options) -> Result<Vec<u8>, String> {
+	d("image::grid2png()", 2, Dt::Head, options, &mut std::io::stdout());
+
+	// an image can't be empty so we draw at least one cell
+	let columns = grid.width().max(1);
+	let rows = grid.height().max(1);
+	let width = columns * CELL_WIDTH;
+	let height = rows * CELL_HEIGHT;
+
+	let to_rgba = |rgb: &Option<Rgb>| match rgb {
+		Some(rgb) => {
+			let (r, g, b) = rgb.get_value();
+			[r, g, b, 255]
+		}
+		None => [0, 0, 0, 0],
+	};
+	let default_background = to_rgba(background);
+	let mut pixels = default_background.repeat(width * height);
+	let mut glyphs: HashMap<char, Glyph> = HashMap::new();
+
+	for (row_index, row) in grid.rows.iter().enumerate() {
+		for (column_index, cell) in row.iter().enumerate() {
+			let glyph = glyphs.entry(cell.character).or_insert_with(|| get_glyph(cell.character, options));
+			let cell_background = match cell.bg {
+				Some(_) => to_rgba(&cell.bg),
+				None => default_background,
+			};
+			let cell_foreground = to_rgba(&Some(cell.fg.clone().unwrap_or_else(|| foreground.clone())));
+
+			for (y, glyph_row) in glyph.iter().enumerate() {
+				let start = ((row_index * CELL_HEIGHT + y) * width + column_index * CELL_WIDTH) * 4;
+				for (x, painted) in glyph_row.iter().enumerate() {
+					let pixel = if *painted { cell_foreground } else { cell_background };
+					pixels[start + x * 4..start + x * 4 + 4].copy_from_slice(&pixel);
+				}
+			}
+		}
+	}
+
+	let mut data = Vec::new();
+	let mut encoder = png::Encoder::new(&mut data, width as u32, height as u32);
+	encoder.set_color(png::ColorType::Rgba);
+	encoder.set_depth(png::BitDepth::Eight);
+	encoder
+		.write_header()
+		.and_then(|mut writer| writer.write_image_data(&pixels))
+		.map_err(|error| format!("Could not encode the image: {}", color(&error.to_string(), Colors::Green)))?;
+
+	d(&format!("image::grid2png() -> {}x{} pixel", width, height), 2, Dt::Log, options, &mut std::io::stdout());
+	Ok(data)
+}
@@ -0,0 +1,113 @@
+//! The contents of this module is all about rendering our output as JSON so other tools can work with it
+use serde::{Deserialize, Serialize};
+
+use crate::color::{color, rgb2hex, Rgb};
+use crate::config::{ColorMode, Colors, Env, Options};
+use crate::debug::{d, Dt};
+use crate::grid::Grid;
+use crate::render::{render, resolve_options};
+
+/// A single character of the output with its colors as hex values
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct JsonCell {
+	/// The character within this cell
+	pub character: char,
+	/// The foreground color or `None` for the default color of the terminal
+	pub fg: Option<String>,
+	/// The background color or `None` for the default color of the terminal
+	pub bg: Option<String>,
+}
+
+/// The return struct you get from [`render_json()`]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RenderedJson {
+	/// Each row of the output including ansi escape sequences
+	pub rows: Vec<String>,
+	/// Each row of the output without any escape sequences
+	pub plain_rows: Vec<String>,
+	/// How many lines of cfonts text the output has, same as [`crate::render::RenderedString`]
+	pub lines: usize,
+	/// The length of the longest row in characters
+	pub width: usize,
+	/// The number of rows
+	pub height: usize,
+	/// The options after resolving everything that depends on the environment
+	pub options: Options,
+	/// The character and colors of each cell for each row
+	pub cells: Vec<Vec<JsonCell>>,
+}
+
+impl RenderedJson {
+	/// Serialize this output into a pretty printed JSON string
+	pub fn to_json(&self) -> Result<String, String> {
+		serde_json::to_string_pretty(self)
+			.map_err(|error| format!("Could not serialize the output: {}", color(&error.to_string(), Colors::Green)))
+	}
+}
+
+/// Render the text of [`Options`] into a struct ready to be serialized into JSON
+///
+/// We always render for [`Env::Cli`] and use truecolor unless a color mode has been picked explicitly
+/// since JSON is rarely read in a terminal.
+///
+/// ```rust
+/// extern crate cfonts;
+///
+/// use cfonts::{Colors, Fonts, Options};
+/// use cfonts::json::render_json;
+///
+/// let output = render_json(&Options {
+///     text: String::from("hi"),
+///     font: Fonts::FontConsole,
+///     colors: vec![Colors::Red],
+///     spaceless: true,
+///     ..Options::default()
+/// });
+///
+/// assert_eq!(output.plain_rows, vec![String::from("hi")]);
+/// assert_eq!((output.width, output.height, output.lines), (2, 1, 1));
+/// assert_eq!(output.cells[0][0].fg, Some(String::from("#ea3223")));
+/// ```
+pub fn render_json(options: &Options) -> RenderedJson {
+	d("json::render_json()", 1, Dt::Head, options, &mut std::io::stdout());
+
+	let options = resolve_options(&Options {
+		env: Env::Cli,
+		color_mode: match options.color_mode {
+			ColorMode::Auto => ColorMode::Truecolor,
+			ref color_mode => color_mode.clone(),
+		},
+		..options.clone()
+	});
+	let output = render(options.clone());
+	let grid = Grid::from_ansi(&output.text, &options);
+
+	let to_hex = |rgb: &Option<Rgb>| rgb.as_ref().map(|rgb| rgb2hex(rgb, &options));
+	let cells = grid
+		.rows
+		.iter()
+		.map(|row| {
+			row
+				.iter()
+				.map(|cell| JsonCell {
+					character: cell.character,
+					fg: to_hex(&cell.fg),
+					bg: to_hex(&cell.bg),
+				})
+				.collect::<Vec<JsonCell>>()
+		})
+		.collect::<Vec<Vec<JsonCell>>>();
+
+	let result = RenderedJson {
+		rows: output.text.split('\n').map(String::from).collect(),
+		plain_rows: grid.rows.iter().map(|row| row.iter().map(|cell| cell.character).collect()).collect(),
+		lines: output.lines,
+		width: grid.width(),
+		height: grid.height(),
+		options,
+		cells,
+	};
+
+	d(&format!("json::render_json() -> {:?}", result.plain_rows), 1, Dt::Log, &result.options, &mut std::io::stdout());
+	result
+}
@@ -0,0 +1,201 @@
+//! The contents of this module is all about putting rendered outputs next to each other
+use crate::config::{Env, Options};
+use crate::debug::{d, Dt};
+use crate::plain::{strip_ansi, strip_html};
+use crate::render::RenderedString;
+
+/// The `VerticalAlign` enum includes all options of how blocks of different heights line up next to each other
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub enum VerticalAlign {
+	/// Line blocks up at their first row
+	#[default]
+	Top,
+	/// Center blocks vertically; an odd leftover row goes below
+	Middle,
+	/// Line blocks up at their last row
+	Bottom,
+}
+
+/// The colors that are still open at the end of a row and carry over into the next row
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+struct OpenColors {
+	/// The escape sequence that set the foreground color
+	fg: Option<String>,
+	/// The escape sequence that set the background color
+	bg: Option<String>,
+}
+
+impl OpenColors {
+	/// Follow all SGR sequences within a row to know which colors are open at its end
+	fn update(&mut self, row: &str) {
+		let mut rest = row;
+		while let Some(start) = rest.find("\x1b[") {
+			let sequence = &rest[start..];
+			let end = match sequence[2..].find(|character: char| character.is_ascii_alphabetic()) {
+				Some(end) => end + 2,
+				None => break,
+			};
+			if sequence[end..].starts_with('m') {
+				let sequence = &sequence[..=end];
+				let param = sequence[2..end].split(';').next().unwrap_or("").parse::<u16>().unwrap_or(0);
+				match param {
+					0 => *self = OpenColors::default(),
+					39 => self.fg = None,
+					49 => self.bg = None,
+					30..=38 | 90..=97 => self.fg = Some(sequence.to_string()),
+					40..=48 | 100..=107 => self.bg = Some(sequence.to_string()),
+					_ => {}
+				}
+			}
+			rest = &sequence[end + 1..];
+		}
+	}
+
+	/// The escape sequences to open all colors again
+	fn open(&self) -> String {
+		format!("{}{}", self.fg.as_deref().unwrap_or(""), self.bg.as_deref().unwrap_or(""))
+	}
+
+	/// The escape sequences to close all open colors
+	fn close(&self) -> String {
+		format!("{}{}", if self.fg.is_some() { "\x1b[39m" } else { "" }, if self.bg.is_some() { "\x1b[49m" } else { "" })
+	}
+}
+
+/// The number of columns a row of output takes up without escape sequences or the markup of [`Env::Browser`]
+///
+/// ```rust
+/// extern crate cfonts;
+///
+/// use cfonts::{Env, Options};
+/// use cfonts::layout::get_width;
+///
+/// let options = Options::default();
+/// assert_eq!(get_width("\x1b[31m█▀\x1b[39m a", &options), 4);
+///
+/// let options = Options { env: Env::Browser, ..Options::default() };
+/// assert_eq!(get_width("<span style=\"color:#ea3223\">█▀</span> a", &options), 4);
+/// ```
+pub fn get_width(row: &str, options: &Options) -> usize {
+	match options.env {
+		Env::Cli => strip_ansi(row).chars().count(),
+		Env::Browser => strip_html(&strip_ansi(row)).chars().count(),
+	}
+}
+
+/// Split a rendered output into rows that each open and close their own colors
+///
+/// Returns the rows and the visible width of the widest row.
+///
+/// ```rust
+/// extern crate cfonts;
+///
+/// use cfonts::Options;
+/// use cfonts::layout::get_rows;
+/// use cfonts::render::RenderedString;
+///
+/// let block = RenderedString {
+///     text: String::from("\x1b[31mab\nc\x1b[39m"),
+///     vec: vec![String::from("\x1b[31mab"), String::from("c\x1b[39m")],
+///     lines: 1,
+///     options: Options::default(),
+/// };
+///
+/// assert_eq!(
+///     get_rows(&block),
+///     (vec![String::from("\x1b[31mab\x1b[39m"), String::from("\x1b[31mc\x1b[39m")], 2)
+/// );
+/// ```
+pub fn get_rows(block: &RenderedString) -> (Vec<String>, usize) {
+	let mut colors = OpenColors::default();
+	let rows = block
+		.vec
+		.join("\n")
+		.split('\n')
+		.map(|row| {
+			let opened = colors.open();
+			colors.update(row);
+			format!("{}{}{}", opened, row, colors.close())
+		})
+		.collect::<Vec<String>>();
+	let width = rows.iter().map(|row| get_width(row, &block.options)).max().unwrap_or(0);
+
+	(rows, width)
+}
+
+/// Join rendered outputs horizontally into one output
+///
+/// Blocks are padded to their widest row and separated by `gap` spaces.
+/// Blocks with fewer rows, e.g. because their font has fewer lines, are lined up via `align`.
+/// Colors that span multiple rows are closed at the end of each row so they don't run into the next block.
+/// The returned [`RenderedString`] carries the [`crate::Options`] of the first block.
+/// Blocks are expected to be rendered for [`crate::Env::Cli`] and without [`crate::Align::Center`] or
+/// [`crate::Align::Right`] since those pad rows to the width of the terminal.
+///
+/// ```rust
+/// extern crate cfonts;
+///
+/// use cfonts::{ render, Options, Fonts };
+/// use cfonts::layout::{join_horizontal, VerticalAlign};
+///
+/// let icon = render(Options {
+///     text: String::from("i"),
+///     font: Fonts::FontTiny,
+///     spaceless: true,
+///     ..Options::default()
+/// });
+/// let word = render(Options {
+///     text: String::from("info"),
+///     font: Fonts::FontConsole,
+///     spaceless: true,
+///     ..Options::default()
+/// });
+///
+/// let output = join_horizontal(&[icon, word], 1, VerticalAlign::Bottom);
+/// assert_eq!(output.vec, vec![String::from(" █     "), String::from(" █ info")]);
+/// ```
+pub fn join_horizontal(blocks: &[RenderedString], gap: usize, align: VerticalAlign) -> RenderedString {
+	let options = blocks.first().map(|block| block.options.clone()).unwrap_or_default();
+	d("layout::join_horizontal()", 1, Dt::Head, &options, &mut std::io::stdout());
+
+	let lines = blocks.iter().map(|block| block.lines).max().unwrap_or(0);
+	let blocks = blocks.iter().map(|block| (get_rows(block), &block.options)).collect::<Vec<_>>();
+	let height = blocks.iter().map(|((rows, _), _)| rows.len()).max().unwrap_or(0);
+	d(
+		&format!(
+			"layout::join_horizontal()\nheight:{:?}\nwidths:{:?}",
+			height,
+			blocks.iter().map(|((_, width), _)| width).collect::<Vec<_>>()
+		),
+		1,
+		Dt::Log,
+		&options,
+		&mut std::io::stdout(),
+	);
+
+	let mut output = vec![String::new(); height];
+	for (index, ((rows, width), block_options)) in blocks.iter().enumerate() {
+		let top = match align {
+			VerticalAlign::Top => 0,
+			VerticalAlign::Middle => (height - rows.len()) / 2,
+			VerticalAlign::Bottom => height - rows.len(),
+		};
+
+		for (row_index, line) in output.iter_mut().enumerate() {
+			if index > 0 {
+				line.push_str(&" ".repeat(gap));
+			}
+			let row = row_index.checked_sub(top).and_then(|row_index| rows.get(row_index)).map_or("", String::as_str);
+			let padding = width - get_width(row, block_options);
+			line.push_str(row);
+			line.push_str(&" ".repeat(padding));
+		}
+	}
+
+	RenderedString {
+		text: output.join("\n"),
+		vec: output,
+		lines,
+		options,
+	}
+}
@@ -78,21 +78,42 @@
 
 extern crate exitcode;
 
+pub mod animate;
 pub mod args;
+pub mod bitmap;
+pub mod border;
+pub mod cast;
 pub mod chars;
 pub mod cli;
+pub mod clock;
 pub mod color;
+pub mod completions;
 pub mod config;
+pub mod contrast;
 pub mod debug;
+pub mod defaults;
 pub mod font;
 pub mod gradient;
+pub mod grid;
 pub mod helpers;
+pub mod html;
+pub mod image;
+pub mod json;
+pub mod layout;
+pub mod marquee;
+pub mod plain;
 pub mod render;
+pub mod terminal;
+pub mod theme;
 
 pub use color::Rgb;
-pub use config::{Align, BgColors, Colors, Env, Fonts, Options};
+pub use config::{
+	Align, BgColors, Border, CandyMode, ColorMode, Colors, Cvd, Effect, Env, Fonts, Options, OutputFormat, Shell,
+	TerminalBg,
+};
 use debug::{d, Dt};
-pub use render::render;
+pub use render::{render, render_many};
+pub use theme::{Theme, ThemeRegistry};
 
 /// The `say` function will print your cfonts output to `stdout`.
 ///
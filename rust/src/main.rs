@@ -37,30 +37,67 @@
 //! **Learn more by reading the [README](https://github.com/dominikwilkowski/cfonts)**
 extern crate exitcode;
 
+use signal_hook::consts::{SIGINT, SIGTERM};
 use std::env::args;
+use std::io::IsTerminal;
+use std::path::Path;
+use std::sync::atomic::AtomicBool;
+use std::sync::Arc;
 
+pub mod animate;
 pub mod args;
+pub mod bitmap;
+pub mod border;
+pub mod cast;
 pub mod chars;
 pub mod cli;
+pub mod clock;
 pub mod color;
+pub mod completions;
 pub mod config;
+pub mod contrast;
 pub mod debug;
+pub mod defaults;
 pub mod font;
 pub mod gradient;
+pub mod grid;
 pub mod helpers;
+pub mod html;
+pub mod image;
+pub mod json;
+pub mod layout;
+pub mod marquee;
+pub mod plain;
 pub mod render;
+pub mod terminal;
+pub mod theme;
 
+use clock::{SystemClock, TimeSource};
+use color::color;
+use config::{Colors, Options, OutputFormat};
 use debug::{d, Dt};
-use render::render;
+use gradient::GradientPresetRegistry;
+use render::render_many;
 
 fn main() {
-	let options = match args::parse(args().collect::<Vec<String>>()) {
-		Ok(options) => options,
+	// a broken config file must not get in the way of the flags that help fixing it
+	let (defaults, mut sources, config_error) =
+		match defaults::load_layers(&std::env::current_dir().unwrap_or_default(), &Options::default())
+			.and_then(|layers| defaults::apply_layers(&layers, Options::default()))
+		{
+			Ok((defaults, sources)) => (defaults, sources, None),
+			Err(msg) => (Options::default(), defaults::Sources::new(), Some(msg)),
+		};
+
+	let mut blocks = match args::parse_many(args().collect::<Vec<String>>(), defaults.clone()) {
+		Ok(blocks) => blocks,
 		Err(msg) => {
 			println!("{}", msg);
 			std::process::exit(exitcode::USAGE);
 		}
 	};
+	// the texts after --then are rendered below the first one
+	let options = blocks.remove(0);
 
 	d("main()", 1, Dt::Head, &options, &mut std::io::stdout());
 	d(&format!("main()\nCLI args:{:#?}", args().collect::<Vec<String>>()), 1, Dt::Log, &options, &mut std::io::stdout());
@@ -75,6 +112,200 @@ fn main() {
 		return;
 	}
 
-	let render_options = render(options);
+	if options.man {
+		print!("{}", cli::man(&options));
+		return;
+	}
+
+	if let Some(shell) = &options.completions {
+		match completions::generate(shell, &options) {
+			Ok(script) => print!("{}", script),
+			Err(msg) => {
+				println!("{}", msg);
+				std::process::exit(exitcode::CONFIG);
+			}
+		}
+		return;
+	}
+
+	if options.print_config {
+		defaults::track_changes(&defaults, &options, &defaults::Source::Cli, &mut sources);
+		print!("{}", defaults::print_config(&options, &sources));
+		if let Some(msg) = &config_error {
+			println!("\n{}", msg);
+		}
+		return;
+	}
+
+	if let Some(msg) = config_error {
+		println!("{}", msg);
+		std::process::exit(exitcode::CONFIG);
+	}
+
+	if options.list_gradients {
+		let mut presets = GradientPresetRegistry::default();
+		let loaded = presets.load_config(&options);
+		println!("{}", cli::list_gradients(&presets, &options));
+		// the presets that could be read are listed along with what went wrong with the others
+		match loaded {
+			Err(msg) => println!("{}", msg),
+			Ok(broken) => {
+				let mut names = broken.keys().collect::<Vec<&String>>();
+				names.sort();
+				for name in names {
+					println!("{}", broken[name]);
+				}
+			}
+		}
+		return;
+	}
+
+	// the texts after --then share the background of the first one
+	let contrast_issues = std::iter::once(options.clone())
+		.chain(blocks.iter().map(|block| Options {
+			background: options.background.clone(),
+			..block.clone()
+		}))
+		.flat_map(|block| contrast::check_contrast(&block))
+		.collect::<Vec<String>>();
+	for issue in &contrast_issues {
+		eprintln!("{}", issue);
+	}
+	if options.strict_contrast && !contrast_issues.is_empty() {
+		std::process::exit(exitcode::DATAERR);
+	}
+
+	let options = match options.clock.is_some() || options.countdown.is_some() {
+		true => {
+			if std::io::stdout().is_terminal() && options.output.is_none() && options.output_format == OutputFormat::Text {
+				let result = clock::run(
+					&options,
+					&mut SystemClock,
+					|| terminal::get_terminal_width().into(),
+					&mut std::io::stdout(),
+					&stop_on_signal(),
+				);
+				exit_when_stopped(result);
+				return;
+			}
+
+			// everything that isn't a terminal gets what the clock shows right now
+			let now = SystemClock.now();
+			let end = options.countdown.map(|countdown| now.timestamp.saturating_add(countdown));
+			Options {
+				text: clock::get_text(&options, &now, end).0,
+				..options
+			}
+		}
+		false => options,
+	};
+
+	if let Some(output) = &options.output {
+		let path = Path::new(output);
+		let extension = path.extension().and_then(|extension| extension.to_str()).map(|extension| extension.to_lowercase());
+		let files = match extension.as_deref() {
+			Some("png") => image::render_png(&options).map(|data| vec![(path.to_path_buf(), data)]),
+			Some("html") => {
+				// the stylesheet goes next to the markup so the page works with a strict content security policy
+				let rendered = html::render_html(&options);
+				Ok(vec![
+					(path.to_path_buf(), rendered.html.into_bytes()),
+					(path.with_extension("css"), rendered.css.into_bytes()),
+				])
+			}
+			Some("txt") => Ok(vec![(path.to_path_buf(), plain::render_plain(&options).text.into_bytes())]),
+			Some("json") => json::render_json(&options).to_json().map(|data| vec![(path.to_path_buf(), data.into_bytes())]),
+			Some("cast") => cast::render_cast(&options).map(|data| vec![(path.to_path_buf(), data.into_bytes())]),
+			_ => {
+				println!(
+					"The file type of \"{}\" is not supported, use one of: png, html, json, txt, cast",
+					color(output, Colors::Green)
+				);
+				std::process::exit(exitcode::USAGE);
+			}
+		};
+		let result = files.and_then(|files| {
+			files.iter().try_for_each(|(file, data)| {
+				std::fs::write(file, data).map_err(|error| {
+					format!("Could not write to \"{}\": {}", color(&file.display().to_string(), Colors::Green), error)
+				})
+			})
+		});
+		if let Err(msg) = result {
+			println!("{}", msg);
+			std::process::exit(exitcode::CANTCREAT);
+		}
+		return;
+	}
+
+	match options.output_format {
+		OutputFormat::Text => {}
+		OutputFormat::Html => {
+			// like for files the stylesheet is kept apart from the markup so it works with a strict content security policy
+			let rendered = html::render_html(&options);
+			match options.html_css {
+				true => print!("{}", rendered.css),
+				false => println!("{}", rendered.html),
+			}
+			return;
+		}
+		OutputFormat::Plain => {
+			println!("{}", plain::render_plain(&options).text);
+			return;
+		}
+		OutputFormat::Json => {
+			match json::render_json(&options).to_json() {
+				Ok(json) => println!("{}", json),
+				Err(msg) => {
+					println!("{}", msg);
+					std::process::exit(exitcode::SOFTWARE);
+				}
+			}
+			return;
+		}
+	}
+
+	// we only animate when someone is watching, pipes get the plain output
+	if std::io::stdout().is_terminal() {
+		if options.marquee {
+			play_in_terminal(marquee::frames(options.clone(), terminal::get_terminal_width().into()));
+			return;
+		}
+		if let Some(effect) = &options.animate {
+			play_in_terminal(animate::frames(options.clone(), effect.clone()));
+			return;
+		}
+	}
+
+	blocks.insert(0, options);
+	let render_options = render_many(&blocks);
 	println!("{}", render_options.text);
 }
+
+/// Play frames in the console and exit if we are interrupted
+fn play_in_terminal<I: Iterator<Item = animate::Frame>>(frames: I) {
+	exit_when_stopped(animate::play(frames, &mut std::io::stdout(), &stop_on_signal()));
+}
+
+/// A flag that is set once we are asked to quit with Ctrl-C
+fn stop_on_signal() -> Arc<AtomicBool> {
+	let stop = Arc::new(AtomicBool::new(false));
+	for signal in [SIGINT, SIGTERM] {
+		let _ = signal_hook::flag::register(signal, Arc::clone(&stop));
+	}
+	stop
+}
+
+/// Exit if playing in the console was interrupted or failed
+fn exit_when_stopped(result: std::io::Result<bool>) {
+	match result {
+		Ok(false) => {}
+		// the conventional exit code after being interrupted with Ctrl-C
+		Ok(true) => std::process::exit(130),
+		Err(error) => {
+			println!("{}", animate::SHOW_CURSOR);
+			println!("Could not play the animation: {}", color(&error.to_string(), Colors::Green));
+			std::process::exit(exitcode::IOERR);
+		}
+	}
+}
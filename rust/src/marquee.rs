@@ -0,0 +1,128 @@
+//! The contents of this module is all about scrolling output that is wider than the terminal
+use std::time::Duration;
+
+use crate::animate::{get_frame_count, Frame};
+use crate::color::get_background_color_for;
+use crate::config::{Align, BgColors, Env, Options};
+use crate::debug::{d, Dt};
+use crate::grid::Grid;
+use crate::render::{render, resolve_options};
+
+/// Cut the columns `start..start + width` out of a line of output
+///
+/// All escape sequences are kept, even those of columns we cut away, so colors and backgrounds stay intact.
+///
+/// ```rust
+/// extern crate cfonts;
+///
+/// use cfonts::marquee::slice_line;
+///
+/// assert_eq!(slice_line("abcde", 1, 3), "bcd");
+/// assert_eq!(slice_line("\x1b[31mab\x1b[39mc", 1, 5), "\x1b[31mb\x1b[39mc");
+/// ```
+pub fn slice_line(line: &str, start: usize, width: usize) -> String {
+	let mut result = String::with_capacity(line.len());
+	let mut chars = line.chars().peekable();
+	let mut column = 0;
+
+	while let Some(character) = chars.next() {
+		if character == '\x1b' && chars.peek() == Some(&'[') {
+			result.push(character);
+			for next in chars.by_ref() {
+				result.push(next);
+				if next.is_ascii_alphabetic() {
+					break;
+				}
+			}
+		} else {
+			if column >= start && column < start + width {
+				result.push(character);
+			}
+			column += 1;
+		}
+	}
+
+	result
+}
+
+/// Generate the frames of a marquee scrolling the output through a viewport of `width` columns
+///
+/// The text is rendered into a single row without wrapping, enters the viewport from the right and moves one column
+/// per frame at the `speed` of [`Options`].
+/// The marquee loops forever unless [`Options`] has a `duration`.
+///
+/// ```rust
+/// extern crate cfonts;
+///
+/// use cfonts::{Fonts, Options};
+/// use cfonts::marquee::frames;
+///
+/// let options = Options {
+///     text: String::from("hi"),
+///     font: Fonts::FontConsole,
+///     spaceless: true,
+///     ..Options::default()
+/// };
+///
+/// let result = frames(options, 3).take(6).map(|frame| frame.text).collect::<Vec<String>>();
+/// assert_eq!(result, vec!["   ", "  h", " hi", "hi ", "i  ", "   "]);
+/// ```
+pub fn frames(options: Options, width: usize) -> impl Iterator<Item = Frame> {
+	d("marquee::frames()", 1, Dt::Head, &options, &mut std::io::stdout());
+
+	let options = resolve_options(&Options {
+		no_wrap: true,
+		align: match options.align {
+			Align::Center | Align::Right => Align::Left,
+			ref align => align.clone(),
+		},
+		..options
+	});
+	// we add the background to each frame ourselves so it covers the padding too
+	let text = render(Options {
+		background: BgColors::Transparent,
+		..options.clone()
+	})
+	.text;
+	let (background_open, background_close) = match options.background != BgColors::Transparent && options.env == Env::Cli
+	{
+		true => get_background_color_for(&options.background, &options),
+		false => (String::new(), String::new()),
+	};
+	let block_width = Grid::from_ansi(&text, &options).width();
+
+	// the viewport slides over the block with an empty viewport in front so the text enters from the right
+	let rows = text
+		.split('\n')
+		.map(|row| {
+			let row_width = Grid::from_ansi(row, &options).width();
+			let row = format!("{}{}{}", " ".repeat(width), row, " ".repeat(block_width - row_width));
+			row.clone() + &row
+		})
+		.collect::<Vec<String>>();
+	let period = width + block_width;
+
+	let interval = Duration::from_millis(1000 / options.speed.max(1) as u64);
+	let count = match options.duration {
+		Some(duration) => get_frame_count(duration, options.speed.max(1) as u64),
+		None => usize::MAX,
+	};
+	d(
+		&format!("marquee::frames()\nwidth:{}\nblock_width:{}\nframes:{}", width, block_width, count),
+		1,
+		Dt::Log,
+		&options,
+		&mut std::io::stdout(),
+	);
+
+	(0..count).map(move |index| {
+		let text = rows.iter().map(|row| slice_line(row, index % period, width)).collect::<Vec<String>>().join("\n");
+		Frame {
+			text: match background_open.is_empty() {
+				true => text,
+				false => format!("{}\n{}{}", background_open, text, background_close),
+			},
+			delay: if index + 1 == count { Duration::ZERO } else { interval },
+		}
+	})
+}
@@ -0,0 +1,161 @@
+//! The contents of this module is all about turning our output into plain text without colors
+use crate::config::{BgColors, ColorMode, Env, Options};
+use crate::debug::{d, Dt};
+use crate::render::{render, RenderedString};
+
+/// Remove all ansi escape sequences from a string
+///
+/// ```rust
+/// extern crate cfonts;
+///
+/// use cfonts::plain::strip_ansi;
+///
+/// assert_eq!(strip_ansi("\x1b[31mred\x1b[39m \x1b[38;2;255;0;0m█\x1b[39m"), "red █");
+/// ```
+pub fn strip_ansi(text: &str) -> String {
+	let mut result = String::with_capacity(text.len());
+	let mut chars = text.chars().peekable();
+
+	while let Some(character) = chars.next() {
+		if character == '\x1b' && chars.peek() == Some(&'[') {
+			chars.next();
+			// a control sequence ends with its first letter
+			for next in chars.by_ref() {
+				if next.is_ascii_alphabetic() {
+					break;
+				}
+			}
+		} else {
+			result.push(character);
+		}
+	}
+
+	result
+}
+
+/// Remove the markup we add to the output for [`Env::Browser`]
+///
+/// Only our own tags are removed so glyphs like `<` stay where they are.
+///
+/// ```rust
+/// extern crate cfonts;
+///
+/// use cfonts::plain::strip_html;
+///
+/// assert_eq!(
+///     strip_html("<div style=\"font-family:monospace\"><span style=\"color:#ea3223\">a<b</span><br>\nc</div>"),
+///     "a<b\nc"
+/// );
+/// ```
+pub fn strip_html(text: &str) -> String {
+	let mut result = String::with_capacity(text.len());
+	let mut rest = text;
+
+	while let Some(start) = rest.find('<') {
+		result.push_str(&rest[..start]);
+		rest = &rest[start..];
+
+		let tag_length = if rest.starts_with("<span style=\"") || rest.starts_with("<div style=\"") {
+			rest.find("\">").map(|end| end + 2)
+		} else {
+			["</span>", "</div>", "<br>"].iter().find(|tag| rest.starts_with(**tag)).map(|tag| tag.len())
+		};
+
+		match tag_length {
+			Some(length) => rest = &rest[length..],
+			None => {
+				result.push('<');
+				rest = &rest[1..];
+			}
+		}
+	}
+	result.push_str(rest);
+
+	result
+}
+
+/// Remove trailing whitespace from each line of a string
+///
+/// ```rust
+/// extern crate cfonts;
+///
+/// use cfonts::plain::trim_lines;
+///
+/// assert_eq!(trim_lines(" a  \n\n b \n"), " a\n\n b\n");
+/// ```
+pub fn trim_lines(text: &str) -> String {
+	text.split('\n').map(|line| line.trim_end()).collect::<Vec<&str>>().join("\n")
+}
+
+/// Turn a [`RenderedString`] into plain text without any ansi escape sequences or HTML
+///
+/// The layout stays exactly the same unless the `trim` option of [`Options`] removes trailing whitespace.
+///
+/// ```rust
+/// extern crate cfonts;
+///
+/// use cfonts::{render, Colors, Fonts, Options};
+/// use cfonts::plain::to_plain;
+///
+/// let output = render(Options {
+///     text: String::from("hi"),
+///     font: Fonts::FontTiny,
+///     colors: vec![Colors::Red],
+///     ..Options::default()
+/// });
+///
+/// assert_eq!(to_plain(&output).text, "\n\n █ █ █\n █▀█ █\n\n");
+/// ```
+pub fn to_plain(output: &RenderedString) -> RenderedString {
+	d("plain::to_plain()", 1, Dt::Head, &output.options, &mut std::io::stdout());
+
+	let strip = |text: &str| {
+		let mut text = strip_ansi(text);
+		if output.options.env == Env::Browser {
+			text = strip_html(&text.replace("<br>\n", "\n"));
+		}
+		if output.options.trim {
+			text = trim_lines(&text);
+		}
+		text
+	};
+
+	let result = RenderedString {
+		text: strip(&output.text),
+		vec: output.vec.iter().map(|line| strip(line)).collect(),
+		lines: output.lines,
+		options: output.options.clone(),
+	};
+
+	d(&format!("plain::to_plain() -> {:?}", result.text), 1, Dt::Log, &output.options, &mut std::io::stdout());
+	result
+}
+
+/// Render the text of [`Options`] as plain text
+///
+/// Colors and the background are turned off for the render since they can't be seen in plain text anyway.
+///
+/// ```rust
+/// extern crate cfonts;
+///
+/// use cfonts::{BgColors, Env, Fonts, Options};
+/// use cfonts::plain::render_plain;
+///
+/// let output = render_plain(&Options {
+///     text: String::from("hi"),
+///     font: Fonts::FontConsole,
+///     background: BgColors::Red,
+///     env: Env::Browser,
+///     spaceless: true,
+///     ..Options::default()
+/// });
+///
+/// assert_eq!(output.text, "hi");
+/// ```
+pub fn render_plain(options: &Options) -> RenderedString {
+	to_plain(&render(Options {
+		color_mode: ColorMode::NoColor,
+		background: BgColors::Transparent,
+		..options.clone()
+	}))
+}
@@ -1,16 +1,22 @@
 //! The contents of this module is all about composing all functions together to render our output
 extern crate enable_ansi_support;
 use enable_ansi_support::enable_ansi_support;
-use terminal_size::{terminal_size, Width};
 
+use crate::border::{add_border, trim_columns};
 use crate::chars::{
 	add_letter, add_line, add_line_height, align_last_line, get_letter_length, get_letter_space, paint_letter,
+	paint_letter_at,
 };
-use crate::color::{bgcolor2hex, get_background_color};
-use crate::config::{Align, BgColors, Env, Options};
+use crate::color::{
+	bgcolor2hex, color2hex, get_background_color_for, get_readable_color, hex2rgb, resolve_color_mode, CandyPosition,
+};
+use crate::config::{Align, BgColors, ColorMode, Colors, Env, Options};
 use crate::debug::{d, Dt};
 use crate::font;
 use crate::gradient::add_gradient_colors;
+use crate::grid::{Blend, Grid};
+use crate::layout::{get_rows, get_width};
+use crate::terminal::{get_terminal_width, resolve_terminal_bg};
 
 /// The return struct you get from [`render()`]
 pub struct RenderedString {
@@ -25,6 +31,63 @@ pub struct RenderedString {
 	pub options: Options,
 }
 
+/// Resolve all options that depend on the environment into the values a render will use
+///
+/// We detect the color support only once per render instead of for each letter
+/// and pick a seed so candy colors are consistent within this render.
+/// The terminal is only asked for its background when candy colors or colors that may not be readable on it need it.
+/// Those colors are swapped via [`get_readable_color()`].
+/// Resolving already resolved options doesn't change them.
+///
+/// ```rust
+/// extern crate cfonts;
+///
+/// use cfonts::{ColorMode, Options};
+/// use cfonts::render::resolve_options;
+///
+/// let options = resolve_options(&Options::default());
+/// assert!(options.seed.is_some());
+/// assert_ne!(options.color_mode, ColorMode::Auto);
+/// assert_eq!(resolve_options(&options), options);
+/// ```
+pub fn resolve_options(options: &Options) -> Options {
+	let colors =
+		|| options.colors.iter().chain(options.border_color.iter()).chain(std::iter::once(&options.shadow_color));
+	// candy colors and black or white depend on the background of the terminal
+	// while the system color is readable on any terminal and only swapped when we already know the background
+	let needs_terminal_bg = options.colors.contains(&Colors::Candy)
+		|| (options.background == BgColors::Transparent
+			&& colors().any(|color| matches!(color, Colors::Black | Colors::White | Colors::WhiteBright)));
+	let terminal_bg = match needs_terminal_bg {
+		true => resolve_terminal_bg(options),
+		false => options.terminal_bg.clone(),
+	};
+	let with_bg = Options {
+		terminal_bg: terminal_bg.clone(),
+		..options.clone()
+	};
+	let resolved = Options {
+		color_mode: resolve_color_mode(options),
+		seed: Some(options.seed.unwrap_or_else(rand::random)),
+		colors: options.colors.iter().map(|color| get_readable_color(color, &with_bg)).collect(),
+		border_color: options.border_color.iter().map(|color| get_readable_color(color, &with_bg)).collect(),
+		shadow_color: get_readable_color(&options.shadow_color, &with_bg),
+		terminal_bg,
+		..options.clone()
+	};
+	d(
+		&format!(
+			"render::resolve_options()\ncolor_mode:{:?}\nseed:{:?}\nterminal_bg:{:?}",
+			resolved.color_mode, resolved.seed, resolved.terminal_bg
+		),
+		1,
+		Dt::Log,
+		&resolved,
+		&mut std::io::stdout(),
+	);
+	resolved
+}
+
 /// ## Render your text to a sexy font ready to be printed to `stdout`
 ///
 /// Use this function if you want control over what to do with the output and
@@ -59,20 +122,24 @@ pub fn render(options: Options) -> RenderedString {
 	d("render()", 1, Dt::Head, &options, &mut std::io::stdout());
 	d(&format!("render() Options\n{:#?}", options), 1, Dt::Log, &options, &mut std::io::stdout());
 
+	let returned_options = options.clone();
+	let options = resolve_options(&options);
+
 	// enable ansi support in windows 10
 	if let Ok(()) = enable_ansi_support() {
 		d("render() enabled ansi support in windows", 2, Dt::Log, &options, &mut std::io::stdout());
 	}
 
-	let size = terminal_size();
+	if options.border.is_some() || options.padding != [0; 4] {
+		return render_framed(options, returned_options);
+	}
+
+	if options.shadow {
+		return render_shadowed(options, returned_options);
+	}
+
 	let terminal_width = match options.env {
-		Env::Cli => {
-			if let Some((Width(w), _)) = size {
-				w
-			} else {
-				80
-			}
-		}
+		Env::Cli => get_terminal_width(),
 		Env::Browser => 0xFFFF,
 	};
 	d(&format!("render()\nterminal_width:{:?}", terminal_width), 1, Dt::Log, &options, &mut std::io::stdout());
@@ -82,6 +149,7 @@ pub fn render(options: Options) -> RenderedString {
 	let mut line_length = 0;
 	let mut letter_count = 0;
 	let mut lines = 0;
+	let mut letter_index = 0;
 	let mut output: Vec<String> = Vec::new();
 
 	// some fonts have smaller letter spacing
@@ -127,7 +195,7 @@ pub fn render(options: Options) -> RenderedString {
 				d(&format!("render()\nfont_letter:{:?}", font_letter), 2, Dt::Log, &options, &mut std::io::stdout());
 				let this_letter_len = get_letter_length(font_letter, font.colors, &options);
 				if font_letter[0] == "|"
-					|| this_letter_len + letter_space_len + line_length > terminal_width.into()
+					|| this_letter_len + letter_space_len + line_length > terminal_width.into() && !options.no_wrap
 					|| letter_count + 1 > options.max_length && options.max_length > 0
 				{
 					d(
@@ -157,7 +225,13 @@ pub fn render(options: Options) -> RenderedString {
 				}
 
 				if font_letter[0] != "|" {
-					let painted_letter = paint_letter(font_letter, font.colors, &options);
+					letter_index += 1;
+					let position = CandyPosition {
+						letter: letter_index,
+						line: lines - 1,
+						..CandyPosition::default()
+					};
+					let painted_letter = paint_letter_at(font_letter, font.colors, &position, &options);
 					d("render() added color to letter", 1, Dt::Log, &options, &mut std::io::stdout());
 					d(&format!("render()\npainted_letter:{:?}", painted_letter), 2, Dt::Log, &options, &mut std::io::stdout());
 					add_letter(&mut output, &painted_letter_space, &options);
@@ -178,6 +252,183 @@ pub fn render(options: Options) -> RenderedString {
 		d("render() added gradient colors", 1, Dt::Log, &options, &mut std::io::stdout());
 	}
 
+	let text = frame_output(&mut output, &options);
+
+	RenderedString {
+		text,
+		vec: output,
+		lines,
+		options: returned_options,
+	}
+}
+
+/// ## Render several texts stacked on top of each other into one output
+///
+/// Each [`Options`] struct is one block with its own font, colors and so on, rendered the same way as with [`render()`].
+/// All blocks share the alignment, spacing, background and env of the first block.
+/// A single block gives you the same output as [`render()`].
+///
+/// ```rust
+/// extern crate cfonts;
+///
+/// use cfonts::{ render_many, Options, Fonts };
+///
+/// let output = render_many(&[
+///     Options {
+///         text: String::from("hi"),
+///         font: Fonts::FontTiny,
+///         ..Options::default()
+///     },
+///     Options {
+///         text: String::from("there"),
+///         font: Fonts::FontConsole,
+///         ..Options::default()
+///     },
+/// ]);
+///
+/// assert_eq!(output.vec, vec![
+///     String::from("\n\n █ █ █"),
+///     String::from(" █▀█ █"),
+///     String::from("there\n\n"),
+/// ]);
+/// assert_eq!(output.lines, 2);
+/// ```
+pub fn render_many(blocks: &[Options]) -> RenderedString {
+	let first = blocks.first().cloned().unwrap_or_default();
+	d("render_many()", 1, Dt::Head, &first, &mut std::io::stdout());
+
+	if blocks.len() < 2 {
+		return render(first);
+	}
+
+	let mut output: Vec<String> = Vec::new();
+	let mut lines = 0;
+	for block in blocks {
+		// the space and background go around all blocks so we leave them out of each block
+		let rendered = render(Options {
+			align: first.align.clone(),
+			background: BgColors::Transparent,
+			spaceless: true,
+			env: first.env.clone(),
+			..block.clone()
+		});
+		output.extend(rendered.vec);
+		lines += rendered.lines;
+	}
+	d(&format!("render_many() rendered {:?} blocks", blocks.len()), 1, Dt::Log, &first, &mut std::io::stdout());
+
+	let text = frame_output(&mut output, &first);
+
+	RenderedString {
+		text,
+		vec: output,
+		lines,
+		options: first,
+	}
+}
+
+/// Render the output inside its frame and align the frame as a whole
+///
+/// The background is painted inside the frame so we leave it out when we add the space around the output.
+fn render_framed(options: Options, returned_options: Options) -> RenderedString {
+	d("render_framed()", 1, Dt::Head, &options, &mut std::io::stdout());
+
+	let inner = render(Options {
+		border: None,
+		padding: [0; 4],
+		align: Align::Left,
+		background: BgColors::Transparent,
+		spaceless: true,
+		..options.clone()
+	});
+	let (rows, _) = get_rows(&inner);
+	// the padding should be the only space between the text and the frame
+	let (rows, width) = trim_columns(&rows, &options);
+	let mut output = add_border(&rows, width, &options);
+
+	if options.env == Env::Cli {
+		let terminal_width = usize::from(get_terminal_width());
+		let frame_width = output.iter().map(|row| get_width(row, &options)).max().unwrap_or(0);
+		let rows = output.len();
+		align_last_line(&mut output, rows, frame_width, terminal_width, &options);
+		d("render_framed() aligned frame", 1, Dt::Log, &options, &mut std::io::stdout());
+	}
+
+	let text = frame_output(
+		&mut output,
+		&Options {
+			background: match options.env {
+				Env::Cli => BgColors::Transparent,
+				Env::Browser => options.background.clone(),
+			},
+			..options.clone()
+		},
+	);
+
+	RenderedString {
+		text,
+		vec: output,
+		lines: inner.lines,
+		options: returned_options,
+	}
+}
+
+/// Render the output with a shadow of its glyphs behind it, moved by the `shadow_offset` option
+fn render_shadowed(options: Options, returned_options: Options) -> RenderedString {
+	d("render_shadowed()", 1, Dt::Head, &options, &mut std::io::stdout());
+
+	// the shadow is composited in the terminal output which carries the exact colors for the browser too
+	let inner = render(Options {
+		shadow: false,
+		align: Align::Left,
+		background: BgColors::Transparent,
+		spaceless: true,
+		env: Env::Cli,
+		color_mode: match options.env {
+			Env::Cli => options.color_mode.clone(),
+			Env::Browser => ColorMode::Truecolor,
+		},
+		..options.clone()
+	});
+
+	let mut grid = Grid::from_ansi(&inner.text, &options);
+	let shadow_color = match options.shadow_color {
+		Colors::System => None,
+		_ => Some(hex2rgb(&color2hex(&options.shadow_color, &options), &options)),
+	};
+	let [x, y] = options.shadow_offset.map(usize::from);
+	grid.composite(&grid.silhouette(options.shadow_char, shadow_color), x, y, Blend::Behind);
+	d(&format!("render_shadowed() offset:{:?}", options.shadow_offset), 1, Dt::Log, &options, &mut std::io::stdout());
+
+	// colors have been simulated in the inner render and in color2hex already
+	let text = match options.env {
+		Env::Cli => grid.to_ansi(&Options {
+			simulate: None,
+			..options.clone()
+		}),
+		Env::Browser => grid.to_spans(&options),
+	};
+	let mut output = text.split('\n').map(String::from).collect::<Vec<String>>();
+
+	let terminal_width = match options.env {
+		Env::Cli => usize::from(get_terminal_width()),
+		Env::Browser => 0xFFFF,
+	};
+	let rows = output.len();
+	align_last_line(&mut output, rows, grid.width(), terminal_width, &options);
+
+	let text = frame_output(&mut output, &options);
+
+	RenderedString {
+		text,
+		vec: output,
+		lines: inner.lines,
+		options: returned_options,
+	}
+}
+
+/// Add the space, background and markup around the rendered lines and join them into the final text
+fn frame_output(output: &mut Vec<String>, options: &Options) -> String {
 	if !options.spaceless {
 		match options.align {
 			Align::Top => output.push(String::from("\n\n\n")),
@@ -188,15 +439,15 @@ pub fn render(options: Options) -> RenderedString {
 				output[last_index] = format!("{}\n\n", output[last_index]);
 			}
 		}
-		d("render() added space", 1, Dt::Log, &options, &mut std::io::stdout());
+		d("render() added space", 1, Dt::Log, options, &mut std::io::stdout());
 	}
 
 	if options.background != BgColors::Transparent && options.env == Env::Cli {
-		let (open, close) = get_background_color(&options.background);
+		let (open, close) = get_background_color_for(&options.background, options);
 		output[0] = format!("{}\n", open) + &output[0];
 		let last_index = output.len() - 1;
 		output[last_index] = format!("{}{}", output[last_index], close);
-		d("render() added background", 1, Dt::Log, &options, &mut std::io::stdout());
+		d("render() added background", 1, Dt::Log, options, &mut std::io::stdout());
 	}
 
 	let mut text = match options.env {
@@ -205,20 +456,15 @@ pub fn render(options: Options) -> RenderedString {
 	};
 
 	if options.env == Env::Browser {
-		let color = bgcolor2hex(&options.background, &options);
+		let color = bgcolor2hex(&options.background, options);
 		let align = match options.align {
 			Align::Right => "right",
 			Align::Center => "center",
 			Align::Left | Align::Top | Align::Bottom => "left",
 		};
 		text = format!("<div style=\"font-family:monospace;white-space:pre;text-align:{};max-width:100%;overflow:scroll;background:{}\">{}</div>", align, color, text);
-		d("render() formatted for Env::Browser", 1, Dt::Log, &options, &mut std::io::stdout());
+		d("render() formatted for Env::Browser", 1, Dt::Log, options, &mut std::io::stdout());
 	}
 
-	RenderedString {
-		text,
-		vec: output,
-		lines,
-		options,
-	}
+	text
 }
@@ -0,0 +1,253 @@
+//! The contents of this module is all about finding out what the terminal we render into looks like
+use std::io::{IsTerminal, Read, Write};
+use std::time::{Duration, Instant};
+use terminal_size::{terminal_size, Width};
+
+use crate::color::Rgb;
+use crate::config::{Env, Options, TerminalBg};
+use crate::debug::{d, Dt};
+
+/// How long we wait for the terminal to answer our background color query
+pub const OSC11_TIMEOUT: Duration = Duration::from_millis(100);
+
+/// The escape sequence asking the terminal for its background color
+pub const OSC11_QUERY: &str = "\x1b]11;?\x1b\\";
+
+/// The number of columns of the terminal we render into or 80 if we can't tell
+pub fn get_terminal_width() -> u16 {
+	match terminal_size() {
+		Some((Width(width), _)) => width,
+		None => 80,
+	}
+}
+
+/// Parse the answer of a terminal to an [`OSC11_QUERY`]
+///
+/// Terminals answer with `ESC ] 11 ; rgb:RRRR/GGGG/BBBB` followed by either `BEL` or `ESC \`.
+/// Each channel can have one to four hex digits.
+///
+/// ```rust
+/// extern crate cfonts;
+///
+/// use cfonts::color::Rgb;
+/// use cfonts::terminal::parse_osc11_response;
+///
+/// assert_eq!(parse_osc11_response("\x1b]11;rgb:ffff/8080/0000\x1b\\"), Some(Rgb::Val(255, 128, 0)));
+/// assert_eq!(parse_osc11_response("\x1b]11;rgb:f/8/0\x07"), Some(Rgb::Val(255, 136, 0)));
+/// assert_eq!(parse_osc11_response("nonsense"), None);
+/// ```
+pub fn parse_osc11_response(response: &str) -> Option<Rgb> {
+	let start = response.find("]11;")? + 4;
+	let body = response[start..].trim_start_matches("rgb:").trim_start_matches("rgba:");
+	let end = body.find(['\x07', '\x1b']).unwrap_or(body.len());
+
+	let channels = body[..end]
+		.split('/')
+		.take(3)
+		.map(|channel| {
+			if channel.is_empty() || channel.len() > 4 {
+				return None;
+			}
+			let value = u32::from_str_radix(channel, 16).ok()?;
+			let max = (1_u32 << (channel.len() * 4)) - 1;
+			Some(((value * 255 + max / 2) / max) as u8)
+		})
+		.collect::<Option<Vec<u8>>>()?;
+
+	match channels[..] {
+		[r, g, b] => Some(Rgb::Val(r, g, b)),
+		_ => None,
+	}
+}
+
+/// Parse the `COLORFGBG` environment variable some terminals set
+///
+/// The variable looks like `15;0` or `15;default;0` where the last number is the ansi color of the background.
+///
+/// ```rust
+/// extern crate cfonts;
+///
+/// use cfonts::TerminalBg;
+/// use cfonts::terminal::parse_colorfgbg;
+///
+/// assert_eq!(parse_colorfgbg("15;0"), Some(TerminalBg::Dark));
+/// assert_eq!(parse_colorfgbg("0;default;15"), Some(TerminalBg::Light));
+/// assert_eq!(parse_colorfgbg("default;default"), None);
+/// ```
+pub fn parse_colorfgbg(value: &str) -> Option<TerminalBg> {
+	match value.rsplit(';').next()?.trim().parse::<u8>().ok()? {
+		0..=6 | 8 => Some(TerminalBg::Dark),
+		7 | 9..=15 => Some(TerminalBg::Light),
+		_ => None,
+	}
+}
+
+/// Decide if a background color is light or dark by its relative luminance
+///
+/// ```rust
+/// extern crate cfonts;
+///
+/// use cfonts::TerminalBg;
+/// use cfonts::color::Rgb;
+/// use cfonts::terminal::rgb2terminal_bg;
+///
+/// assert_eq!(rgb2terminal_bg(&Rgb::Val(255, 255, 255)), TerminalBg::Light);
+/// assert_eq!(rgb2terminal_bg(&Rgb::Val(40, 44, 52)), TerminalBg::Dark);
+/// ```
+pub fn rgb2terminal_bg(rgb: &Rgb) -> TerminalBg {
+	let (r, g, b) = rgb.get_value();
+	let luminance = (0.2126 * r as f64 + 0.7152 * g as f64 + 0.0722 * b as f64) / 255.0;
+
+	if luminance > 0.5 {
+		TerminalBg::Light
+	} else {
+		TerminalBg::Dark
+	}
+}
+
+/// Send an [`OSC11_QUERY`] to a tty and wait for its answer until the timeout runs out
+///
+/// The tty is expected to be in a mode where reads return after a short while even without input.
+///
+/// ```rust
+/// extern crate cfonts;
+///
+/// use std::io::{Read, Result, Write};
+/// use std::time::Duration;
+///
+/// use cfonts::color::Rgb;
+/// use cfonts::terminal::query_osc11;
+///
+/// struct FakeTty(Vec<u8>, Vec<u8>);
+///
+/// impl Read for FakeTty {
+///     fn read(&mut self, buf: &mut [u8]) -> Result<usize> {
+///         let len = self.0.len().min(buf.len());
+///         buf[..len].copy_from_slice(&self.0[..len]);
+///         self.0.drain(..len);
+///         Ok(len)
+///     }
+/// }
+///
+/// impl Write for FakeTty {
+///     fn write(&mut self, buf: &[u8]) -> Result<usize> {
+///         self.1.extend_from_slice(buf);
+///         Ok(buf.len())
+///     }
+///     fn flush(&mut self) -> Result<()> {
+///         Ok(())
+///     }
+/// }
+///
+/// let mut tty = FakeTty(b"\x1b]11;rgb:0000/0000/0000\x07".to_vec(), vec![]);
+/// assert_eq!(query_osc11(&mut tty, Duration::from_millis(10)), Some(Rgb::Val(0, 0, 0)));
+/// ```
+pub fn query_osc11<T: Read + Write>(tty: &mut T, timeout: Duration) -> Option<Rgb> {
+	tty.write_all(OSC11_QUERY.as_bytes()).ok()?;
+	tty.flush().ok()?;
+
+	let deadline = Instant::now() + timeout;
+	let mut response = Vec::new();
+	let mut buffer = [0; 64];
+
+	while Instant::now() < deadline {
+		match tty.read(&mut buffer) {
+			Ok(0) => std::thread::sleep(Duration::from_millis(1)),
+			Ok(len) => {
+				response.extend_from_slice(&buffer[..len]);
+				if response.contains(&0x07) || response.windows(2).any(|pair| pair == b"\x1b\\") {
+					break;
+				}
+			}
+			Err(_) => return None,
+		}
+	}
+
+	parse_osc11_response(&String::from_utf8_lossy(&response))
+}
+
+/// Ask the controlling terminal for its background color
+///
+/// This puts the terminal into a non-canonical mode without echo for the duration of the query via `stty`.
+#[cfg(unix)]
+fn query_terminal() -> Option<Rgb> {
+	use std::fs::{File, OpenOptions};
+	use std::process::{Command, Stdio};
+
+	fn stty(tty: &File, args: &[&str]) -> Option<String> {
+		let output = Command::new("stty").args(args).stdin(Stdio::from(tty.try_clone().ok()?)).output().ok()?;
+		match output.status.success() {
+			true => Some(String::from_utf8_lossy(&output.stdout).trim().to_string()),
+			false => None,
+		}
+	}
+
+	let mut tty = OpenOptions::new().read(true).write(true).open("/dev/tty").ok()?;
+	let saved_mode = stty(&tty, &["-g"])?;
+	stty(&tty, &["-echo", "-icanon", "min", "0", "time", "1"])?;
+
+	let result = query_osc11(&mut tty, OSC11_TIMEOUT);
+
+	stty(&tty, &[&saved_mode]);
+	result
+}
+
+#[cfg(not(unix))]
+fn query_terminal() -> Option<Rgb> {
+	None
+}
+
+/// Return the terminal background a render should use
+///
+/// An explicit `terminal_bg` in [`Options`] always wins.
+/// Otherwise we ask the terminal via OSC 11 and fall back to `COLORFGBG`.
+/// A dark terminal is assumed if neither gives an answer so we only ever ask once per render.
+///
+/// ```rust
+/// extern crate cfonts;
+///
+/// use cfonts::{Options, TerminalBg};
+/// use cfonts::terminal::resolve_terminal_bg;
+///
+/// let mut options = Options::default();
+/// options.terminal_bg = TerminalBg::Light;
+/// assert_eq!(resolve_terminal_bg(&options), TerminalBg::Light);
+/// ```
+pub fn resolve_terminal_bg(options: &Options) -> TerminalBg {
+	resolve_terminal_bg_with(options, || {
+		// we only talk to the terminal when we're in an interactive session so pipes never block
+		if std::io::stdin().is_terminal() && std::io::stdout().is_terminal() {
+			query_terminal()
+		} else {
+			None
+		}
+	})
+}
+
+/// Same as [`resolve_terminal_bg()`] but with the function that asks the terminal for its background passed in
+///
+/// ```rust
+/// extern crate cfonts;
+///
+/// use cfonts::{Options, TerminalBg};
+/// use cfonts::color::Rgb;
+/// use cfonts::terminal::resolve_terminal_bg_with;
+///
+/// let options = Options::default();
+/// assert_eq!(resolve_terminal_bg_with(&options, || Some(Rgb::Val(250, 250, 250))), TerminalBg::Light);
+/// ```
+pub fn resolve_terminal_bg_with<F: FnOnce() -> Option<Rgb>>(options: &Options, query: F) -> TerminalBg {
+	d("terminal::resolve_terminal_bg_with()", 3, Dt::Head, options, &mut std::io::stdout());
+
+	if options.terminal_bg != TerminalBg::Auto || options.env == Env::Browser {
+		return options.terminal_bg.clone();
+	}
+
+	let result = query()
+		.map(|rgb| rgb2terminal_bg(&rgb))
+		.or_else(|| std::env::var("COLORFGBG").ok().and_then(|value| parse_colorfgbg(&value)))
+		.unwrap_or(TerminalBg::Dark);
+
+	d(&format!("terminal::resolve_terminal_bg_with() -> {:?}", result), 3, Dt::Log, options, &mut std::io::stdout());
+	result
+}
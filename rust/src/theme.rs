@@ -0,0 +1,390 @@
+//! The contents of this module is all about themes which bundle colors, gradients, backgrounds and style under a name
+use std::collections::HashMap;
+use std::fs;
+use std::path::Path;
+
+use serde::Deserialize;
+
+use crate::args::{parse_align, parse_bg_color, parse_colors, parse_font, parse_gradient, validate_gradient};
+use crate::color::color;
+use crate::config::{Colors, Fonts, Options, TerminalBg};
+use crate::debug::{d, Dt};
+use crate::gradient::GradientPresetRegistry;
+use crate::terminal::resolve_terminal_bg;
+
+/// A theme holds a set of options that can be applied to an [`Options`] struct in one go
+///
+/// Values are kept as strings, the same way they are written on the command line, so a theme can be read from a TOML
+/// or JSON file:
+///
+/// ```toml
+/// colors = ["#1e90ff", "#ff6f61"]
+/// background = "black"
+/// font = "block"
+/// ```
+///
+/// Everything that isn't set in a theme is left untouched when the theme is applied.
+///
+/// A theme can pick readable colors for light and dark terminals with a `[light]` and `[dark]` table:
+///
+/// ```toml
+/// gradient = ["#1e90ff", "#ff6f61"]
+///
+/// [light]
+/// colors = ["black"]
+///
+/// [dark]
+/// colors = ["white"]
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Theme {
+	/// The font, see [`Fonts`]
+	pub font: Option<String>,
+	/// The alignment, see [`crate::Align`]
+	pub align: Option<String>,
+	/// The colors, each item can be a color name or a hex color
+	pub colors: Vec<String>,
+	/// The background color, can be a color name or a hex color
+	pub background: Option<String>,
+	/// The letter spacing
+	pub letter_spacing: Option<u16>,
+	/// The line height
+	pub line_height: Option<u16>,
+	/// Whether to remove the space around the output
+	pub spaceless: Option<bool>,
+	/// The gradient colors, each item can be a color name or a hex color
+	pub gradient: Vec<String>,
+	/// Whether the gradient is applied to each line independently
+	pub independent_gradient: Option<bool>,
+	/// Whether the gradient transitions between all colors
+	pub transition_gradient: Option<bool>,
+	/// Values that are applied on top of this theme on light terminal backgrounds
+	pub light: Option<Box<Theme>>,
+	/// Values that are applied on top of this theme on dark terminal backgrounds or when the background is unknown
+	pub dark: Option<Box<Theme>>,
+}
+
+impl Theme {
+	/// Create a theme that holds only a transition gradient
+	///
+	/// ```rust
+	/// extern crate cfonts;
+	///
+	/// use cfonts::Theme;
+	///
+	/// let theme = Theme::from_gradient(&["#ff0000", "#00ff00", "#0000ff"]);
+	/// assert_eq!(theme.gradient.len(), 3);
+	/// assert_eq!(theme.transition_gradient, Some(true));
+	/// ```
+	pub fn from_gradient(gradient: &[&str]) -> Self {
+		Theme {
+			gradient: gradient.iter().map(|item| item.to_string()).collect(),
+			transition_gradient: Some(true),
+			..Theme::default()
+		}
+	}
+
+	/// Read a theme from a TOML string
+	///
+	/// ```rust
+	/// extern crate cfonts;
+	///
+	/// use cfonts::Theme;
+	///
+	/// let theme = Theme::from_toml("colors = [\"red\", \"#ff8800\"]\nfont = \"tiny\"").unwrap();
+	/// assert_eq!(theme.colors, vec![String::from("red"), String::from("#ff8800")]);
+	/// assert_eq!(theme.font, Some(String::from("tiny")));
+	/// ```
+	pub fn from_toml(content: &str) -> Result<Self, String> {
+		toml::from_str::<Theme>(content).map_err(|error| format!("Could not read theme: {}", error.message()))
+	}
+
+	/// Read a theme from a JSON string
+	///
+	/// ```rust
+	/// extern crate cfonts;
+	///
+	/// use cfonts::Theme;
+	///
+	/// let theme = Theme::from_json("{ \"background\": \"blue\", \"spaceless\": true }").unwrap();
+	/// assert_eq!(theme.background, Some(String::from("blue")));
+	/// assert_eq!(theme.spaceless, Some(true));
+	/// ```
+	pub fn from_json(content: &str) -> Result<Self, String> {
+		serde_json::from_str::<Theme>(content).map_err(|error| format!("Could not read theme: {}", error))
+	}
+
+	/// Apply this theme onto an [`Options`] struct
+	///
+	/// ```rust
+	/// extern crate cfonts;
+	///
+	/// use cfonts::{BgColors, Colors, Fonts, Options, Rgb, Theme};
+	///
+	/// let theme = Theme {
+	///     font: Some(String::from("chrome")),
+	///     colors: vec![String::from("#1e90ff"), String::from("red")],
+	///     background: Some(String::from("black")),
+	///     ..Theme::default()
+	/// };
+	///
+	/// let mut options = Options::default();
+	/// theme.apply(&mut options).unwrap();
+	///
+	/// assert_eq!(options.font, Fonts::FontChrome);
+	/// assert_eq!(options.colors, vec![Colors::Rgb(Rgb::Val(30, 144, 255)), Colors::Red]);
+	/// assert_eq!(options.background, BgColors::Black);
+	/// ```
+	pub fn apply(&self, options: &mut Options) -> Result<(), String> {
+		self.apply_with_transition(options, false)
+	}
+
+	/// Apply the theme like [`Theme::apply()`] knowing whether the transition flag was given on the command line
+	///
+	/// The transition flag is applied after the theme so its gradient has to be validated with it in mind.
+	pub(crate) fn apply_with_transition(&self, options: &mut Options, transition_given: bool) -> Result<(), String> {
+		d("theme::apply()", 3, Dt::Head, options, &mut std::io::stdout());
+		d(&format!("theme::apply()\nself:{:?}", self), 3, Dt::Log, options, &mut std::io::stdout());
+
+		if let Some(font) = &self.font {
+			options.font = parse_font(font, options)?;
+
+			if options.font == Fonts::FontConsole && self.line_height.is_none() {
+				options.line_height = 0;
+			}
+		}
+
+		if let Some(align) = &self.align {
+			options.align = parse_align(align, options)?;
+		}
+
+		if !self.colors.is_empty() {
+			options.colors = parse_colors(&self.colors.join(","), options)?;
+		}
+
+		if let Some(background) = &self.background {
+			options.background = parse_bg_color(background, options)?;
+		}
+
+		if let Some(letter_spacing) = self.letter_spacing {
+			options.letter_spacing = letter_spacing;
+		}
+
+		if let Some(line_height) = self.line_height {
+			options.line_height = line_height;
+		}
+
+		if let Some(spaceless) = self.spaceless {
+			options.spaceless = spaceless;
+		}
+
+		if let Some(independent_gradient) = self.independent_gradient {
+			options.independent_gradient = independent_gradient;
+		}
+
+		if let Some(transition_gradient) = self.transition_gradient {
+			options.transition_gradient = transition_gradient;
+		}
+
+		if !self.gradient.is_empty() {
+			let gradient = parse_gradient(&self.gradient.join(","), options)?;
+			validate_gradient(&gradient, transition_given || options.transition_gradient)?;
+			options.gradient = gradient;
+		}
+
+		if self.light.is_some() || self.dark.is_some() {
+			// we keep what we found so the terminal is only asked once
+			options.terminal_bg = resolve_terminal_bg(options);
+			let variant = match options.terminal_bg {
+				TerminalBg::Light => &self.light,
+				TerminalBg::Dark | TerminalBg::Auto => &self.dark,
+			};
+
+			if let Some(variant) = variant {
+				variant.apply_with_transition(options, transition_given)?;
+			}
+		}
+
+		d(&format!("theme::apply()\noptions:{:?}", options), 3, Dt::Log, options, &mut std::io::stdout());
+
+		Ok(())
+	}
+}
+
+/// A registry of named [`Theme`]s
+///
+/// The default registry comes with a built-in theme for each of the built-in [`crate::gradient::GradientPreset`]s.
+/// Use [`ThemeRegistry::new()`] for an empty one.
+///
+/// ```rust
+/// extern crate cfonts;
+///
+/// use cfonts::{Options, Theme, ThemeRegistry};
+///
+/// let mut registry = ThemeRegistry::default();
+/// registry.register("brand", Theme::from_toml("colors = [\"#1e90ff\", \"#ff6f61\"]").unwrap());
+///
+/// let mut options = Options::default();
+/// registry.get("brand").unwrap().apply(&mut options).unwrap();
+/// assert_eq!(options.colors.len(), 2);
+///
+/// assert!(registry.get("pride").is_some());
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ThemeRegistry {
+	themes: HashMap<String, Theme>,
+	aliases: HashMap<String, String>,
+}
+
+impl Default for ThemeRegistry {
+	fn default() -> Self {
+		let mut registry = ThemeRegistry::new();
+
+		let presets = GradientPresetRegistry::default();
+
+		for name in presets.list() {
+			let preset = presets.get(&name).unwrap();
+			registry.register(
+				&name,
+				Theme {
+					gradient: preset.colors.clone(),
+					transition_gradient: Some(preset.transition),
+					..Theme::default()
+				},
+			);
+
+			for alias in presets.aliases_of(&name) {
+				registry.alias(&alias, &name);
+			}
+		}
+
+		registry
+	}
+}
+
+impl ThemeRegistry {
+	/// Create an empty registry without the built-in themes
+	pub fn new() -> Self {
+		ThemeRegistry {
+			themes: HashMap::new(),
+			aliases: HashMap::new(),
+		}
+	}
+
+	/// Register a theme under a name, replacing any theme with the same name
+	pub fn register(&mut self, name: &str, theme: Theme) {
+		let name = name.to_lowercase();
+		self.aliases.remove(&name);
+		self.themes.insert(name, theme);
+	}
+
+	/// Register another name for an existing theme
+	pub fn alias(&mut self, alias: &str, name: &str) {
+		self.aliases.insert(alias.to_lowercase(), name.to_lowercase());
+	}
+
+	/// Get a theme by its name or alias, names are case insensitive
+	pub fn get(&self, name: &str) -> Option<&Theme> {
+		let name = name.to_lowercase();
+		match self.themes.get(&name) {
+			Some(theme) => Some(theme),
+			None => self.aliases.get(&name).and_then(|name| self.themes.get(name)),
+		}
+	}
+
+	/// List the names of all registered themes in alphabetical order
+	///
+	/// ```rust
+	/// extern crate cfonts;
+	///
+	/// use cfonts::{Theme, ThemeRegistry};
+	///
+	/// let mut registry = ThemeRegistry::new();
+	/// registry.register("b", Theme::default());
+	/// registry.register("a", Theme::default());
+	/// assert_eq!(registry.list(), vec![String::from("a"), String::from("b")]);
+	/// ```
+	pub fn list(&self) -> Vec<String> {
+		let mut names = self.themes.keys().cloned().collect::<Vec<String>>();
+		names.sort();
+		names
+	}
+
+	/// Load a `.toml` or `.json` theme file and register it under its file name
+	///
+	/// Returns the name the theme was registered under.
+	pub fn load_file(&mut self, path: &Path, options: &Options) -> Result<String, String> {
+		d("theme::ThemeRegistry::load_file()", 3, Dt::Head, options, &mut std::io::stdout());
+		d(&format!("theme::ThemeRegistry::load_file()\npath:{:?}", path), 3, Dt::Log, options, &mut std::io::stdout());
+
+		let name = match path.file_stem() {
+			Some(stem) => stem.to_string_lossy().to_lowercase(),
+			None => {
+				return Err(format!("The theme file \"{}\" has no name", color(&path.display().to_string(), Colors::Green)))
+			}
+		};
+
+		let content = fs::read_to_string(path).map_err(|error| {
+			format!("Could not read theme file \"{}\": {}", color(&path.display().to_string(), Colors::Green), error)
+		})?;
+
+		let theme = match path.extension().and_then(|extension| extension.to_str()) {
+			Some("toml") => Theme::from_toml(&content),
+			Some("json") => Theme::from_json(&content),
+			_ => {
+				return Err(format!(
+					"The theme file \"{}\" is not supported.\nAllowed options are: {}",
+					color(&path.display().to_string(), Colors::Green),
+					color(".toml, .json", Colors::Green)
+				))
+			}
+		}
+		.map_err(|error| format!("{} in \"{}\"", error, color(&path.display().to_string(), Colors::Green)))?;
+
+		self.register(&name, theme);
+
+		Ok(name)
+	}
+
+	/// Load all `.toml` and `.json` theme files inside a folder
+	///
+	/// A folder that doesn't exist is not an error as it just means there are no user themes.
+	/// Files that can't be loaded are skipped so they don't get in the way of all other themes.
+	/// Returns the error of each skipped file by the name its theme would have had.
+	pub fn load_dir(&mut self, dir: &Path, options: &Options) -> HashMap<String, String> {
+		d("theme::ThemeRegistry::load_dir()", 3, Dt::Head, options, &mut std::io::stdout());
+		d(&format!("theme::ThemeRegistry::load_dir()\ndir:{:?}", dir), 3, Dt::Log, options, &mut std::io::stdout());
+
+		let mut broken = HashMap::new();
+		let entries = match fs::read_dir(dir) {
+			Ok(entries) => entries,
+			Err(_) => return broken,
+		};
+
+		let mut paths = entries
+			.filter_map(|entry| entry.ok().map(|entry| entry.path()))
+			.filter(|path| {
+				path.is_file()
+					&& matches!(path.extension().and_then(|extension| extension.to_str()), Some("toml") | Some("json"))
+			})
+			.collect::<Vec<_>>();
+		paths.sort();
+
+		for path in paths {
+			if let Err(error) = self.load_file(&path, options) {
+				d(
+					&format!("theme::ThemeRegistry::load_dir() skipped {:?}\n{}", path, error),
+					3,
+					Dt::Log,
+					options,
+					&mut std::io::stdout(),
+				);
+				let name = path.file_stem().map(|stem| stem.to_string_lossy().to_lowercase()).unwrap_or_default();
+				broken.insert(name, error);
+			}
+		}
+
+		broken
+	}
+}
@@ -0,0 +1,297 @@
+extern crate cfonts;
+
+use std::sync::atomic::AtomicBool;
+use std::time::Duration;
+
+use cfonts::animate::{
+	clear_frame, frames, get_frame_count, mask_line, play, Frame, CYCLE_GRADIENT, CYCLE_STEPS_PER_COLOR, HIDE_CURSOR,
+	SHOW_CURSOR,
+};
+use cfonts::config::{ColorMode, Colors, Effect, Fonts, Options};
+
+#[cfg(test)]
+mod animate {
+	use super::*;
+
+	fn texts(frames: &[Frame]) -> Vec<&str> {
+		frames.iter().map(|frame| frame.text.as_str()).collect()
+	}
+
+	#[test]
+	fn mask_line_works() {
+		assert_eq!(mask_line("abc", |_| true), "abc");
+		assert_eq!(mask_line("abc", |_| false), "   ");
+		assert_eq!(mask_line("abc", |column| column < 2), "ab ");
+		assert_eq!(
+			mask_line("\x1b[31m█\x1b[39m\x1b[38;5;1m█\x1b[39m", |column| column == 1),
+			"\x1b[31m \x1b[39m\x1b[38;5;1m█\x1b[39m"
+		);
+		assert_eq!(mask_line("", |_| false), "");
+	}
+
+	#[test]
+	fn frame_height_works() {
+		let frame = Frame {
+			text: String::from("a\nb\nc"),
+			delay: Duration::ZERO,
+		};
+		assert_eq!(frame.height(), 3);
+		assert_eq!(
+			Frame {
+				text: String::new(),
+				delay: Duration::ZERO
+			}
+			.height(),
+			1
+		);
+	}
+
+	#[test]
+	fn frames_typewriter_works() {
+		let options = Options {
+			text: String::from("abc"),
+			font: Fonts::FontConsole,
+			spaceless: true,
+			..Options::default()
+		};
+
+		let result = frames(options, Effect::Typewriter).collect::<Vec<Frame>>();
+		assert_eq!(texts(&result), vec!["", "a", "ab", "abc"]);
+		assert_eq!(result[0].delay, Duration::from_millis(33));
+		assert_eq!(result[3].delay, Duration::ZERO);
+	}
+
+	#[test]
+	fn frames_lines_works() {
+		let options = Options {
+			text: String::from("a|b"),
+			font: Fonts::FontConsole,
+			spaceless: true,
+			..Options::default()
+		};
+
+		let result = frames(options, Effect::Lines).collect::<Vec<Frame>>();
+		assert_eq!(texts(&result), vec![" \n\n ", "a\n\n ", "a\n\nb"]);
+	}
+
+	#[test]
+	fn frames_wipe_works() {
+		let options = Options {
+			text: String::from("ab|c"),
+			font: Fonts::FontConsole,
+			spaceless: true,
+			..Options::default()
+		};
+
+		let result = frames(options, Effect::Wipe).collect::<Vec<Frame>>();
+		assert_eq!(texts(&result), vec!["  \n\n ", "a \n\nc", "ab\n\nc"]);
+	}
+
+	#[test]
+	fn frames_keep_colors() {
+		let options = Options {
+			text: String::from("ab"),
+			font: Fonts::FontConsole,
+			colors: vec![Colors::Red],
+			spaceless: true,
+			..Options::default()
+		};
+
+		let result = frames(options, Effect::Wipe).collect::<Vec<Frame>>();
+		assert_eq!(
+			texts(&result),
+			vec![
+				"\x1b[31m \x1b[39m\x1b[31m \x1b[39m",
+				"\x1b[31ma\x1b[39m\x1b[31m \x1b[39m",
+				"\x1b[31ma\x1b[39m\x1b[31mb\x1b[39m"
+			]
+		);
+	}
+
+	#[test]
+	fn frames_duration_works() {
+		let options = Options {
+			text: String::from("abc"),
+			font: Fonts::FontConsole,
+			spaceless: true,
+			fps: 10,
+			duration: Some(1_000),
+			..Options::default()
+		};
+
+		// 4 steps spread over 10 frames
+		let result = frames(options.clone(), Effect::Typewriter).collect::<Vec<Frame>>();
+		assert_eq!(result.len(), 10);
+		assert_eq!(result[0].text, "");
+		assert_eq!(result[9].text, "abc");
+		assert!(result[..9].iter().all(|frame| frame.delay == Duration::from_millis(100)));
+		assert_eq!(result[9].delay, Duration::ZERO);
+
+		// a duration shorter than a single frame still shows the result
+		let result = frames(
+			Options {
+				duration: Some(10),
+				..options.clone()
+			},
+			Effect::Typewriter,
+		)
+		.collect::<Vec<Frame>>();
+		assert_eq!(texts(&result), vec!["abc"]);
+
+		// fewer frames than steps skip some steps
+		let result = frames(
+			Options {
+				duration: Some(200),
+				..options
+			},
+			Effect::Typewriter,
+		)
+		.collect::<Vec<Frame>>();
+		assert_eq!(texts(&result), vec!["", "abc"]);
+	}
+
+	#[test]
+	fn frames_work_with_huge_durations() {
+		let options = Options {
+			text: String::from("abc"),
+			font: Fonts::FontConsole,
+			spaceless: true,
+			duration: Some(u64::MAX),
+			..Options::default()
+		};
+
+		let first = frames(
+			Options {
+				duration: None,
+				..options.clone()
+			},
+			Effect::Wipe,
+		)
+		.next()
+		.unwrap();
+		let result = frames(options, Effect::Wipe).take(2).collect::<Vec<Frame>>();
+		assert_eq!(result.len(), 2);
+		assert_eq!(result[0], first);
+		assert_eq!(get_frame_count(u64::MAX, u64::MAX), (u64::MAX / 1000) as usize);
+	}
+
+	#[test]
+	fn frames_cycle_works() {
+		let options = Options {
+			text: String::from("abc"),
+			font: Fonts::FontConsole,
+			gradient: vec![String::from("#ff0000"), String::from("#0000ff")],
+			color_mode: ColorMode::Truecolor,
+			spaceless: true,
+			..Options::default()
+		};
+
+		// the gradient is closed into a loop with at least a few steps per color and each frame shifts it by one step
+		let result = frames(options.clone(), Effect::Cycle).collect::<Vec<Frame>>();
+		assert_eq!(result.len(), 2 * CYCLE_STEPS_PER_COLOR);
+		assert!(result[0].text.starts_with("\x1b[38;2;255;0;0ma"));
+		assert!(result.windows(2).all(|pair| pair[0].text != pair[1].text));
+		assert_ne!(result.last().unwrap().text, result[0].text);
+		assert_eq!(result.last().unwrap().delay, Duration::ZERO);
+
+		// with a duration we keep looping
+		let looped = frames(
+			Options {
+				fps: 10,
+				duration: Some(1_000),
+				..options.clone()
+			},
+			Effect::Cycle,
+		)
+		.collect::<Vec<Frame>>();
+		assert_eq!(looped.len(), 10);
+		assert_eq!(looped[0].text, result[0].text);
+		assert_eq!(looped[8].text, result[0].text);
+
+		// a single character still cycles through all colors
+		let result = frames(
+			Options {
+				text: String::from("a"),
+				..options.clone()
+			},
+			Effect::Cycle,
+		)
+		.collect::<Vec<Frame>>();
+		assert_eq!(result.len(), 2 * CYCLE_STEPS_PER_COLOR);
+		assert!(result[CYCLE_STEPS_PER_COLOR].text.starts_with("\x1b[38;2;0;0;255ma"));
+
+		// without a gradient we cycle through the rainbow
+		let result = frames(
+			Options {
+				gradient: Vec::new(),
+				..options.clone()
+			},
+			Effect::Cycle,
+		)
+		.collect::<Vec<Frame>>();
+		assert_eq!(result.len(), CYCLE_GRADIENT.len() * CYCLE_STEPS_PER_COLOR);
+		assert!(result[0].text.starts_with("\x1b[38;2;255;0;0ma"));
+
+		// without colors there is nothing to cycle
+		let result = frames(
+			Options {
+				color_mode: ColorMode::NoColor,
+				..options
+			},
+			Effect::Cycle,
+		)
+		.collect::<Vec<Frame>>();
+		assert_eq!(texts(&result), vec!["abc"]);
+	}
+
+	#[test]
+	fn clear_frame_works() {
+		assert_eq!(clear_frame(0), "");
+		assert_eq!(clear_frame(1), "\r\x1b[J");
+		assert_eq!(clear_frame(2), "\r\x1b[1A\x1b[J");
+		assert_eq!(clear_frame(10), "\r\x1b[9A\x1b[J");
+	}
+
+	#[test]
+	fn play_works() {
+		let frames = vec![
+			Frame {
+				text: String::from("a"),
+				delay: Duration::from_millis(1),
+			},
+			Frame {
+				text: String::from("b\nc\nd"),
+				delay: Duration::from_millis(1),
+			},
+			Frame {
+				text: String::from("e"),
+				delay: Duration::ZERO,
+			},
+		];
+		let mut output = Vec::new();
+
+		assert!(!play(frames.into_iter(), &mut output, &AtomicBool::new(false)).unwrap());
+		assert_eq!(
+			String::from_utf8(output).unwrap(),
+			format!("{}a\r\x1b[Jb\nc\nd\r\x1b[2A\x1b[Je{}\n", HIDE_CURSOR, SHOW_CURSOR)
+		);
+	}
+
+	#[test]
+	fn play_stops_and_restores_cursor() {
+		let frames = vec![
+			Frame {
+				text: String::from("a"),
+				delay: Duration::from_secs(60),
+			},
+			Frame {
+				text: String::from("b"),
+				delay: Duration::ZERO,
+			},
+		];
+		let mut output = Vec::new();
+
+		assert!(play(frames.into_iter(), &mut output, &AtomicBool::new(true)).unwrap());
+		assert_eq!(String::from_utf8(output).unwrap(), format!("{}a{}\n", HIDE_CURSOR, SHOW_CURSOR));
+	}
+}
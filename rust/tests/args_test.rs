@@ -1,11 +1,14 @@
+#![allow(clippy::field_reassign_with_default)]
+
 extern crate cfonts;
 
-use cfonts::args::parse;
+use cfonts::args::{apply_option, normalize_args, parse, parse_many, parse_with};
 use cfonts::color::Rgb;
 use cfonts::config::{
-	Align, BgColors, Colors, Env, Fonts, Options, GRADIENTS_AGENDER, GRADIENTS_AROMANTIC, GRADIENTS_ASEXUAL,
-	GRADIENTS_BISEXUAL, GRADIENTS_GENDERFLUID, GRADIENTS_GENDERQUEER, GRADIENTS_INTERSEX, GRADIENTS_LESBIAN,
-	GRADIENTS_NONBINARY, GRADIENTS_PANSEXUAL, GRADIENTS_POLYSEXUAL, GRADIENTS_PRIDE, GRADIENTS_TRANSGENDER,
+	Align, BgColors, Border, CandyMode, ColorMode, Colors, Cvd, Effect, Env, Fonts, Options, OutputFormat, Shell,
+	TerminalBg, CLIOPTIONS, GRADIENTS_AGENDER, GRADIENTS_AROMANTIC, GRADIENTS_ASEXUAL, GRADIENTS_BISEXUAL,
+	GRADIENTS_GENDERFLUID, GRADIENTS_GENDERQUEER, GRADIENTS_INTERSEX, GRADIENTS_LESBIAN, GRADIENTS_NONBINARY,
+	GRADIENTS_PANSEXUAL, GRADIENTS_POLYSEXUAL, GRADIENTS_PRIDE, GRADIENTS_TRANSGENDER,
 };
 
 #[cfg(test)]
@@ -99,11 +102,8 @@ mod args {
 	fn args_parse_version_flags() {
 		let mut options = Options::default();
 		options.version = true;
-		options.text = String::from("-v");
 		assert_eq!(parse(vec!["path/to/bin".to_string(), "-v".to_string()]).unwrap(), options);
-		options.text = String::from("-V");
 		assert_eq!(parse(vec!["path/to/bin".to_string(), "-V".to_string()]).unwrap(), options);
-		options.text = String::from("--version");
 		assert_eq!(parse(vec!["path/to/bin".to_string(), "--version".to_string()]).unwrap(), options);
 	}
 
@@ -111,9 +111,7 @@ mod args {
 	fn args_parse_help_flags() {
 		let mut options = Options::default();
 		options.help = true;
-		options.text = String::from("-h");
 		assert_eq!(parse(vec!["path/to/bin".to_string(), "-h".to_string()]).unwrap(), options);
-		options.text = String::from("--help");
 		assert_eq!(parse(vec!["path/to/bin".to_string(), "--help".to_string()]).unwrap(), options);
 	}
 
@@ -652,6 +650,129 @@ mod args {
 		);
 	}
 
+	#[test]
+	fn args_parse_color_mode() {
+		let mut options = Options::default();
+		options.text = String::from("my text");
+
+		for (value, mode) in [
+			("auto", ColorMode::Auto),
+			("none", ColorMode::NoColor),
+			("16", ColorMode::Ansi16),
+			("256", ColorMode::Ansi256),
+			("truecolor", ColorMode::Truecolor),
+			("TrueColor", ColorMode::Truecolor),
+		] {
+			options.color_mode = mode;
+			assert_eq!(
+				parse(vec![
+					"path/to/bin".to_string(),
+					"my text".to_string(),
+					"--color-mode".to_string(),
+					value.to_string()
+				])
+				.unwrap(),
+				options
+			);
+		}
+
+		// missing value
+		assert!(
+			parse(vec![
+				"path/to/bin".to_string(),
+				"my text".to_string(),
+				"--color-mode".to_string()
+			])
+			.is_err(),
+			"We should error when no value has been passed to the flag"
+		);
+
+		// unknown value
+		assert!(
+			parse(vec![
+				"path/to/bin".to_string(),
+				"my text".to_string(),
+				"--color-mode".to_string(),
+				"8".to_string(),
+			])
+			.is_err(),
+			"We should error when an unknown value has been passed to the flag"
+		);
+	}
+
+	#[test]
+	fn args_parse_seed() {
+		let mut options = Options::default();
+		options.text = String::from("my text");
+		options.seed = Some(18446744073709551615);
+
+		assert_eq!(
+			parse(vec![
+				"path/to/bin".to_string(),
+				"my text".to_string(),
+				"--seed".to_string(),
+				"18446744073709551615".to_string()
+			])
+			.unwrap(),
+			options
+		);
+
+		// missing value
+		assert!(
+			parse(vec!["path/to/bin".to_string(), "my text".to_string(), "--seed".to_string()]).is_err(),
+			"We should error when no value has been passed to the flag"
+		);
+
+		// not a number
+		assert!(
+			parse(vec![
+				"path/to/bin".to_string(),
+				"my text".to_string(),
+				"--seed".to_string(),
+				"-1".to_string(),
+			])
+			.is_err(),
+			"We should error when the seed is not a positive number"
+		);
+	}
+
+	#[test]
+	fn args_parse_candy_mode() {
+		let mut options = Options::default();
+		options.text = String::from("my text");
+
+		for (value, mode) in [
+			("segment", CandyMode::Segment),
+			("glyph", CandyMode::Glyph),
+			("ROW", CandyMode::Row),
+			("layer", CandyMode::Layer),
+		] {
+			options.candy_mode = mode;
+			assert_eq!(
+				parse(vec![
+					"path/to/bin".to_string(),
+					"my text".to_string(),
+					"--candy-mode".to_string(),
+					value.to_string()
+				])
+				.unwrap(),
+				options
+			);
+		}
+
+		// unknown value
+		assert!(
+			parse(vec![
+				"path/to/bin".to_string(),
+				"my text".to_string(),
+				"--candy-mode".to_string(),
+				"letter".to_string(),
+			])
+			.is_err(),
+			"We should error when an unknown value has been passed to the flag"
+		);
+	}
+
 	#[test]
 	fn args_parse_bgcolors() {
 		let mut options = Options::default();
@@ -1796,19 +1917,113 @@ mod args {
 
 	#[test]
 	fn args_parse_ignored_unknown_arguments() {
-		let options = Options::default();
+		let mut options = Options::default();
+		options.lenient = true;
 		assert_eq!(
 			parse(vec![
 				"path/to/bin".to_string(),
 				"".to_string(),
 				"-u".to_string(),
-				"--unknown".to_string()
+				"--unknown".to_string(),
+				"-su".to_string(),
+				"--lenient".to_string()
 			])
 			.unwrap(),
+			Options {
+				spaceless: true,
+				..options.clone()
+			}
+		);
+
+		// the lenient option can also come from the defaults
+		assert_eq!(
+			parse_with(vec!["path/to/bin".to_string(), "".to_string(), "--unknown".to_string()], options.clone()).unwrap(),
+			options
+		);
+	}
+
+	#[test]
+	fn args_parse_lenient_unknown_flag_first() {
+		let mut options = Options::default();
+		options.text = String::from("hi");
+		options.lenient = true;
+		options.font = Fonts::FontConsole;
+		options.line_height = 0;
+
+		assert_eq!(
+			parse(args(vec!["path/to/bin", "--lenient", "--colour", "red", "hi", "-f", "console"])).unwrap(),
+			options
+		);
+		assert_eq!(parse(args(vec!["path/to/bin", "--lenient", "-u", "red", "hi", "-f", "console"])).unwrap(), options);
+		// an unknown flag doesn't take the text away when nothing else could be the text
+		assert_eq!(parse(args(vec!["path/to/bin", "--lenient", "--colour", "hi", "-f", "console"])).unwrap(), options);
+		// a first argument that isn't a flag is always the text
+		assert_eq!(
+			parse(args(vec!["path/to/bin", "hi", "--lenient", "--colour", "red", "-f", "console"])).unwrap(),
 			options
 		);
 	}
 
+	#[test]
+	fn args_parse_rejects_unknown_arguments() {
+		let error = parse(vec![
+			"path/to/bin".to_string(),
+			"".to_string(),
+			"--colour".to_string(),
+			"red".to_string(),
+		])
+		.unwrap_err();
+		assert!(error.contains("--colour"));
+		assert!(error.contains("Did you mean"));
+		assert!(error.contains("--colors"));
+
+		let error = parse(vec!["path/to/bin".to_string(), "".to_string(), "-u".to_string()]).unwrap_err();
+		assert!(error.contains("-u"));
+		assert!(error.contains("Did you mean"));
+
+		let error = parse(vec!["path/to/bin".to_string(), "".to_string(), "--unheard-of".to_string()]).unwrap_err();
+		assert!(error.contains("--unheard-of"));
+		assert!(!error.contains("Did you mean"));
+
+		// unknown flags inside stacked flags
+		let error = parse(vec!["path/to/bin".to_string(), "".to_string(), "-sqi".to_string()]).unwrap_err();
+		assert!(error.contains("-q"));
+
+		// values that aren't flags are still ignored
+		assert!(parse(vec!["path/to/bin".to_string(), "".to_string(), "stray".to_string()]).is_ok());
+	}
+
+	#[test]
+	fn args_parse_suggests_values() {
+		for (flag, value, suggestion) in [
+			("-f", "chorme", "chrome"),
+			("-f", "TINNY", "tiny"),
+			("-c", "red,bleu", "blue"),
+			("-b", "magneta", "magenta"),
+			("-a", "centre", "center"),
+			("-c", "rd", "red"),
+		] {
+			let error = parse(vec![
+				"path/to/bin".to_string(),
+				"".to_string(),
+				flag.to_string(),
+				value.to_string(),
+			])
+			.unwrap_err();
+			assert!(error.contains("Did you mean"), "{} {} should suggest something", flag, value);
+			assert!(error.contains(suggestion), "{} {} should suggest {}", flag, value, suggestion);
+		}
+
+		let error = parse(vec![
+			"path/to/bin".to_string(),
+			"".to_string(),
+			"-f".to_string(),
+			"xyz".to_string(),
+		])
+		.unwrap_err();
+		assert!(!error.contains("Did you mean"));
+	}
+
 	#[test]
 	fn args_parse_all_together() {
 		let mut options = Options::default();
@@ -1925,4 +2140,787 @@ mod args {
 			options
 		);
 	}
+
+	#[test]
+	fn args_parse_list_gradients() {
+		assert!(parse(vec!["path/to/bin".to_string(), "--list-gradients".to_string()]).unwrap().list_gradients);
+		assert!(
+			parse(vec![
+				"path/to/bin".to_string(),
+				"my text".to_string(),
+				"--list-gradients".to_string()
+			])
+			.unwrap()
+			.list_gradients
+		);
+		assert!(!parse(vec!["path/to/bin".to_string(), "my text".to_string()]).unwrap().list_gradients);
+	}
+
+	#[test]
+	fn args_parse_completions() {
+		let options = parse(vec![
+			"path/to/bin".to_string(),
+			"--completions".to_string(),
+			"zsh".to_string(),
+		])
+		.unwrap();
+		assert_eq!(options.completions, Some(Shell::Zsh));
+		assert_eq!(options.text, String::from(""));
+
+		let options = parse(vec![
+			"path/to/bin".to_string(),
+			"my text".to_string(),
+			"--completions".to_string(),
+			"fish".to_string(),
+		])
+		.unwrap();
+		assert_eq!(options.completions, Some(Shell::Fish));
+
+		assert!(parse(vec![
+			"path/to/bin".to_string(),
+			"--completions".to_string(),
+			"cmd".to_string()
+		])
+		.is_err());
+		assert!(parse(vec!["path/to/bin".to_string(), "--completions".to_string()]).is_err());
+	}
+
+	#[test]
+	fn args_parse_man() {
+		assert!(parse(vec!["path/to/bin".to_string(), "--man".to_string()]).unwrap().man);
+		assert!(parse(vec!["path/to/bin".to_string(), "my text".to_string(), "--man".to_string()]).unwrap().man);
+		assert!(!parse(vec!["path/to/bin".to_string(), "my text".to_string()]).unwrap().man);
+	}
+
+	#[test]
+	fn args_parse_print_config() {
+		assert!(parse(vec!["path/to/bin".to_string(), "--print-config".to_string()]).unwrap().print_config);
+		assert!(
+			parse(vec![
+				"path/to/bin".to_string(),
+				"my text".to_string(),
+				"--print-config".to_string()
+			])
+			.unwrap()
+			.print_config
+		);
+		assert!(!parse(vec!["path/to/bin".to_string(), "my text".to_string()]).unwrap().print_config);
+	}
+
+	#[test]
+	fn args_parse_with_defaults() {
+		let defaults = Options {
+			font: Fonts::FontTiny,
+			align: Align::Center,
+			spaceless: true,
+			..Options::default()
+		};
+
+		let options = parse_with(vec!["path/to/bin".to_string(), "my text".to_string()], defaults.clone()).unwrap();
+		assert_eq!(
+			options,
+			Options {
+				text: String::from("my text"),
+				..defaults.clone()
+			}
+		);
+
+		let options = parse_with(
+			vec![
+				"path/to/bin".to_string(),
+				"my text".to_string(),
+				"-f".to_string(),
+				"console".to_string(),
+				"-a".to_string(),
+				"right".to_string(),
+			],
+			defaults,
+		)
+		.unwrap();
+		assert_eq!(options.font, Fonts::FontConsole);
+		assert_eq!(options.align, Align::Right);
+		assert_eq!(options.line_height, 0);
+		assert!(options.spaceless);
+	}
+
+	#[test]
+	fn args_apply_option() {
+		let option = |key: &str| CLIOPTIONS.iter().find(|option| option.key == key).unwrap();
+		let mut options = Options {
+			spaceless: true,
+			..Options::default()
+		};
+
+		apply_option(option("spaceless"), "FALSE", &mut options).unwrap();
+		apply_option(option("letter_spacing"), "4", &mut options).unwrap();
+		apply_option(option("countdown"), "2m", &mut options).unwrap();
+		apply_option(option("clock"), "%H:%M", &mut options).unwrap();
+		apply_option(option("colors"), "red,#ff8800", &mut options).unwrap();
+		assert!(!options.spaceless);
+		assert_eq!(options.letter_spacing, 4);
+		assert_eq!(options.countdown, Some(120_000));
+		assert_eq!(options.clock, Some(String::from("%H:%M")));
+		assert_eq!(options.colors, vec![Colors::Red, Colors::Rgb(Rgb::Val(255, 136, 0))]);
+
+		assert!(apply_option(option("letter_spacing"), "-1", &mut options).is_err());
+		assert!(apply_option(option("debug"), "1", &mut options).is_err());
+		assert!(apply_option(option("theme"), "no-such-theme", &mut options).is_err());
+	}
+
+	#[test]
+	fn args_parse_gradient_broken_user_presets() {
+		let dir = std::env::temp_dir().join(format!("cfonts-args-gradient-broken-test-{}", std::process::id()));
+		std::fs::create_dir_all(dir.join("cfonts")).unwrap();
+		std::fs::write(dir.join("cfonts").join("gradients.toml"), "[sunset]\ncolours = [\"#ff5f6d\", \"#ffc371\"]\n")
+			.unwrap();
+
+		temp_env::with_var("XDG_CONFIG_HOME", Some(&dir), || {
+			let args = |gradient: &str| {
+				vec![
+					"path/to/bin".to_string(),
+					"my text".to_string(),
+					"-g".to_string(),
+					gradient.to_string(),
+				]
+			};
+			assert!(parse(args("pride")).unwrap().transition_gradient);
+			assert_eq!(parse(args("red,blue")).unwrap().gradient, vec![String::from("#ff0000"), String::from("#0000ff")]);
+			assert!(parse(args("sunset")).is_err());
+		});
+
+		std::fs::write(dir.join("cfonts").join("gradients.toml"), "[sunset\n").unwrap();
+		temp_env::with_var("XDG_CONFIG_HOME", Some(&dir), || {
+			assert!(parse(vec![
+				"path/to/bin".to_string(),
+				"my text".to_string(),
+				"-g".to_string(),
+				"pride".to_string()
+			])
+			.is_ok());
+			assert!(parse(vec![
+				"path/to/bin".to_string(),
+				"my text".to_string(),
+				"-g".to_string(),
+				"sunset".to_string()
+			])
+			.is_err());
+		});
+
+		let _ = std::fs::remove_dir_all(&dir);
+	}
+
+	#[test]
+	fn args_parse_gradient_user_presets() {
+		let dir = std::env::temp_dir().join(format!("cfonts-args-gradient-test-{}", std::process::id()));
+		std::fs::create_dir_all(dir.join("cfonts")).unwrap();
+		std::fs::write(dir.join("cfonts").join("gradients.toml"), "[sunset]\ncolors = [\"#ff5f6d\", \"#ffc371\"]\n")
+			.unwrap();
+
+		temp_env::with_var("XDG_CONFIG_HOME", Some(&dir), || {
+			let options = parse(vec![
+				"path/to/bin".to_string(),
+				"my text".to_string(),
+				"-g".to_string(),
+				"sunset".to_string(),
+			])
+			.unwrap();
+			assert_eq!(options.gradient, vec![String::from("#ff5f6d"), String::from("#ffc371")]);
+			assert!(!options.transition_gradient);
+
+			let options = parse(vec![
+				"path/to/bin".to_string(),
+				"my text".to_string(),
+				"-g".to_string(),
+				"pride".to_string(),
+			])
+			.unwrap();
+			assert!(options.transition_gradient);
+		});
+
+		let _ = std::fs::remove_dir_all(&dir);
+	}
+
+	#[test]
+	fn args_parse_terminal_bg() {
+		let mut options = Options::default();
+		options.text = String::from("my text");
+
+		for (value, bg) in [
+			("auto", TerminalBg::Auto),
+			("LIGHT", TerminalBg::Light),
+			("dark", TerminalBg::Dark),
+		] {
+			options.terminal_bg = bg;
+			assert_eq!(
+				parse(vec![
+					"path/to/bin".to_string(),
+					"my text".to_string(),
+					"--terminal-bg".to_string(),
+					value.to_string()
+				])
+				.unwrap(),
+				options
+			);
+		}
+
+		// missing value
+		assert!(parse(vec![
+			"path/to/bin".to_string(),
+			"my text".to_string(),
+			"--terminal-bg".to_string()
+		])
+		.is_err());
+
+		// unknown value
+		assert!(parse(vec![
+			"path/to/bin".to_string(),
+			"my text".to_string(),
+			"--terminal-bg".to_string(),
+			"grey".to_string(),
+		])
+		.is_err());
+	}
+
+	#[test]
+	fn args_parse_strict_contrast() {
+		assert!(
+			parse(vec![
+				"path/to/bin".to_string(),
+				"my text".to_string(),
+				"--strict-contrast".to_string()
+			])
+			.unwrap()
+			.strict_contrast
+		);
+		assert!(!parse(vec!["path/to/bin".to_string(), "my text".to_string()]).unwrap().strict_contrast);
+	}
+
+	#[test]
+	fn args_parse_simulate() {
+		let mut options = Options::default();
+		options.text = String::from("my text");
+
+		for (value, cvd) in [
+			("none", None),
+			("protanopia", Some(Cvd::Protanopia)),
+			("Deuteranopia", Some(Cvd::Deuteranopia)),
+			("tritanopia", Some(Cvd::Tritanopia)),
+			("achromatopsia", Some(Cvd::Achromatopsia)),
+		] {
+			options.simulate = cvd;
+			assert_eq!(
+				parse(vec![
+					"path/to/bin".to_string(),
+					"my text".to_string(),
+					"--simulate".to_string(),
+					value.to_string()
+				])
+				.unwrap(),
+				options
+			);
+		}
+
+		// missing value
+		assert!(parse(vec![
+			"path/to/bin".to_string(),
+			"my text".to_string(),
+			"--simulate".to_string()
+		])
+		.is_err());
+
+		// unknown value
+		assert!(parse(vec![
+			"path/to/bin".to_string(),
+			"my text".to_string(),
+			"--simulate".to_string(),
+			"colorblind".to_string(),
+		])
+		.is_err());
+	}
+
+	#[test]
+	fn args_parse_output() {
+		let mut options = Options::default();
+		options.text = String::from("my text");
+		options.output = Some(String::from("banner.png"));
+
+		assert_eq!(
+			parse(vec![
+				"path/to/bin".to_string(),
+				"my text".to_string(),
+				"--output".to_string(),
+				"banner.png".to_string()
+			])
+			.unwrap(),
+			options
+		);
+
+		// missing value
+		assert!(parse(vec!["path/to/bin".to_string(), "my text".to_string(), "--output".to_string()]).is_err());
+	}
+
+	#[test]
+	fn args_parse_animate() {
+		let mut options = Options::default();
+		options.text = String::from("my text");
+		options.animate = Some(Effect::Wipe);
+		options.fps = 12;
+		options.duration = Some(1_500);
+
+		assert_eq!(
+			parse(vec![
+				"path/to/bin".to_string(),
+				"my text".to_string(),
+				"--animate".to_string(),
+				"WIPE".to_string(),
+				"--fps".to_string(),
+				"12".to_string(),
+				"--duration".to_string(),
+				"1500ms".to_string()
+			])
+			.unwrap(),
+			options
+		);
+
+		options.animate = Some(Effect::Typewriter);
+		options.fps = 30;
+		options.duration = Some(120_000);
+		assert_eq!(
+			parse(vec![
+				"path/to/bin".to_string(),
+				"my text".to_string(),
+				"--animate".to_string(),
+				"typewriter".to_string(),
+				"--duration".to_string(),
+				"2m".to_string()
+			])
+			.unwrap(),
+			options
+		);
+
+		// unknown effect
+		assert!(parse(vec![
+			"path/to/bin".to_string(),
+			"my text".to_string(),
+			"--animate".to_string(),
+			"explode".to_string()
+		])
+		.is_err());
+
+		// unreadable duration
+		assert!(parse(vec![
+			"path/to/bin".to_string(),
+			"my text".to_string(),
+			"--duration".to_string(),
+			"1 day".to_string()
+		])
+		.is_err());
+
+		// missing values
+		assert!(parse(vec![
+			"path/to/bin".to_string(),
+			"my text".to_string(),
+			"--animate".to_string()
+		])
+		.is_err());
+		assert!(parse(vec![
+			"path/to/bin".to_string(),
+			"my text".to_string(),
+			"--duration".to_string()
+		])
+		.is_err());
+	}
+
+	#[test]
+	fn args_parse_marquee() {
+		let mut options = Options::default();
+		options.text = String::from("my text");
+		options.marquee = true;
+		options.speed = 25;
+
+		assert_eq!(
+			parse(vec![
+				"path/to/bin".to_string(),
+				"my text".to_string(),
+				"--marquee".to_string(),
+				"--speed".to_string(),
+				"25".to_string()
+			])
+			.unwrap(),
+			options
+		);
+
+		// missing value
+		assert!(parse(vec!["path/to/bin".to_string(), "my text".to_string(), "--speed".to_string()]).is_err());
+	}
+
+	#[test]
+	fn args_parse_clock() {
+		let mut options = Options::default();
+		options.clock = Some(String::from("%H:%M"));
+
+		assert_eq!(parse(vec!["path/to/bin".to_string(), "--clock".to_string(), "%H:%M".to_string()]).unwrap(), options);
+
+		// the format is optional
+		options.clock = Some(String::from("%H:%M:%S"));
+		options.font = Fonts::FontTiny;
+		assert_eq!(
+			parse(vec![
+				"path/to/bin".to_string(),
+				"--clock".to_string(),
+				"-f".to_string(),
+				"tiny".to_string()
+			])
+			.unwrap(),
+			options
+		);
+
+		// text is ignored for the clock but still allowed
+		options.text = String::from("my text");
+		options.font = Fonts::FontBlock;
+		assert_eq!(parse(vec!["path/to/bin".to_string(), "my text".to_string(), "--clock".to_string()]).unwrap(), options);
+	}
+
+	#[test]
+	fn args_parse_countdown() {
+		let mut options = Options::default();
+		options.countdown = Some(300_000);
+		options.font = Fonts::FontChrome;
+
+		assert_eq!(
+			parse(vec![
+				"path/to/bin".to_string(),
+				"--countdown".to_string(),
+				"5m".to_string(),
+				"-f".to_string(),
+				"chrome".to_string()
+			])
+			.unwrap(),
+			options
+		);
+
+		// unreadable duration
+		assert!(parse(vec!["path/to/bin".to_string(), "--countdown".to_string(), "soon".to_string()]).is_err());
+
+		// missing value
+		assert!(parse(vec!["path/to/bin".to_string(), "--countdown".to_string()]).is_err());
+	}
+
+	#[test]
+	fn args_parse_output_format() {
+		let mut options = Options::default();
+		options.text = String::from("my text");
+		options.output_format = OutputFormat::Html;
+		options.html_fragment = true;
+		options.html_css = true;
+
+		assert_eq!(
+			parse(vec![
+				"path/to/bin".to_string(),
+				"my text".to_string(),
+				"--output-format".to_string(),
+				"HTML".to_string(),
+				"--html-fragment".to_string(),
+				"--html-css".to_string()
+			])
+			.unwrap(),
+			options
+		);
+
+		// missing value
+		assert!(parse(vec![
+			"path/to/bin".to_string(),
+			"my text".to_string(),
+			"--output-format".to_string()
+		])
+		.is_err());
+
+		options.output_format = OutputFormat::Json;
+		options.html_fragment = false;
+		options.html_css = false;
+		assert_eq!(
+			parse(vec![
+				"path/to/bin".to_string(),
+				"my text".to_string(),
+				"--output-format".to_string(),
+				"json".to_string()
+			])
+			.unwrap(),
+			options
+		);
+
+		options.output_format = OutputFormat::Plain;
+		options.trim = true;
+		assert_eq!(
+			parse(vec![
+				"path/to/bin".to_string(),
+				"my text".to_string(),
+				"--output-format".to_string(),
+				"plain".to_string(),
+				"--trim".to_string()
+			])
+			.unwrap(),
+			options
+		);
+
+		// unknown value
+		assert!(parse(vec![
+			"path/to/bin".to_string(),
+			"my text".to_string(),
+			"--output-format".to_string(),
+			"xml".to_string()
+		])
+		.is_err());
+	}
+
+	fn args(args: Vec<&str>) -> Vec<String> {
+		args.into_iter().map(String::from).collect()
+	}
+
+	#[test]
+	fn normalize_args_works() {
+		assert_eq!(
+			normalize_args(&args(vec!["-f", "tiny", "hi", "-s"])).unwrap(),
+			(Some(String::from("hi")), args(vec!["-f", "tiny", "-s"]))
+		);
+		assert_eq!(
+			normalize_args(&args(vec!["--font=tiny", "--colors=red,blue", "hi", "--gradient="])).unwrap(),
+			(Some(String::from("hi")), args(vec!["--font", "tiny", "--colors", "red,blue", "--gradient", ""]))
+		);
+		assert_eq!(
+			normalize_args(&args(vec!["-ftiny", "-sitcred", "hi"])).unwrap(),
+			(Some(String::from("hi")), args(vec!["-f", "tiny", "-s", "-i", "-t", "-c", "red"]))
+		);
+		assert_eq!(
+			normalize_args(&args(vec!["--clock", "-f", "tiny"])).unwrap(),
+			(None, args(vec!["--clock", "-f", "tiny"]))
+		);
+		assert_eq!(normalize_args(&args(vec!["--clock", "%H", "-s"])).unwrap(), (None, args(vec!["--clock", "%H", "-s"])));
+		assert_eq!(normalize_args(&args(vec!["-squ"])).unwrap(), (None, args(vec!["-s", "-q", "-u"])));
+		assert_eq!(normalize_args(&args(vec!["hi", "stray"])).unwrap(), (Some(String::from("hi")), vec![]));
+		assert_eq!(normalize_args(&args(vec!["-"])).unwrap(), (Some(String::from("-")), vec![]));
+		assert!(normalize_args(&args(vec!["hi", "--spaceless=false"])).is_err());
+		// unknown flags only take a value when there is still a text after it
+		assert_eq!(
+			normalize_args(&args(vec!["--colour", "red", "hi"])).unwrap(),
+			(Some(String::from("hi")), args(vec!["--colour", "red"]))
+		);
+		assert_eq!(
+			normalize_args(&args(vec!["-sq", "red", "hi"])).unwrap(),
+			(Some(String::from("hi")), args(vec!["-s", "-q", "red"]))
+		);
+		assert_eq!(
+			normalize_args(&args(vec!["--colour", "hi"])).unwrap(),
+			(Some(String::from("hi")), args(vec!["--colour"]))
+		);
+		assert_eq!(
+			normalize_args(&args(vec!["hi", "--colour", "red"])).unwrap(),
+			(Some(String::from("hi")), args(vec!["--colour", "red"]))
+		);
+	}
+
+	#[test]
+	fn args_parse_gnu_style() {
+		let mut options = Options::default();
+		options.text = String::from("my text");
+		options.font = Fonts::FontTiny;
+		options.align = Align::Center;
+		options.colors = vec![Colors::Red];
+		options.spaceless = true;
+		options.letter_spacing = 2;
+
+		assert_eq!(
+			parse(args(vec![
+				"path/to/bin",
+				"-f",
+				"tiny",
+				"--align=center",
+				"my text",
+				"-s",
+				"-cred",
+				"-l2"
+			]))
+			.unwrap(),
+			options
+		);
+		assert_eq!(
+			parse(args(vec![
+				"path/to/bin",
+				"--font=tiny",
+				"-sacenter",
+				"-c",
+				"red",
+				"--letter-spacing",
+				"2",
+				"my text"
+			]))
+			.unwrap(),
+			options
+		);
+		// short flags take the rest of the argument as is
+		assert!(parse(args(vec!["path/to/bin", "my text", "-c=red"])).is_err());
+	}
+
+	#[test]
+	fn args_parse_double_dash() {
+		let mut options = Options::default();
+		options.text = String::from("-10%");
+		options.font = Fonts::FontTiny;
+		assert_eq!(parse(args(vec!["path/to/bin", "-f", "tiny", "--", "-10%"])).unwrap(), options);
+		assert_eq!(parse(args(vec!["path/to/bin", "--", "-10%", "-f", "tiny"])).unwrap().font, Fonts::FontBlock);
+
+		options.text = String::from("--help");
+		options.font = Fonts::FontBlock;
+		assert_eq!(parse(args(vec!["path/to/bin", "--", "--help"])).unwrap(), options);
+
+		// without -- the text is read as flags
+		assert!(parse(args(vec!["path/to/bin", "-10%"])).is_err());
+	}
+
+	#[test]
+	fn args_parse_requires_text() {
+		assert!(parse(args(vec!["path/to/bin", "-f", "tiny"])).is_err());
+		assert!(parse(args(vec!["path/to/bin", "-s", "--"])).is_err());
+		assert_eq!(parse(args(vec!["path/to/bin", "-f", "tiny", ""])).unwrap().text, String::from(""));
+		assert!(parse(args(vec!["path/to/bin", "-f", "tiny", "-v"])).unwrap().version);
+	}
+
+	#[test]
+	fn parse_many_works() {
+		let defaults = Options {
+			align: Align::Center,
+			..Options::default()
+		};
+		let blocks = parse_many(
+			args(vec![
+				"path/to/bin",
+				"Title",
+				"-f",
+				"block",
+				"--then",
+				"-f",
+				"tiny",
+				"subtitle",
+				"-c",
+				"gray",
+				"--then",
+				"--",
+				"--then",
+			]),
+			defaults.clone(),
+		)
+		.unwrap();
+
+		assert_eq!(blocks.len(), 3);
+		assert_eq!(
+			blocks[0],
+			Options {
+				text: String::from("Title"),
+				font: Fonts::FontBlock,
+				..defaults.clone()
+			}
+		);
+		assert_eq!(
+			blocks[1],
+			Options {
+				text: String::from("subtitle"),
+				font: Fonts::FontTiny,
+				colors: vec![Colors::Gray],
+				..defaults.clone()
+			}
+		);
+		assert_eq!(
+			blocks[2],
+			Options {
+				text: String::from("--then"),
+				..defaults.clone()
+			}
+		);
+
+		assert_eq!(parse_many(args(vec!["path/to/bin", "hi"]), defaults).unwrap().len(), 1);
+	}
+
+	#[test]
+	fn parse_many_errors() {
+		assert!(parse_many(args(vec!["path/to/bin", "hi", "--then"]), Options::default()).is_err());
+		assert!(parse_many(args(vec!["path/to/bin", "hi", "--then", "-f", "nope", "there"]), Options::default()).is_err());
+		assert!(parse_many(args(vec!["path/to/bin", "hi", "--marquee", "--then", "there"]), Options::default()).is_err());
+		assert!(parse_many(
+			args(vec!["path/to/bin", "hi", "--output-format", "json", "--then", "there"]),
+			Options::default()
+		)
+		.is_err());
+	}
+
+	#[test]
+	fn args_parse_border() {
+		let mut options = Options::default();
+		options.text = String::from("my text");
+		options.border = Some(Border::Rounded);
+		options.border_color = vec![Colors::Cyan, Colors::Magenta];
+		options.padding = [1, 2, 1, 2];
+
+		assert_eq!(
+			parse(args(vec![
+				"path/to/bin",
+				"my text",
+				"--border",
+				"rounded",
+				"--border-color",
+				"cyan,magenta",
+				"--padding",
+				"1,2"
+			]))
+			.unwrap(),
+			options
+		);
+
+		options.border = None;
+		options.border_color = vec![Colors::System];
+		options.padding = [1, 2, 3, 4];
+		assert_eq!(parse(args(vec!["path/to/bin", "my text", "--border=none", "--padding=1,2,3,4"])).unwrap(), options);
+
+		assert!(parse(args(vec!["path/to/bin", "my text", "--border", "dotted"])).is_err());
+		assert!(parse(args(vec!["path/to/bin", "my text", "--border-color", "nope"])).is_err());
+		assert!(parse(args(vec!["path/to/bin", "my text", "--padding", "1,2,3"])).is_err());
+		assert!(parse(args(vec!["path/to/bin", "my text", "--padding", "-1"])).is_err());
+		assert!(parse(args(vec!["path/to/bin", "my text", "--border"])).is_err());
+	}
+
+	#[test]
+	fn args_parse_shadow() {
+		let mut options = Options::default();
+		options.text = String::from("my text");
+		options.shadow = true;
+		options.shadow_offset = [2, 1];
+		options.shadow_char = '▒';
+		options.shadow_color = Colors::Blue;
+
+		assert_eq!(
+			parse(args(vec![
+				"path/to/bin",
+				"my text",
+				"--shadow",
+				"--shadow-offset",
+				"2,1",
+				"--shadow-char",
+				"▒",
+				"--shadow-color",
+				"blue"
+			]))
+			.unwrap(),
+			options
+		);
+
+		options.shadow_offset = [3, 3];
+		options.shadow_char = '░';
+		options.shadow_color = Colors::Gray;
+		assert_eq!(parse(args(vec!["path/to/bin", "my text", "--shadow", "--shadow-offset=3"])).unwrap(), options);
+
+		assert!(parse(args(vec!["path/to/bin", "my text", "--shadow-offset", "1,2,3"])).is_err());
+		assert!(parse(args(vec!["path/to/bin", "my text", "--shadow-char", "ab"])).is_err());
+		assert!(parse(args(vec!["path/to/bin", "my text", "--shadow-char", " "])).is_err());
+		assert!(parse(args(vec!["path/to/bin", "my text", "--shadow-color", "red,blue"])).is_err());
+		assert!(parse(args(vec!["path/to/bin", "my text", "--shadow-color", "candy"])).is_err());
+	}
 }
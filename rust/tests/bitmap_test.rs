@@ -0,0 +1,80 @@
+extern crate cfonts;
+
+use cfonts::bitmap::{get_glyph, Glyph, CELL_HEIGHT, CELL_WIDTH};
+use cfonts::config::Options;
+
+#[cfg(test)]
+mod bitmap {
+	use super::*;
+
+	fn count(glyph: &Glyph) -> usize {
+		glyph.iter().map(|row| row.iter().filter(|pixel| **pixel).count()).sum()
+	}
+
+	#[test]
+	fn get_glyph_draws_ascii() {
+		let options = Options::default();
+
+		assert_eq!(count(&get_glyph(' ', &options)), 0);
+		// the dot of the "!" is a single font pixel drawn at double size
+		let glyph = get_glyph('!', &options);
+		assert!(glyph[16][5] && glyph[17][6]);
+		assert!(!glyph[14][5]);
+		// every printable character apart from the space paints something
+		for character in '!'..='~' {
+			assert!(count(&get_glyph(character, &options)) > 0, "{:?} is empty", character);
+		}
+	}
+
+	#[test]
+	fn get_glyph_draws_blocks() {
+		let options = Options::default();
+		let half = CELL_WIDTH * CELL_HEIGHT / 2;
+
+		assert_eq!(count(&get_glyph('█', &options)), CELL_WIDTH * CELL_HEIGHT);
+		assert_eq!(count(&get_glyph('▀', &options)), half);
+		assert_eq!(count(&get_glyph('▄', &options)), half);
+		assert_eq!(count(&get_glyph('▌', &options)), half);
+		assert_eq!(count(&get_glyph('▐', &options)), half);
+		assert_eq!(count(&get_glyph('▚', &options)), half);
+		assert_eq!(count(&get_glyph('▖', &options)), half / 2);
+		assert_eq!(count(&get_glyph('▟', &options)), half + half / 2);
+		assert_eq!(count(&get_glyph('░', &options)), half / 2);
+		assert!(get_glyph('▀', &options)[0][0]);
+		assert!(!get_glyph('▀', &options)[CELL_HEIGHT - 1][0]);
+		assert!(get_glyph('▗', &options)[CELL_HEIGHT - 1][CELL_WIDTH - 1]);
+	}
+
+	#[test]
+	fn get_glyph_draws_box_drawing() {
+		let options = Options::default();
+
+		let line = get_glyph('─', &options);
+		assert!(line[12][0] && line[12][CELL_WIDTH - 1]);
+		assert!(!line[0][6]);
+
+		let heavy = get_glyph('┃', &options);
+		assert_eq!(count(&heavy), 4 * CELL_HEIGHT);
+
+		// double lines have a gap in the middle
+		let double = get_glyph('═', &options);
+		assert!(double[9][0] && double[14][0]);
+		assert!(!double[11][0] && !double[12][0]);
+
+		// the inner corner of a double corner does not reach the outer line
+		let corner = get_glyph('╔', &options);
+		assert!(corner[9][3] && corner[CELL_HEIGHT - 1][3] && corner[9][CELL_WIDTH - 1]);
+		assert!(corner[13][7]);
+		assert!(!corner[12][6] && !corner[13][5]);
+		assert!(!corner[0][6]);
+	}
+
+	#[test]
+	fn get_glyph_falls_back_to_a_box() {
+		let options = Options::default();
+		let glyph = get_glyph('€', &options);
+
+		assert!(glyph[4][2] && glyph[CELL_HEIGHT - 5][CELL_WIDTH - 3]);
+		assert!(!glyph[12][6]);
+	}
+}
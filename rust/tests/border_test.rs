@@ -0,0 +1,193 @@
+extern crate cfonts;
+
+use cfonts::border::{add_border, get_border_chars, get_border_colors, trim_columns, BorderChars};
+use cfonts::config::{Align, BgColors, Border, ColorMode, Colors, Env, Fonts, Options};
+use cfonts::plain::strip_ansi;
+use cfonts::render;
+
+#[cfg(test)]
+mod border {
+	use super::*;
+
+	fn rows(rows: Vec<&str>) -> Vec<String> {
+		rows.into_iter().map(String::from).collect()
+	}
+
+	#[test]
+	fn get_border_chars_works() {
+		assert_eq!(
+			get_border_chars(&Border::Single),
+			BorderChars {
+				top_left: '┌',
+				top_right: '┐',
+				bottom_left: '└',
+				bottom_right: '┘',
+				horizontal: '─',
+				vertical: '│',
+			}
+		);
+		assert_eq!(get_border_chars(&Border::Double).top_right, '╗');
+		assert_eq!(get_border_chars(&Border::Rounded).bottom_left, '╰');
+		assert_eq!(get_border_chars(&Border::Heavy).vertical, '┃');
+		assert_eq!(get_border_chars(&Border::Ascii).horizontal, '-');
+	}
+
+	#[test]
+	fn get_border_colors_works() {
+		let mut options = Options::default();
+		assert_eq!(get_border_colors(3, &options), vec![Colors::System; 3]);
+
+		options.border_color = vec![];
+		assert_eq!(get_border_colors(2, &options), vec![Colors::System; 2]);
+
+		options.border_color = vec![Colors::Red, Colors::Blue, Colors::Green];
+		let colors = get_border_colors(10, &options);
+		assert_eq!(colors.len(), 10);
+		assert!(colors.iter().all(|color| matches!(color, Colors::Rgb(_))));
+	}
+
+	#[test]
+	fn add_border_works() {
+		let options = Options {
+			border: Some(Border::Ascii),
+			..Options::default()
+		};
+
+		assert_eq!(add_border(&rows(vec!["ab", "c"]), 2, &options), rows(vec!["+--+", "|ab|", "|c |", "+--+"]));
+	}
+
+	#[test]
+	fn add_border_adds_padding() {
+		let options = Options {
+			border: Some(Border::Single),
+			padding: [1, 2, 0, 1],
+			..Options::default()
+		};
+
+		assert_eq!(add_border(&rows(vec!["ab"]), 2, &options), rows(vec!["┌─────┐", "│     │", "│ ab  │", "└─────┘"]));
+
+		// padding works without a frame
+		let options = Options {
+			padding: [1, 1, 1, 1],
+			..Options::default()
+		};
+		assert_eq!(add_border(&rows(vec!["ab"]), 2, &options), rows(vec!["    ", " ab ", "    "]));
+	}
+
+	#[test]
+	fn add_border_aligns_rows() {
+		let mut options = Options {
+			border: Some(Border::Ascii),
+			align: Align::Center,
+			..Options::default()
+		};
+		assert_eq!(
+			add_border(&rows(vec!["abcd", "a", "ab"]), 4, &options),
+			rows(vec!["+----+", "|abcd|", "|  a |", "| ab |", "+----+"])
+		);
+
+		options.align = Align::Right;
+		assert_eq!(add_border(&rows(vec!["abcd", "a"]), 4, &options), rows(vec!["+----+", "|abcd|", "|   a|", "+----+"]));
+	}
+
+	#[test]
+	fn add_border_ignores_escape_sequences() {
+		let options = Options {
+			border: Some(Border::Ascii),
+			..Options::default()
+		};
+
+		assert_eq!(
+			add_border(&rows(vec!["\x1b[31mab\x1b[39m", "c"]), 2, &options),
+			rows(vec!["+--+", "|\x1b[31mab\x1b[39m|", "|c |", "+--+"])
+		);
+	}
+
+	#[test]
+	fn add_border_paints_frame_and_background() {
+		let options = Options {
+			border: Some(Border::Ascii),
+			border_color: vec![Colors::Red],
+			background: BgColors::Blue,
+			color_mode: ColorMode::Ansi16,
+			..Options::default()
+		};
+
+		assert_eq!(
+			add_border(&rows(vec!["a"]), 1, &options),
+			rows(vec![
+				"\x1b[31m+-+\x1b[39m",
+				"\x1b[31m|\x1b[39m\x1b[44ma\x1b[49m\x1b[31m|\x1b[39m",
+				"\x1b[31m+-+\x1b[39m",
+			])
+		);
+
+		let options = Options {
+			color_mode: ColorMode::NoColor,
+			..options
+		};
+		assert_eq!(add_border(&rows(vec!["a"]), 1, &options), rows(vec!["+-+", "|a|", "+-+"]));
+	}
+
+	#[test]
+	fn add_border_paints_for_browser() {
+		let options = Options {
+			border: Some(Border::Ascii),
+			border_color: vec![Colors::Red],
+			background: BgColors::Blue,
+			env: Env::Browser,
+			..Options::default()
+		};
+
+		assert_eq!(
+			add_border(&rows(vec!["<span style=\"color:#0020f5\">a</span>"]), 1, &options),
+			rows(vec![
+				"<span style=\"color:#ea3223\">+-+</span>",
+				"<span style=\"color:#ea3223\">|</span><span style=\"color:#0020f5\">a</span><span style=\"color:#ea3223\">|</span>",
+				"<span style=\"color:#ea3223\">+-+</span>",
+			])
+		);
+	}
+
+	#[test]
+	fn trim_columns_works() {
+		let options = Options::default();
+		assert_eq!(
+			trim_columns(&rows(vec![" \x1b[31m ab \x1b[39m", "  c", ""]), &options),
+			(rows(vec!["\x1b[31mab\x1b[39m", "c", ""]), 2)
+		);
+		assert_eq!(trim_columns(&rows(vec!["  ", ""]), &options), (rows(vec!["", ""]), 0));
+
+		let options = Options {
+			env: Env::Browser,
+			..Options::default()
+		};
+		assert_eq!(
+			trim_columns(&rows(vec![" <span style=\"color:#ea3223\"> a</span> "]), &options),
+			(rows(vec!["<span style=\"color:#ea3223\">a</span>"]), 1)
+		);
+	}
+
+	#[test]
+	fn render_frame_pads_evenly() {
+		for padding in [[0, 0, 0, 0], [0, 1, 0, 1], [0, 2, 0, 2]] {
+			let output = render(Options {
+				text: String::from("hi"),
+				font: Fonts::FontBlock,
+				border: Some(Border::Single),
+				padding,
+				..Options::default()
+			});
+			let rows = strip_ansi(&output.text)
+				.lines()
+				.filter(|row| row.starts_with('│'))
+				.map(|row| row.trim_start_matches('│').trim_end_matches('│').to_string())
+				.collect::<Vec<String>>();
+			let left = rows.iter().map(|row| row.len() - row.trim_start().len()).min().unwrap();
+			let right = rows.iter().map(|row| row.len() - row.trim_end().len()).min().unwrap();
+
+			assert_eq!(left, usize::from(padding[3]));
+			assert_eq!(right, usize::from(padding[1]));
+		}
+	}
+}
@@ -0,0 +1,168 @@
+extern crate cfonts;
+
+use std::path::PathBuf;
+use std::time::Duration;
+
+use cfonts::animate::{frames, Frame};
+use cfonts::cast::{frames2cast, render_cast};
+use cfonts::config::{BgColors, Colors, Effect, Fonts, Options};
+
+#[cfg(test)]
+mod cast {
+	use super::*;
+
+	/// Compare a cast against a fixture in `tests/fixtures/cast`
+	///
+	/// Run the tests with `CFONTS_UPDATE_GOLDEN=1` to write new fixtures after an intended change.
+	fn assert_fixture(name: &str, cast: &str) {
+		let path = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures/cast").join(name);
+		if std::env::var("CFONTS_UPDATE_GOLDEN").is_ok() {
+			std::fs::write(&path, cast).unwrap();
+		}
+		let fixture = std::fs::read_to_string(&path).unwrap_or_else(|_| panic!("Fixture {:?} is missing", path));
+		assert!(cast == fixture, "Output does not match the fixture {:?}", path);
+	}
+
+	#[test]
+	fn frames2cast_works() {
+		let frames = vec![
+			Frame {
+				text: String::from("\x1b[31ma\x1b[39m\nb"),
+				delay: Duration::from_millis(250),
+			},
+			Frame {
+				text: String::from("abc"),
+				delay: Duration::from_millis(250),
+			},
+			Frame {
+				text: String::from("d"),
+				delay: Duration::ZERO,
+			},
+		];
+
+		assert_eq!(
+			frames2cast(&frames, &Options::default()).unwrap(),
+			[
+				"{\"version\":2,\"width\":3,\"height\":2}",
+				"[0.0,\"o\",\"\\u001b[?25l\\u001b[31ma\\u001b[39m\\r\\nb\"]",
+				"[0.25,\"o\",\"\\r\\u001b[1A\\u001b[Jabc\"]",
+				"[0.5,\"o\",\"\\r\\u001b[Jd\"]",
+				"[0.5,\"o\",\"\\u001b[?25h\"]",
+				"",
+			]
+			.join("\n")
+		);
+	}
+
+	#[test]
+	fn frames2cast_works_without_frames() {
+		assert_eq!(
+			frames2cast(&[], &Options::default()).unwrap(),
+			"{\"version\":2,\"width\":0,\"height\":0}\n[0.0,\"o\",\"\\u001b[?25h\"]\n"
+		);
+	}
+
+	#[test]
+	fn frames2cast_works_with_animations() {
+		let options = Options {
+			text: String::from("ab"),
+			font: Fonts::FontConsole,
+			colors: vec![Colors::Red],
+			spaceless: true,
+			fps: 4,
+			..Options::default()
+		};
+		let frames = frames(options.clone(), Effect::Typewriter).collect::<Vec<Frame>>();
+
+		assert_eq!(
+			frames2cast(&frames, &options).unwrap(),
+			[
+				"{\"version\":2,\"width\":2,\"height\":1}",
+				"[0.0,\"o\",\"\\u001b[?25l\"]",
+				"[0.25,\"o\",\"\\r\\u001b[J\\u001b[31ma\\u001b[39m\"]",
+				"[0.5,\"o\",\"\\r\\u001b[J\\u001b[31ma\\u001b[39m\\u001b[31mb\\u001b[39m\"]",
+				"[0.5,\"o\",\"\\u001b[?25h\"]",
+				"",
+			]
+			.join("\n")
+		);
+	}
+
+	#[test]
+	fn render_cast_works_static() {
+		let cast = render_cast(&Options {
+			text: String::from("hi"),
+			font: Fonts::FontBlock,
+			gradient: vec![String::from("#ff0000"), String::from("#0000ff")],
+			..Options::default()
+		})
+		.unwrap();
+
+		assert_fixture("static.cast", &cast);
+		// the terminal fits the output exactly
+		assert!(cast.starts_with("{\"version\":2,\"width\":13,\"height\":10}\n"));
+	}
+
+	#[test]
+	fn render_cast_works_with_typewriter() {
+		let cast = render_cast(&Options {
+			text: String::from("hey"),
+			font: Fonts::FontTiny,
+			colors: vec![Colors::Red],
+			fps: 5,
+			animate: Some(Effect::Typewriter),
+			..Options::default()
+		})
+		.unwrap();
+
+		assert_fixture("typewriter.cast", &cast);
+	}
+
+	#[test]
+	fn render_cast_works_with_wipe_and_background() {
+		let cast = render_cast(&Options {
+			text: String::from("hi"),
+			font: Fonts::FontTiny,
+			background: BgColors::Blue,
+			fps: 10,
+			animate: Some(Effect::Wipe),
+			..Options::default()
+		})
+		.unwrap();
+
+		assert_fixture("wipe.cast", &cast);
+	}
+
+	#[test]
+	fn render_cast_works_with_cycle() {
+		let options = Options {
+			text: String::from("hi"),
+			font: Fonts::FontTiny,
+			gradient: vec![String::from("#ff0000"), String::from("#0000ff")],
+			fps: 10,
+			duration: Some(1_000),
+			animate: Some(Effect::Cycle),
+			..Options::default()
+		};
+		let cast = render_cast(&options).unwrap();
+
+		assert_fixture("cycle.cast", &cast);
+		// the same options always give the same cast
+		assert_eq!(cast, render_cast(&options).unwrap());
+		assert_eq!(cast.lines().count(), 12);
+	}
+
+	#[test]
+	fn render_cast_rejects_huge_durations() {
+		let error = render_cast(&Options {
+			text: String::from("hi"),
+			font: Fonts::FontTiny,
+			duration: Some(u64::MAX),
+			animate: Some(Effect::Wipe),
+			..Options::default()
+		})
+		.unwrap_err();
+
+		assert!(error.contains("too long"));
+	}
+}
@@ -2,9 +2,10 @@ extern crate cfonts;
 
 use cfonts::chars::{
 	add_letter, add_line, add_line_height, align_last_line, get_first_char_position, get_letter_length, get_letter_space,
-	get_longest_line_len, paint_letter,
+	get_longest_line_len, paint_letter, paint_letter_at,
 };
-use cfonts::config::{Align, Colors, Env, Options};
+use cfonts::color::{get_foreground_color_for, CandyPosition, CANDY_COLORS};
+use cfonts::config::{Align, CandyMode, ColorMode, Colors, Env, Options};
 
 #[cfg(test)]
 mod chars {
@@ -223,6 +224,69 @@ mod chars {
 		assert_eq!(get_letter_length(&letter, 2, &options), 11);
 	}
 
+	#[test]
+	fn paint_letter_at_works_with_candy_modes() {
+		let mut options = Options {
+			colors: vec![Colors::Candy, Colors::Candy],
+			env: Env::Browser,
+			seed: Some(1337),
+			..Options::default()
+		};
+		let letter = vec![
+			String::from("<c1>a</c1><c2>b</c2>"),
+			String::from("<c1>a</c1><c2>b</c2>"),
+		];
+		let position = |letter, line| CandyPosition {
+			letter,
+			line,
+			..CandyPosition::default()
+		};
+
+		// the same seed and position always give us the same output
+		options.candy_mode = CandyMode::Segment;
+		assert_eq!(
+			paint_letter_at(&letter, 2, &position(1, 0), &options),
+			paint_letter_at(&letter, 2, &position(1, 0), &options)
+		);
+
+		// every letter gets a single color
+		options.candy_mode = CandyMode::Glyph;
+		let painted = paint_letter_at(&letter, 2, &position(1, 0), &options);
+		assert_eq!(painted[0], painted[1]);
+		let colors =
+			painted[0].split("</span>").filter(|span| !span.is_empty()).map(|span| &span[0..26]).collect::<Vec<_>>();
+		assert_eq!(colors[0], colors[1]);
+
+		// every row gets a single color no matter what letter it is
+		options.candy_mode = CandyMode::Row;
+		assert_eq!(
+			paint_letter_at(&letter, 2, &position(1, 0), &options),
+			paint_letter_at(&letter, 2, &position(5, 0), &options)
+		);
+
+		// every layer gets a single color no matter where it is
+		options.candy_mode = CandyMode::Layer;
+		let painted = paint_letter_at(&letter, 2, &position(1, 0), &options);
+		assert_eq!(painted, paint_letter_at(&letter, 2, &position(9, 4), &options));
+		assert_eq!(painted[0], painted[1]);
+
+		// different seeds give us different colors for at least one of those letters
+		let painted = (0..20).map(|i| paint_letter_at(&letter, 2, &position(i, 0), &options)).collect::<Vec<_>>();
+		options.seed = Some(1338);
+		assert_ne!(painted, (0..20).map(|i| paint_letter_at(&letter, 2, &position(i, 0), &options)).collect::<Vec<_>>());
+	}
+
+	#[test]
+	fn paint_letter_works_with_candy() {
+		let options = Options {
+			colors: vec![Colors::Candy],
+			color_mode: ColorMode::Ansi16,
+			..Options::default()
+		};
+		let painted = paint_letter(&[String::from("x")], 1, &options);
+		assert!(CANDY_COLORS.iter().any(|color| painted[0].starts_with(&get_foreground_color_for(color, &options).0)));
+	}
+
 	#[test]
 	fn paint_letter_works() {
 		let mut options = Options::default();
@@ -1,7 +1,8 @@
 extern crate cfonts;
 
-use cfonts::cli::{help, version};
-use cfonts::config::Options;
+use cfonts::cli::{get_option_values, help, list_gradients, man, version, ENVIRONMENT, EXAMPLES};
+use cfonts::config::{Options, CLIOPTIONS};
+use cfonts::gradient::{GradientPreset, GradientPresetRegistry};
 
 #[cfg(test)]
 mod tests {
@@ -18,4 +19,94 @@ mod tests {
 		let options = Options::default();
 		assert!(help(&options).contains("sexy fonts in the console"));
 	}
+
+	#[test]
+	fn help_documents_gradient_presets() {
+		let options = Options::default();
+		let output = help(&options);
+		assert!(output.contains("--list-gradients"));
+		assert!(output.contains("pride"));
+		assert!(output.contains("transgender"));
+	}
+
+	#[test]
+	fn list_gradients_works() {
+		let options = Options::default();
+		let mut presets = GradientPresetRegistry::new();
+		presets
+			.register(
+				"sunset",
+				GradientPreset {
+					colors: vec![String::from("#ff0000"), String::from("#0000ff")],
+					transition: false,
+				},
+				&options,
+			)
+			.unwrap();
+		presets.alias("dusk", "sunset");
+
+		let output = list_gradients(&presets, &options);
+		assert!(output.contains("sunset"));
+		assert!(output.contains("dusk"));
+		assert!(output.contains("\u{2588}"));
+		assert!(!output.contains("transgender"));
+	}
+
+	#[test]
+	fn help_documents_all_option_values() {
+		let options = Options::default();
+		let output = help(&options);
+		assert!(output.contains("bash, zsh, fish, elvish, powershell"));
+		assert!(output.contains(EXAMPLES[0].0));
+	}
+
+	#[test]
+	fn get_option_values_works() {
+		let presets = GradientPresetRegistry::default();
+		let option = |key: &str| CLIOPTIONS.iter().find(|option| option.key == key).unwrap();
+
+		assert_eq!(get_option_values(option("env"), &presets), Some(String::from("cli, browser")));
+		assert_eq!(
+			get_option_values(option("simulate"), &presets),
+			Some(String::from("none, protanopia, deuteranopia, tritanopia, achromatopsia"))
+		);
+		assert!(get_option_values(option("gradient"), &presets).unwrap().starts_with("presets: agender, "));
+		assert_eq!(get_option_values(option("letter_spacing"), &presets), None);
+	}
+
+	#[test]
+	fn man_works() {
+		let options = Options::default();
+		let output = man(&options);
+
+		assert!(
+			output.starts_with(&format!(".TH CFONTS 1 \"\" \"cfonts {}\" \"User Commands\"\n", env!("CARGO_PKG_VERSION")))
+		);
+		for section in [
+			"NAME",
+			"SYNOPSIS",
+			"DESCRIPTION",
+			"OPTIONS",
+			"EXAMPLES",
+			"ENVIRONMENT",
+			"FILES",
+			"SEE ALSO",
+		] {
+			assert!(output.contains(&format!("\n.SH {}\n", section)));
+		}
+		assert!(output.contains(
+			".TP\n\\fB\\-\\-align\\fR, \\fB\\-a\\fR\nUse to align your text output\n.br\nAllowed values: left, center, right, top, bottom\n.br\nExample: \\fBcfonts \\-\\-align center\\fR\n"
+		));
+		assert!(output.contains(".TP\n\\fB\\-\\-version\\fR, \\fB\\-v\\fR, \\fB\\-V\\fR\n"));
+		assert!(output.contains(".TP\n\\fBNO_COLOR\\fR\n"));
+		assert!(output.contains(".TP\n\\fBFORCE_COLOR\\fR\n"));
+		assert!(output.contains(".TP\n\\fI\\&.cfontsrc\\fR\n"));
+		assert_eq!(output.matches(".TP\n").count(), CLIOPTIONS.len() + EXAMPLES.len() + ENVIRONMENT.len() + 4);
+		// no line may start with a dot that isn't a request
+		assert!(output.lines().all(|line| !line.starts_with('.')
+			|| line.starts_with(".TH")
+			|| line.starts_with(".SH")
+			|| line.starts_with(".TP")
+			|| line.starts_with(".br")));
+	}
 }
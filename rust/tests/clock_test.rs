@@ -0,0 +1,300 @@
+extern crate cfonts;
+
+use std::cell::Cell;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::Duration;
+
+use cfonts::animate::{HIDE_CURSOR, SHOW_CURSOR};
+use cfonts::clock::{
+	align_text, format_countdown, format_time, get_text, run, Display, LocalTime, TimeSource, DEFAULT_CLOCK_FORMAT,
+};
+use cfonts::config::{Align, Fonts, Options};
+
+/// A clock that only moves when we sleep and can stop the run and resize the terminal at a given time
+struct FakeClock<'a> {
+	time: u64,
+	stop_at: Option<u64>,
+	stop: &'a AtomicBool,
+	resize_at: Option<(u64, usize)>,
+	width: &'a Cell<usize>,
+}
+
+impl TimeSource for FakeClock<'_> {
+	fn now(&mut self) -> LocalTime {
+		LocalTime::from_utc(self.time)
+	}
+
+	fn sleep(&mut self, duration: Duration) {
+		self.time += duration.as_millis() as u64;
+		if self.stop_at.is_some_and(|stop_at| self.time >= stop_at) {
+			self.stop.store(true, Ordering::Relaxed);
+		}
+		if let Some((resize_at, width)) = self.resize_at {
+			if self.time >= resize_at {
+				self.width.set(width);
+			}
+		}
+	}
+}
+
+/// 2000-02-29 12:34:56 UTC
+const TIME: u64 = 951_827_696_000;
+
+#[cfg(test)]
+mod clock {
+	use super::*;
+
+	fn options() -> Options {
+		Options {
+			font: Fonts::FontConsole,
+			spaceless: true,
+			..Options::default()
+		}
+	}
+
+	#[test]
+	fn local_time_from_utc_works() {
+		assert_eq!(
+			LocalTime::from_utc(0),
+			LocalTime {
+				timestamp: 0,
+				year: 1970,
+				month: 1,
+				day: 1,
+				hour: 0,
+				minute: 0,
+				second: 0,
+			}
+		);
+		assert_eq!(
+			LocalTime::from_utc(946_684_799_999),
+			LocalTime {
+				timestamp: 946_684_799_999,
+				year: 1999,
+				month: 12,
+				day: 31,
+				hour: 23,
+				minute: 59,
+				second: 59,
+			}
+		);
+		let time = LocalTime::from_utc(TIME);
+		assert_eq!((time.year, time.month, time.day, time.hour, time.minute, time.second), (2000, 2, 29, 12, 34, 56));
+		let time = LocalTime::from_utc(4_107_542_400_000);
+		assert_eq!((time.year, time.month, time.day), (2100, 3, 1));
+	}
+
+	#[test]
+	fn local_time_from_local_works() {
+		let time = LocalTime::from_local(TIME);
+		assert_eq!(time.timestamp, TIME);
+		assert!((1..=12).contains(&time.month));
+		assert!((1..=31).contains(&time.day));
+		assert!(time.hour < 24 && time.minute < 60 && time.second < 60);
+	}
+
+	#[test]
+	fn format_time_works() {
+		let time = LocalTime::from_utc(TIME);
+		assert_eq!(format_time(DEFAULT_CLOCK_FORMAT, &time), "12:34:56");
+		assert_eq!(format_time("%Y-%m-%d", &time), "2000-02-29");
+		assert_eq!(format_time("%y %I %p", &time), "00 12 PM");
+		assert_eq!(format_time("100%%", &time), "100%");
+		// unknown placeholders are kept
+		assert_eq!(format_time("%Q %", &time), "%Q %");
+
+		let morning = LocalTime::from_utc(TIME - 12 * 3_600_000);
+		assert_eq!(format_time("%I:%M %p", &morning), "12:34 AM");
+		let afternoon = LocalTime::from_utc(TIME + 3_600_000);
+		assert_eq!(format_time("%H %I %p", &afternoon), "13 01 PM");
+	}
+
+	#[test]
+	fn format_countdown_works() {
+		assert_eq!(format_countdown(0), "00:00");
+		assert_eq!(format_countdown(999), "00:01");
+		assert_eq!(format_countdown(1_000), "00:01");
+		assert_eq!(format_countdown(1_001), "00:02");
+		assert_eq!(format_countdown(59_000), "00:59");
+		assert_eq!(format_countdown(3_599_000), "59:59");
+		assert_eq!(format_countdown(3_600_000), "1:00:00");
+		assert_eq!(format_countdown(36_000_000), "10:00:00");
+	}
+
+	#[test]
+	fn get_text_works() {
+		let now = LocalTime::from_utc(TIME);
+		let options = Options {
+			clock: Some(String::from("%H%M")),
+			..Options::default()
+		};
+
+		assert_eq!(get_text(&options, &now, None), (String::from("1234"), false));
+		assert_eq!(get_text(&options, &now, Some(TIME + 300_000)), (String::from("05:00"), false));
+		// a countdown that ended a while ago stays at zero
+		assert_eq!(get_text(&options, &now, Some(TIME - 5_000)), (String::from("00:00"), true));
+	}
+
+	#[test]
+	fn align_text_works() {
+		let options = Options::default();
+		assert_eq!(align_text("ab", 5, &Align::Center, &options), "  ab");
+		assert_eq!(align_text("ab", 5, &Align::Right, &options), "   ab");
+		assert_eq!(align_text("ab", 5, &Align::Top, &options), "ab");
+		// output wider than the terminal stays where it is
+		assert_eq!(align_text("abcdef", 5, &Align::Center, &options), "abcdef");
+		// escape sequences don't count
+		assert_eq!(align_text("\x1b[31mab\x1b[39m\n", 4, &Align::Right, &options), "  \x1b[31mab\x1b[39m\n  ");
+	}
+
+	#[test]
+	fn display_draw_works() {
+		let mut display = Display::default();
+		let mut output = Vec::new();
+
+		display.draw("a\nb\nc", &mut output).unwrap();
+		assert_eq!(String::from_utf8(output.clone()).unwrap(), "a\nb\nc");
+
+		// nothing changed so nothing is drawn
+		output.clear();
+		display.draw("a\nb\nc", &mut output).unwrap();
+		assert_eq!(String::from_utf8(output.clone()).unwrap(), "");
+
+		// only the changed rows are drawn
+		output.clear();
+		display.draw("a\nx\ny", &mut output).unwrap();
+		assert_eq!(
+			String::from_utf8(output.clone()).unwrap(),
+			"\r\x1b[1A\x1b[0m\x1b[2Kx\x1b[0m\x1b[1B\r\x1b[0m\x1b[2Ky\x1b[0m"
+		);
+
+		// a different number of rows is drawn from scratch
+		output.clear();
+		display.draw("z", &mut output).unwrap();
+		assert_eq!(String::from_utf8(output.clone()).unwrap(), "\r\x1b[2A\x1b[Jz");
+
+		output.clear();
+		display.clear(&mut output).unwrap();
+		display.draw("z", &mut output).unwrap();
+		assert_eq!(String::from_utf8(output).unwrap(), "\r\x1b[Jz");
+	}
+
+	#[test]
+	fn display_draw_keeps_colors() {
+		let mut display = Display::default();
+		let mut output = Vec::new();
+
+		display.draw("\x1b[44m\n\x1b[31ma\x1b[39m\n\x1b[31mb\x1b[39m\x1b[49m", &mut output).unwrap();
+		output.clear();
+		display.draw("\x1b[44m\n\x1b[31ma\x1b[39m\n\x1b[31mc\x1b[39m\x1b[49m", &mut output).unwrap();
+
+		// the row starts with the escape sequences of the rows above so the background stays
+		assert_eq!(
+			String::from_utf8(output).unwrap(),
+			"\r\x1b[0m\x1b[2K\x1b[44m\x1b[31m\x1b[39m\x1b[31mc\x1b[39m\x1b[49m\x1b[0m"
+		);
+	}
+
+	#[test]
+	fn run_countdown_works() {
+		let stop = AtomicBool::new(false);
+		let width = Cell::new(80);
+		let mut clock = FakeClock {
+			time: TIME + 250,
+			stop_at: None,
+			stop: &stop,
+			resize_at: None,
+			width: &width,
+		};
+		let mut output = Vec::new();
+
+		let options = Options {
+			countdown: Some(3_000),
+			..options()
+		};
+		assert!(!run(&options, &mut clock, || width.get(), &mut output, &stop).unwrap());
+		assert_eq!(
+			String::from_utf8(output).unwrap(),
+			format!(
+				"{}00:03{}{}{}{}\n",
+				HIDE_CURSOR,
+				"\r\x1b[0m\x1b[2K00:02\x1b[0m",
+				"\r\x1b[0m\x1b[2K00:01\x1b[0m",
+				"\r\x1b[0m\x1b[2K00:00\x1b[0m",
+				SHOW_CURSOR
+			)
+		);
+		// the countdown ends exactly when its time is up
+		assert_eq!(clock.time, TIME + 3_250);
+	}
+
+	#[test]
+	fn run_countdown_works_with_huge_durations() {
+		let stop = AtomicBool::new(false);
+		let width = Cell::new(80);
+		let mut clock = FakeClock {
+			time: TIME,
+			stop_at: Some(TIME + 1_000),
+			stop: &stop,
+			resize_at: None,
+			width: &width,
+		};
+		let mut output = Vec::new();
+
+		let options = Options {
+			countdown: Some(u64::MAX),
+			..options()
+		};
+		assert!(run(&options, &mut clock, || width.get(), &mut output, &stop).unwrap());
+		assert!(String::from_utf8(output).unwrap().starts_with(HIDE_CURSOR));
+	}
+
+	#[test]
+	fn run_clock_works() {
+		let stop = AtomicBool::new(false);
+		let width = Cell::new(80);
+		let mut clock = FakeClock {
+			time: TIME + 500,
+			stop_at: Some(TIME + 2_000),
+			stop: &stop,
+			resize_at: None,
+			width: &width,
+		};
+		let mut output = Vec::new();
+
+		let options = Options {
+			clock: Some(String::from(DEFAULT_CLOCK_FORMAT)),
+			..options()
+		};
+		assert!(run(&options, &mut clock, || width.get(), &mut output, &stop).unwrap());
+		assert_eq!(
+			String::from_utf8(output).unwrap(),
+			format!("{}12:34:56\r\x1b[0m\x1b[2K12:34:57\x1b[0m{}\n", HIDE_CURSOR, SHOW_CURSOR)
+		);
+	}
+
+	#[test]
+	fn run_clock_follows_resize() {
+		let stop = AtomicBool::new(false);
+		let width = Cell::new(10);
+		let mut clock = FakeClock {
+			time: TIME,
+			stop_at: Some(TIME + 1_000),
+			stop: &stop,
+			resize_at: Some((TIME + 500, 20)),
+			width: &width,
+		};
+		let mut output = Vec::new();
+
+		let options = Options {
+			clock: Some(String::from(DEFAULT_CLOCK_FORMAT)),
+			align: Align::Center,
+			..options()
+		};
+		assert!(run(&options, &mut clock, || width.get(), &mut output, &stop).unwrap());
+		assert_eq!(
+			String::from_utf8(output).unwrap(),
+			format!("{} 12:34:56\r\x1b[J      12:34:56{}\n", HIDE_CURSOR, SHOW_CURSOR)
+		);
+	}
+}
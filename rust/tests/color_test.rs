@@ -1,11 +1,12 @@
 extern crate cfonts;
 
 use cfonts::color::{
-	bg_color, bgcolor2hex, color, color2hex, get_background_color, get_foreground_color, get_term_color_support, hex2rgb,
-	hex2rsv, hsv2rgb, hsv2rsv, rgb2ansi_16, rgb2ansi_16m, rgb2ansi_256, rgb2hex, rgb2hsv, rgb_u8_2ansi_256, rsv2hex,
-	rsv2hsv, ColorLayer, Hsv, Rgb, Rsv, TermColorSupport,
+	bg_color, bgcolor2hex, color, color2hex, get_background_color, get_background_color_for, get_candy_color,
+	get_color_support, get_foreground_color, get_foreground_color_for, get_term_color_support, hex2rgb, hex2rsv, hsv2rgb,
+	hsv2rsv, resolve_color_mode, rgb2ansi_16, rgb2ansi_16m, rgb2ansi_256, rgb2hex, rgb2hsv, rgb_u8_2ansi_256, rsv2hex,
+	rsv2hsv, simulate_rgb, CandyPosition, ColorLayer, Hsv, Rgb, Rsv, TermColorSupport, CANDY_COLORS, LIGHT_CANDY_COLORS,
 };
-use cfonts::config::{BgColors, Colors, Options};
+use cfonts::config::{BgColors, CandyMode, ColorMode, Colors, Cvd, Options, TerminalBg};
 
 #[cfg(test)]
 mod color {
@@ -287,6 +288,263 @@ mod color {
 		.contains(&get_term_color_support()));
 	}
 
+	#[test]
+	fn simulate_rgb_works() {
+		let mut options = Options::default();
+		assert_eq!(simulate_rgb(&Rgb::Val(12, 34, 56), &options), Rgb::Val(12, 34, 56));
+
+		for cvd in [Cvd::Protanopia, Cvd::Deuteranopia, Cvd::Tritanopia, Cvd::Achromatopsia] {
+			options.simulate = Some(cvd);
+			// black and white stay the same for everyone
+			assert_eq!(simulate_rgb(&Rgb::Val(0, 0, 0), &options), Rgb::Val(0, 0, 0));
+			assert_eq!(simulate_rgb(&Rgb::Val(255, 255, 255), &options), Rgb::Val(255, 255, 255));
+		}
+
+		options.simulate = Some(Cvd::Protanopia);
+		assert_eq!(simulate_rgb(&Rgb::Val(255, 0, 0), &options), Rgb::Val(109, 95, 0));
+
+		options.simulate = Some(Cvd::Deuteranopia);
+		let red = simulate_rgb(&Rgb::Val(255, 0, 0), &options);
+		let green = simulate_rgb(&Rgb::Val(0, 128, 0), &options);
+		let (red_r, red_g, _) = red.get_value();
+		let (green_r, green_g, _) = green.get_value();
+		// red and green end up in the same corner of the color space
+		assert!((red_r as i16 - red_g as i16).abs() < 60);
+		assert!((green_r as i16 - green_g as i16).abs() < 60);
+
+		options.simulate = Some(Cvd::Achromatopsia);
+		let (r, g, b) = simulate_rgb(&Rgb::Val(30, 144, 255), &options).get_value();
+		assert_eq!(r, g);
+		assert_eq!(g, b);
+	}
+
+	#[test]
+	fn simulate_is_applied_to_all_colors() {
+		let options = Options {
+			simulate: Some(Cvd::Achromatopsia),
+			color_mode: ColorMode::Truecolor,
+			..Options::default()
+		};
+
+		assert_eq!(color2hex(&Colors::Red, &options), "#7b7b7b");
+		assert_eq!(color2hex(&Colors::System, &options), "transparent");
+		assert_eq!(bgcolor2hex(&BgColors::Rgb(Rgb::Val(255, 0, 0)), &options), "#7f7f7f");
+		assert_eq!(bgcolor2hex(&BgColors::Transparent, &options), "transparent");
+
+		assert_eq!(
+			get_foreground_color_for(&Colors::Red, &options),
+			(String::from("\x1b[38;2;123;123;123m"), String::from("\x1b[39m"))
+		);
+		assert_eq!(
+			get_foreground_color_for(&Colors::Rgb(Rgb::Val(255, 0, 0)), &options),
+			(String::from("\x1b[38;2;127;127;127m"), String::from("\x1b[39m"))
+		);
+		assert_eq!(
+			get_foreground_color_for(&Colors::System, &options),
+			(String::from("\x1b[39m"), String::from("\x1b[39m"))
+		);
+		assert_eq!(
+			get_background_color_for(&BgColors::Red, &options),
+			(String::from("\x1b[48;2;123;123;123m"), String::from("\x1b[49m"))
+		);
+		assert_eq!(
+			get_background_color_for(&BgColors::Transparent, &options),
+			(String::from("\x1b[49m"), String::from("\x1b[49m"))
+		);
+
+		let (start, _) = get_foreground_color_for(
+			&Colors::Candy,
+			&Options {
+				seed: Some(1),
+				..options.clone()
+			},
+		);
+		let rgb = start.trim_start_matches("\x1b[38;2;").trim_end_matches('m').split(';').collect::<Vec<&str>>();
+		assert_eq!(rgb[0], rgb[1]);
+		assert_eq!(rgb[1], rgb[2]);
+
+		let options = Options {
+			color_mode: ColorMode::NoColor,
+			..options
+		};
+		assert_eq!(get_foreground_color_for(&Colors::Red, &options), (String::from(""), String::from("")));
+	}
+
+	#[test]
+	fn get_candy_color_respects_terminal_bg() {
+		let mut options = Options {
+			seed: Some(7),
+			terminal_bg: TerminalBg::Light,
+			..Options::default()
+		};
+
+		for letter in 0..50 {
+			let position = CandyPosition {
+				letter,
+				..CandyPosition::default()
+			};
+			assert!(LIGHT_CANDY_COLORS.contains(&get_candy_color(&position, &options)));
+		}
+
+		options.terminal_bg = TerminalBg::Dark;
+		let dark = get_candy_color(&CandyPosition::default(), &options);
+		options.terminal_bg = TerminalBg::Auto;
+		assert_eq!(get_candy_color(&CandyPosition::default(), &options), dark);
+	}
+
+	#[test]
+	fn get_candy_color_works() {
+		let mut options = Options::default();
+		let position = CandyPosition {
+			letter: 2,
+			line: 1,
+			row: 3,
+			layer: 1,
+		};
+
+		assert!(CANDY_COLORS.contains(&get_candy_color(&position, &options)));
+
+		options.seed = Some(42);
+		for mode in [CandyMode::Segment, CandyMode::Glyph, CandyMode::Row, CandyMode::Layer] {
+			options.candy_mode = mode;
+			let color = get_candy_color(&position, &options);
+			assert!(CANDY_COLORS.contains(&color));
+			assert_eq!(get_candy_color(&position, &options), color);
+		}
+
+		// glyph mode ignores everything but the letter
+		options.candy_mode = CandyMode::Glyph;
+		assert_eq!(
+			get_candy_color(&position, &options),
+			get_candy_color(
+				&CandyPosition {
+					letter: 2,
+					..CandyPosition::default()
+				},
+				&options
+			)
+		);
+
+		// row mode ignores the letter and the layer
+		options.candy_mode = CandyMode::Row;
+		assert_eq!(
+			get_candy_color(&position, &options),
+			get_candy_color(
+				&CandyPosition {
+					line: 1,
+					row: 3,
+					..CandyPosition::default()
+				},
+				&options
+			)
+		);
+
+		// layer mode ignores everything but the layer
+		options.candy_mode = CandyMode::Layer;
+		assert_eq!(
+			get_candy_color(&position, &options),
+			get_candy_color(
+				&CandyPosition {
+					layer: 1,
+					..CandyPosition::default()
+				},
+				&options
+			)
+		);
+	}
+
+	#[test]
+	fn resolve_color_mode_works() {
+		let mut options = Options::default();
+
+		temp_env::with_var("FORCE_COLOR", Some("0"), || {
+			assert_eq!(resolve_color_mode(&options), ColorMode::NoColor);
+		});
+		temp_env::with_var("FORCE_COLOR", Some("1"), || {
+			assert_eq!(resolve_color_mode(&options), ColorMode::Ansi16);
+		});
+		temp_env::with_var("FORCE_COLOR", Some("2"), || {
+			assert_eq!(resolve_color_mode(&options), ColorMode::Ansi256);
+		});
+		temp_env::with_var("FORCE_COLOR", Some("3"), || {
+			assert_eq!(resolve_color_mode(&options), ColorMode::Truecolor);
+		});
+
+		options.color_mode = ColorMode::Ansi16;
+		temp_env::with_var("FORCE_COLOR", Some("3"), || {
+			assert_eq!(resolve_color_mode(&options), ColorMode::Ansi16);
+		});
+	}
+
+	#[test]
+	fn get_color_support_works() {
+		let mut options = Options::default();
+
+		temp_env::with_var("FORCE_COLOR", Some("2"), || {
+			assert_eq!(get_color_support(&options), TermColorSupport::Ansi256);
+		});
+
+		options.color_mode = ColorMode::NoColor;
+		assert_eq!(get_color_support(&options), TermColorSupport::NoColor);
+		options.color_mode = ColorMode::Ansi16;
+		assert_eq!(get_color_support(&options), TermColorSupport::Ansi16);
+		options.color_mode = ColorMode::Ansi256;
+		assert_eq!(get_color_support(&options), TermColorSupport::Ansi256);
+		options.color_mode = ColorMode::Truecolor;
+		assert_eq!(get_color_support(&options), TermColorSupport::Ansi16m);
+	}
+
+	#[test]
+	fn get_foreground_color_for_works() {
+		let mut options = Options::default();
+		let rgb = Colors::Rgb(Rgb::Val(157, 5, 98));
+
+		temp_env::with_vars(vec![("NO_COLOR", Some("")), ("FORCE_COLOR", None)], || {
+			options.color_mode = ColorMode::Truecolor;
+			assert_eq!(
+				get_foreground_color_for(&rgb, &options),
+				(String::from("\x1b[38;2;157;5;98m"), String::from("\x1b[39m"))
+			);
+			options.color_mode = ColorMode::Ansi256;
+			assert_eq!(get_foreground_color_for(&rgb, &options), (String::from("\x1b[38;5;126m"), String::from("\x1b[39m")));
+			options.color_mode = ColorMode::Ansi16;
+			assert_eq!(get_foreground_color_for(&rgb, &options), (String::from("\x1b[31m"), String::from("\x1b[39m")));
+			assert_eq!(
+				get_foreground_color_for(&Colors::Red, &options),
+				(String::from("\x1b[31m"), String::from("\x1b[39m"))
+			);
+		});
+
+		temp_env::with_var("FORCE_COLOR", Some("3"), || {
+			options.color_mode = ColorMode::NoColor;
+			assert_eq!(get_foreground_color_for(&rgb, &options), (String::from(""), String::from("")));
+			assert_eq!(get_foreground_color_for(&Colors::Red, &options), (String::from(""), String::from("")));
+		});
+	}
+
+	#[test]
+	fn get_background_color_for_works() {
+		let mut options = Options::default();
+		let rgb = BgColors::Rgb(Rgb::Val(157, 5, 98));
+
+		temp_env::with_vars(vec![("NO_COLOR", Some("")), ("FORCE_COLOR", None)], || {
+			options.color_mode = ColorMode::Truecolor;
+			assert_eq!(
+				get_background_color_for(&rgb, &options),
+				(String::from("\x1b[48;2;157;5;98m"), String::from("\x1b[49m"))
+			);
+			options.color_mode = ColorMode::Ansi256;
+			assert_eq!(get_background_color_for(&rgb, &options), (String::from("\x1b[48;5;126m"), String::from("\x1b[49m")));
+			options.color_mode = ColorMode::Ansi16;
+			assert_eq!(get_background_color_for(&rgb, &options), (String::from("\x1b[41m"), String::from("\x1b[49m")));
+		});
+
+		temp_env::with_var("FORCE_COLOR", Some("3"), || {
+			options.color_mode = ColorMode::NoColor;
+			assert_eq!(get_background_color_for(&rgb, &options), (String::from(""), String::from("")));
+		});
+	}
+
 	#[test]
 	fn get_foreground_color_works_without_no_color() {
 		temp_env::with_var_unset("NO_COLOR", || {
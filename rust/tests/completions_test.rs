@@ -0,0 +1,147 @@
+extern crate cfonts;
+
+use cfonts::completions::{generate, Completion, Completions};
+use cfonts::config::{Options, Shell, CLIOPTIONS};
+
+#[cfg(test)]
+mod completions {
+	use super::*;
+
+	fn completions() -> Completions {
+		Completions {
+			gradients: vec![String::from("pride"), String::from("sunset")],
+			themes: vec![String::from("pride"), String::from("o'neil")],
+		}
+	}
+
+	fn option(key: &str) -> &'static cfonts::config::CliOption<'static> {
+		CLIOPTIONS.iter().find(|option| option.key == key).unwrap()
+	}
+
+	#[test]
+	fn get_works() {
+		let completions = completions();
+
+		assert_eq!(completions.get(option("version")), Completion::Flag);
+		assert_eq!(completions.get(option("letter_spacing")), Completion::Any);
+		assert_eq!(completions.get(option("duration")), Completion::Any);
+		assert_eq!(completions.get(option("clock")), Completion::Optional);
+		assert_eq!(completions.get(option("output")), Completion::File);
+		assert_eq!(completions.get(option("env")), Completion::Values(vec![String::from("cli"), String::from("browser")]));
+		assert_eq!(
+			completions.get(option("gradient")),
+			Completion::Values(vec![String::from("pride"), String::from("sunset")])
+		);
+		assert_eq!(
+			completions.get(option("completions")),
+			Completion::Values(vec![
+				String::from("bash"),
+				String::from("zsh"),
+				String::from("fish"),
+				String::from("elvish"),
+				String::from("powershell"),
+			])
+		);
+	}
+
+	#[test]
+	fn get_leaves_out_descriptions() {
+		match completions().get(option("colors")) {
+			Completion::Values(values) => {
+				assert!(values.contains(&String::from("redBright")));
+				assert!(values.contains(&String::from("candy")));
+				assert!(values.iter().all(|value| !value.contains(' ')));
+			}
+			other => panic!("Expected values but got {:?}", other),
+		}
+	}
+
+	#[test]
+	fn bash_works() {
+		let script = completions().bash();
+
+		assert!(script.starts_with("_cfonts() {\n"));
+		assert!(script.ends_with("complete -F _cfonts cfonts\n"));
+		assert!(script
+			.contains("\t\t-a|--align)\n\t\t\tCOMPREPLY=($(compgen -W 'left center right top bottom' -- \"${cur}\"))\n"));
+		assert!(script.contains("\t\t-g|--gradient)\n\t\t\tCOMPREPLY=($(compgen -W 'pride sunset' -- \"${cur}\"))\n"));
+		assert!(script.contains("\t\t--theme)\n\t\t\tCOMPREPLY=($(compgen -W 'pride o'\\''neil' -- \"${cur}\"))\n"));
+		assert!(script.contains("\t\t--output)\n\t\t\tCOMPREPLY=($(compgen -f -- \"${cur}\"))\n"));
+		assert!(script.contains("-l|--letter-spacing|"));
+		assert!(script.contains("'-v -V --version -h --help -f --font"));
+		assert!(!script.contains("--clock)"));
+	}
+
+	#[test]
+	fn zsh_works() {
+		let script = completions().zsh();
+
+		assert!(script.starts_with("#compdef cfonts\n"));
+		assert!(script.contains("\t\t'(-v -V --version)'{-v,-V,--version}'[Use to display the version of cfonts]' \\\n"));
+		assert!(script.contains("{-a,--align}'[Use to align your text output]:align:(left center right top bottom)' \\\n"));
+		assert!(script.contains("{-l,--letter-spacing}'[Use to define your letter spacing]:letter spacing:' \\\n"));
+		assert!(
+			script.contains("\t\t'--output[Use to write the output into a file instead of the console]:output:_files' \\\n")
+		);
+		assert!(script.contains(":clock:' \\\n"));
+		assert!(script.contains("'--clock["));
+		assert!(script.contains("\t\t'1:text:'\n"));
+	}
+
+	#[test]
+	fn fish_works() {
+		let script = completions().fish();
+
+		assert!(script.starts_with("complete -c cfonts -f\n"));
+		assert!(script.contains("complete -c cfonts -s v -s V -l version -d 'Use to display the version of cfonts'\n"));
+		assert!(script.contains(
+			"complete -c cfonts -s a -l align -d 'Use to align your text output' -x -a 'left center right top bottom'\n"
+		));
+		assert!(script.contains("complete -c cfonts -l theme -d 'Use to apply a theme from your themes folder, a theme file or one of the built-in themes' -x -a 'pride o\\'neil'\n"));
+		assert!(script.contains("-l letter-spacing -d 'Use to define your letter spacing' -x\n"));
+		assert!(script.contains("-l output -d 'Use to write the output into a file instead of the console' -r -F\n"));
+	}
+
+	#[test]
+	fn elvish_works() {
+		let script = completions().elvish();
+
+		assert!(script.contains("set edit:completion:arg-completer[cfonts] = {|@words|\n"));
+		assert!(script.contains("\tvar files = ['--output']\n"));
+		assert!(script.contains("\t\t&'-a'=['left' 'center' 'right' 'top' 'bottom']\n"));
+		assert!(script.contains("\t\t&'--theme'=['pride' 'o''neil']\n"));
+		assert!(script.contains("\t\t&'--letter-spacing'=[]\n"));
+		assert!(!script.contains("&'--clock'"));
+		assert!(script.contains("'--debug' '-x' '--debug-level']\n"));
+	}
+
+	#[test]
+	fn powershell_works() {
+		let script = completions().powershell();
+
+		assert!(script.starts_with("Register-ArgumentCompleter -Native -CommandName 'cfonts' -ScriptBlock {\n"));
+		assert!(script.contains("\t$files = @('--output')\n"));
+		assert!(script.contains("\t\t'--align' = @('left', 'center', 'right', 'top', 'bottom')\n"));
+		assert!(script.contains("\t\t'--theme' = @('pride', 'o''neil')\n"));
+		assert!(script.contains("\t\t'-l' = @()\n"));
+		assert!(!script.contains("'-v' = "));
+	}
+
+	#[test]
+	fn generate_works() {
+		let options = Options::default();
+		temp_env::with_var("XDG_CONFIG_HOME", Some("/no/such/dir"), || {
+			for (shell, start) in [
+				(Shell::Bash, "_cfonts() {"),
+				(Shell::Zsh, "#compdef cfonts"),
+				(Shell::Fish, "complete -c cfonts -f"),
+				(Shell::Elvish, "use str"),
+				(Shell::Powershell, "Register-ArgumentCompleter"),
+			] {
+				let script = generate(&shell, &options).unwrap();
+				assert!(script.starts_with(start));
+				assert!(script.contains("pride"));
+			}
+		});
+	}
+}
@@ -1,6 +1,10 @@
 extern crate cfonts;
 
-use cfonts::config::{Align, BgColors, Colors, Env, Fonts, OptionType, Options, CLIOPTIONS};
+use cfonts::color::Rgb;
+use cfonts::config::{
+	Align, BgColors, ColorMode, Colors, Cvd, Effect, Env, Fonts, OptionType, Options, OutputFormat, TerminalBg,
+	CLIOPTIONS,
+};
 
 #[cfg(test)]
 mod tests {
@@ -13,11 +17,62 @@ mod tests {
 		let _bgcolors = BgColors::Transparent.clone();
 		let _env = Env::Cli.clone();
 		let _align = Align::Top.clone();
+		let _color_mode = ColorMode::Auto.clone();
 		let options = Options::default();
 		let options2 = options.clone();
 		assert!(options == options2);
 	}
 
+	#[test]
+	fn color_mode_list_works() {
+		assert_eq!(ColorMode::list(), "auto, none, 16, 256, truecolor");
+	}
+
+	#[test]
+	fn cvd_list_works() {
+		assert_eq!(Cvd::list(), "protanopia, deuteranopia, tritanopia, achromatopsia");
+	}
+
+	#[test]
+	fn terminal_bg_list_works() {
+		assert_eq!(TerminalBg::list(), "auto, light, dark");
+	}
+
+	#[test]
+	fn output_format_list_works() {
+		assert_eq!(OutputFormat::list(), "text, html, json, plain");
+	}
+
+	#[test]
+	fn effect_list_works() {
+		assert_eq!(Effect::list(), "typewriter, lines, wipe, cycle");
+	}
+
+	#[test]
+	fn options_serde_works() {
+		let options = Options {
+			text: String::from("hi"),
+			font: Fonts::FontChrome,
+			colors: vec![Colors::Red, Colors::Rgb(Rgb::Val(1, 2, 3))],
+			background: BgColors::Blue,
+			simulate: Some(Cvd::Tritanopia),
+			output_format: OutputFormat::Json,
+			..Options::default()
+		};
+		let json = serde_json::to_string(&options).unwrap();
+
+		assert!(json.contains("\"font\":\"FontChrome\""));
+		assert_eq!(serde_json::from_str::<Options>(&json).unwrap(), options);
+		// missing fields fall back to their default
+		assert_eq!(
+			serde_json::from_str::<Options>("{\"text\":\"hi\"}").unwrap(),
+			Options {
+				text: String::from("hi"),
+				..Options::default()
+			}
+		);
+	}
+
 	#[test]
 	fn equality_works() {
 		assert!(OptionType::Text == OptionType::Text);
@@ -0,0 +1,101 @@
+extern crate cfonts;
+
+use cfonts::color::Rgb;
+use cfonts::config::{BgColors, Colors, Options, TerminalBg};
+use cfonts::contrast::{check_contrast, contrast_ratio, relative_luminance, MIN_CONTRAST_RATIO};
+
+#[cfg(test)]
+mod contrast {
+	use super::*;
+
+	#[test]
+	fn relative_luminance_works() {
+		assert_eq!(relative_luminance(&Rgb::Val(0, 0, 0)), 0.0);
+		assert_eq!(relative_luminance(&Rgb::Val(255, 255, 255)), 1.0);
+		assert!((relative_luminance(&Rgb::Val(255, 0, 0)) - 0.2126).abs() < 0.0001);
+		assert!((relative_luminance(&Rgb::Val(128, 128, 128)) - 0.2158).abs() < 0.0001);
+	}
+
+	#[test]
+	fn contrast_ratio_works() {
+		assert_eq!(contrast_ratio(&Rgb::Val(0, 0, 0), &Rgb::Val(255, 255, 255)), 21.0);
+		assert_eq!(contrast_ratio(&Rgb::Val(255, 255, 255), &Rgb::Val(0, 0, 0)), 21.0);
+		assert_eq!(contrast_ratio(&Rgb::Val(100, 100, 100), &Rgb::Val(100, 100, 100)), 1.0);
+		// #777777 on white is the classic just-below-4.5 example
+		assert!((contrast_ratio(&Rgb::Val(119, 119, 119), &Rgb::Val(255, 255, 255)) - 4.48).abs() < 0.01);
+	}
+
+	#[test]
+	fn check_contrast_works_with_colors() {
+		let options = Options {
+			colors: vec![Colors::Blue, Colors::White, Colors::Rgb(Rgb::Val(0, 0, 250))],
+			background: BgColors::Blue,
+			..Options::default()
+		};
+		let issues = check_contrast(&options);
+		assert_eq!(issues.len(), 2);
+		assert!(issues[0].contains("blue"));
+		assert!(issues[1].contains("#0000fa"));
+		assert!(issues.iter().all(|issue| issue.contains(&format!("{}:1", MIN_CONTRAST_RATIO))));
+	}
+
+	#[test]
+	fn check_contrast_skips_unknown_colors() {
+		let options = Options {
+			colors: vec![Colors::System, Colors::Candy],
+			background: BgColors::Black,
+			..Options::default()
+		};
+		assert!(check_contrast(&options).is_empty());
+
+		let options = Options {
+			colors: vec![Colors::Black],
+			..Options::default()
+		};
+		assert!(check_contrast(&options).is_empty());
+	}
+
+	#[test]
+	fn check_contrast_works_with_terminal_bg() {
+		let options = Options {
+			colors: vec![Colors::Yellow, Colors::Black],
+			terminal_bg: TerminalBg::Light,
+			..Options::default()
+		};
+		let issues = check_contrast(&options);
+		assert_eq!(issues.len(), 1);
+		assert!(issues[0].contains("yellow"));
+		assert!(issues[0].contains("light terminal"));
+
+		// black and white are swapped for readable colors when rendering
+		let options = Options {
+			terminal_bg: TerminalBg::Dark,
+			..options
+		};
+		assert!(check_contrast(&options).is_empty());
+
+		let options = Options {
+			colors: vec![Colors::White],
+			terminal_bg: TerminalBg::Light,
+			..options
+		};
+		assert!(check_contrast(&options).is_empty());
+	}
+
+	#[test]
+	fn check_contrast_works_with_gradients() {
+		let options = Options {
+			colors: vec![Colors::Black],
+			gradient: vec![
+				String::from("#ffffff"),
+				String::from("#000011"),
+				String::from("#000011"),
+			],
+			background: BgColors::Black,
+			..Options::default()
+		};
+		let issues = check_contrast(&options);
+		assert_eq!(issues.len(), 1);
+		assert!(issues[0].contains("#000011"));
+	}
+}
@@ -0,0 +1,313 @@
+extern crate cfonts;
+
+use std::path::PathBuf;
+
+use cfonts::color::Rgb;
+use cfonts::config::{Align, Border, Colors, Fonts, Options};
+use cfonts::defaults::{
+	apply_layers, find_project_file, get_value, load_layers, print_config, read_env, read_file, track_changes, Layer,
+	Source, Sources,
+};
+
+#[cfg(test)]
+mod defaults {
+	use super::*;
+
+	fn temp_dir(name: &str) -> PathBuf {
+		let dir = std::env::temp_dir().join(format!("cfonts-defaults-{}-test-{}", name, std::process::id()));
+		let _ = std::fs::remove_dir_all(&dir);
+		std::fs::create_dir_all(&dir).unwrap();
+		dir
+	}
+
+	fn layer(source: Source, values: Vec<(&str, &str)>) -> Layer {
+		Layer {
+			source,
+			values: values.into_iter().map(|(key, value)| (key.to_string(), value.to_string())).collect(),
+		}
+	}
+
+	#[test]
+	fn read_file_works() {
+		let dir = temp_dir("read-file");
+		let path = dir.join("config.toml");
+		std::fs::write(&path, "font = \"tiny\"\ncolors = [\"red\", \"#ff8800\"]\nletter-spacing = 2\nspaceless = true\n")
+			.unwrap();
+
+		let layer = read_file(&path, Source::User(path.clone()), &Options::default()).unwrap();
+		assert_eq!(layer.source, Source::User(path.clone()));
+		assert_eq!(
+			layer.values,
+			vec![
+				(String::from("colors"), String::from("red,#ff8800")),
+				(String::from("font"), String::from("tiny")),
+				(String::from("letter_spacing"), String::from("2")),
+				(String::from("spaceless"), String::from("true")),
+			]
+		);
+
+		let _ = std::fs::remove_dir_all(&dir);
+	}
+
+	#[test]
+	fn read_file_errors() {
+		let dir = temp_dir("read-file-errors");
+		let path = dir.join("config.toml");
+
+		std::fs::write(&path, "fnot = \"tiny\"\n").unwrap();
+		let error = read_file(&path, Source::User(path.clone()), &Options::default()).unwrap_err();
+		assert!(error.contains("fnot"));
+		assert!(error.contains("letter_spacing"));
+
+		std::fs::write(&path, "version = true\n").unwrap();
+		assert!(read_file(&path, Source::User(path.clone()), &Options::default()).is_err());
+
+		std::fs::write(&path, "font = { name = \"tiny\" }\n").unwrap();
+		assert!(read_file(&path, Source::User(path.clone()), &Options::default()).is_err());
+
+		std::fs::write(&path, "font = \n").unwrap();
+		assert!(read_file(&path, Source::User(path.clone()), &Options::default()).is_err());
+
+		let _ = std::fs::remove_dir_all(&dir);
+	}
+
+	#[test]
+	fn read_file_rejects_options_with_side_effects() {
+		let dir = temp_dir("side-effects");
+		let path = dir.join(".cfontsrc");
+
+		std::fs::write(&path, "output = \"hijacked.png\"\n").unwrap();
+		let error = read_file(&path, Source::Project(path.clone()), &Options::default()).unwrap_err();
+		assert!(error.contains("output"));
+		assert!(error.contains(".cfontsrc"));
+
+		for content in [
+			"clock = \"HH:mm\"",
+			"countdown = \"5m\"",
+			"animate = \"wipe\"",
+			"marquee = true",
+			"lenient = true",
+		] {
+			std::fs::write(&path, content).unwrap();
+			assert!(read_file(&path, Source::Project(path.clone()), &Options::default()).is_err());
+		}
+
+		let layer = read_env(vec![(String::from("CFONTS_OUTPUT"), String::from("hijacked.png"))]);
+		assert!(layer.values.is_empty());
+
+		let _ = std::fs::remove_dir_all(&dir);
+	}
+
+	#[test]
+	fn read_env_works() {
+		let layer = read_env(vec![
+			(String::from("CFONTS_ALIGN"), String::from("center")),
+			(String::from("CFONTS_SPACELESS"), String::from("true")),
+			(String::from("CFONTS_VERSION"), String::from("true")),
+			(String::from("CFONTS_UNKNOWN"), String::from("true")),
+			(String::from("FONT"), String::from("tiny")),
+		]);
+
+		assert_eq!(
+			layer.values,
+			vec![
+				(String::from("align"), String::from("center")),
+				(String::from("spaceless"), String::from("true")),
+			]
+		);
+	}
+
+	#[test]
+	fn find_project_file_works() {
+		let dir = temp_dir("project");
+		let nested = dir.join("a").join("b");
+		std::fs::create_dir_all(&nested).unwrap();
+
+		assert_eq!(find_project_file(&nested), None);
+
+		std::fs::write(dir.join(".cfontsrc"), "font = \"tiny\"\n").unwrap();
+		assert_eq!(find_project_file(&nested), Some(dir.join(".cfontsrc")));
+
+		std::fs::write(dir.join("a").join(".cfontsrc"), "font = \"chrome\"\n").unwrap();
+		assert_eq!(find_project_file(&nested), Some(dir.join("a").join(".cfontsrc")));
+
+		let _ = std::fs::remove_dir_all(&dir);
+	}
+
+	#[test]
+	fn load_layers_works() {
+		let dir = temp_dir("load-layers");
+		std::fs::create_dir_all(dir.join("config").join("cfonts")).unwrap();
+		std::fs::create_dir_all(dir.join("project")).unwrap();
+		std::fs::write(dir.join("config").join("cfonts").join("config.toml"), "font = \"tiny\"\n").unwrap();
+		std::fs::write(dir.join("project").join(".cfontsrc"), "align = \"center\"\n").unwrap();
+
+		temp_env::with_vars(
+			vec![
+				("XDG_CONFIG_HOME", Some(dir.join("config").as_os_str())),
+				("CFONTS_SPACELESS", Some("true".as_ref())),
+			],
+			|| {
+				let layers = load_layers(&dir.join("project"), &Options::default()).unwrap();
+				assert_eq!(layers.len(), 3);
+				assert_eq!(
+					layers[0],
+					layer(Source::User(dir.join("config").join("cfonts").join("config.toml")), vec![("font", "tiny")])
+				);
+				assert_eq!(layers[1], layer(Source::Project(dir.join("project").join(".cfontsrc")), vec![("align", "center")]));
+				assert_eq!(layers[2], layer(Source::Env, vec![("spaceless", "true")]));
+			},
+		);
+
+		let _ = std::fs::remove_dir_all(&dir);
+	}
+
+	#[test]
+	fn apply_layers_precedence_works() {
+		let user = PathBuf::from("user.toml");
+		let project = PathBuf::from(".cfontsrc");
+		let layers = vec![
+			layer(Source::User(user.clone()), vec![("font", "tiny"), ("align", "right"), ("colors", "red")]),
+			layer(Source::Project(project.clone()), vec![("font", "chrome"), ("align", "center")]),
+			layer(Source::Env, vec![("align", "left"), ("spaceless", "true")]),
+		];
+
+		let (options, sources) = apply_layers(&layers, Options::default()).unwrap();
+		assert_eq!(options.font, Fonts::FontChrome);
+		assert_eq!(options.align, Align::Left);
+		assert_eq!(options.colors, vec![Colors::Red]);
+		assert!(options.spaceless);
+
+		assert_eq!(sources.get("font"), Some(&Source::Project(project)));
+		assert_eq!(sources.get("colors"), Some(&Source::User(user)));
+		assert_eq!(sources.get("align"), Some(&Source::Env));
+		assert_eq!(sources.get("spaceless"), Some(&Source::Env));
+		assert_eq!(sources.get("line_height"), None);
+	}
+
+	#[test]
+	fn apply_layers_console_works() {
+		let (options, _) = apply_layers(&[layer(Source::Env, vec![("font", "console")])], Options::default()).unwrap();
+		assert_eq!(options.line_height, 0);
+
+		let (options, _) =
+			apply_layers(&[layer(Source::Env, vec![("font", "console"), ("line_height", "2")])], Options::default()).unwrap();
+		assert_eq!(options.line_height, 2);
+	}
+
+	#[test]
+	fn apply_layers_theme_works() {
+		let (options, sources) =
+			apply_layers(&[layer(Source::Env, vec![("font", "tiny"), ("theme", "pride")])], Options::default()).unwrap();
+
+		// the font of the layer wins over the font of the theme
+		assert_eq!(options.font, Fonts::FontTiny);
+		assert!(options.transition_gradient);
+		assert_eq!(sources.get("gradient"), Some(&Source::Env));
+	}
+
+	#[test]
+	fn apply_layers_errors() {
+		let error = apply_layers(&[layer(Source::Env, vec![("font", "nope")])], Options::default()).unwrap_err();
+		assert!(error.contains("nope"));
+		assert!(error.contains("environment"));
+
+		assert!(apply_layers(&[layer(Source::Env, vec![("gradient", "red")])], Options::default()).is_err());
+		assert!(apply_layers(&[layer(Source::Env, vec![("spaceless", "yes")])], Options::default()).is_err());
+	}
+
+	#[test]
+	fn track_changes_works() {
+		let before = Options::default();
+		let after = Options {
+			font: Fonts::FontTiny,
+			seed: Some(42),
+			..Options::default()
+		};
+		let mut sources = Sources::new();
+		sources.insert(String::from("align"), Source::Env);
+
+		track_changes(&before, &after, &Source::Cli, &mut sources);
+		assert_eq!(sources.get("font"), Some(&Source::Cli));
+		assert_eq!(sources.get("seed"), Some(&Source::Cli));
+		assert_eq!(sources.get("align"), Some(&Source::Env));
+		assert_eq!(sources.len(), 3);
+	}
+
+	#[test]
+	fn get_value_round_trips() {
+		let options = Options {
+			font: Fonts::FontSimple3d,
+			colors: vec![Colors::CyanBright],
+			seed: Some(7),
+			duration: Some(1500),
+			border: Some(Border::Heavy),
+			border_color: vec![Colors::Red, Colors::Blue],
+			padding: [1, 2, 3, 4],
+			shadow: true,
+			shadow_offset: [2, 1],
+			shadow_char: '▒',
+			shadow_color: Colors::Rgb(Rgb::Val(255, 136, 0)),
+			..Options::default()
+		};
+		let values = [
+			"font",
+			"colors",
+			"seed",
+			"duration",
+			"color_mode",
+			"env",
+			"border",
+			"border_color",
+			"padding",
+			"shadow",
+			"shadow_offset",
+			"shadow_char",
+			"shadow_color",
+		]
+		.iter()
+		.map(|key| {
+			let value = match get_value(key, &options).unwrap() {
+				toml::Value::String(text) => text,
+				toml::Value::Array(items) => items
+					.iter()
+					.map(|item| item.as_str().map_or(item.to_string(), String::from))
+					.collect::<Vec<String>>()
+					.join(","),
+				value => value.to_string(),
+			};
+			(key.to_string(), value)
+		})
+		.collect();
+
+		let (result, _) = apply_layers(
+			&[Layer {
+				source: Source::Env,
+				values,
+			}],
+			Options::default(),
+		)
+		.unwrap();
+		assert_eq!(result, options);
+	}
+
+	#[test]
+	fn print_config_works() {
+		let options = Options {
+			font: Fonts::FontTiny,
+			gradient: vec![String::from("#ff0000"), String::from("#0000ff")],
+			..Options::default()
+		};
+		let mut sources = Sources::new();
+		sources.insert(String::from("font"), Source::Project(PathBuf::from("/project/.cfontsrc")));
+		sources.insert(String::from("gradient"), Source::Cli);
+
+		let output = print_config(&options, &sources);
+		let lines = output.lines().collect::<Vec<&str>>();
+		assert_eq!(lines[0], "font                 = \"tiny\" # /project/.cfontsrc");
+		assert_eq!(lines[1], "colors               = [\"system\"] # default");
+		assert!(lines.contains(&"gradient             = [\"#ff0000\", \"#0000ff\"] # command line"));
+		assert!(!output.contains("seed"));
+		assert!(!output.contains("version"));
+	}
+}
@@ -1,9 +1,13 @@
+#![allow(clippy::field_reassign_with_default)]
+
 extern crate cfonts;
 
+use std::fs;
+
 use cfonts::config::{Options, GRADIENTS_PRIDE};
 use cfonts::gradient::{
 	add_gradient_colors, get_gradient_colors, get_linear, get_multiple_transition_colors, get_theta,
-	get_transition_colors, get_transition_steps, paint_lines,
+	get_transition_colors, get_transition_steps, paint_lines, GradientPreset, GradientPresetRegistry,
 };
 
 #[cfg(test)]
@@ -105,7 +109,7 @@ mod gradient {
 		let colors = vec!["#ff0000".to_string(), "#00ff00".to_string(), "#0000ff".to_string()];
 
 		assert_eq!(
-			paint_lines(&lines, &colors, 0, &options),
+			paint_lines(&lines, &colors, 0, 0, &options),
 			vec![
 				"\x1b[38;2;255;0;0m#\x1b[39m\x1b[38;2;0;255;0m#\x1b[39m\x1b[38;2;0;0;255m#\x1b[39m".to_string(),
 				"\x1b[38;2;255;0;0m#\x1b[39m\x1b[38;2;0;255;0m#\x1b[39m\x1b[38;2;0;0;255m#\x1b[39m".to_string(),
@@ -114,12 +118,22 @@ mod gradient {
 
 		let lines = vec!["     ###".to_string(), "     ###".to_string()];
 		assert_eq!(
-			paint_lines(&lines, &colors, 5, &options),
+			paint_lines(&lines, &colors, 5, 0, &options),
 			vec![
 				"     \x1b[38;2;255;0;0m#\x1b[39m\x1b[38;2;0;255;0m#\x1b[39m\x1b[38;2;0;0;255m#\x1b[39m".to_string(),
 				"     \x1b[38;2;255;0;0m#\x1b[39m\x1b[38;2;0;255;0m#\x1b[39m\x1b[38;2;0;0;255m#\x1b[39m".to_string(),
 			]
 		);
+
+		// a phase shifts the colors to the right and wraps them around
+		assert_eq!(
+			paint_lines(&lines, &colors, 5, 2, &options),
+			vec![
+				"     \x1b[38;2;0;255;0m#\x1b[39m\x1b[38;2;0;0;255m#\x1b[39m\x1b[38;2;255;0;0m#\x1b[39m".to_string(),
+				"     \x1b[38;2;0;255;0m#\x1b[39m\x1b[38;2;0;0;255m#\x1b[39m\x1b[38;2;255;0;0m#\x1b[39m".to_string(),
+			]
+		);
+		assert_eq!(paint_lines(&lines, &colors, 5, 3, &options), paint_lines(&lines, &colors, 5, 0, &options));
 	}
 
 	#[test]
@@ -295,4 +309,95 @@ mod gradient {
 			String::from("\x1b[38;2;117;7;135m#\x1b[39m"),
 		]);
 	}
+
+	#[test]
+	fn gradient_preset_registry_has_builtin_presets() {
+		let registry = GradientPresetRegistry::default();
+
+		assert_eq!(registry.list().len(), 13);
+		assert_eq!(registry.get("pride"), Some(&GradientPreset::transition(&GRADIENTS_PRIDE)));
+		assert_eq!(registry.get("LGBT"), registry.get("pride"));
+		assert_eq!(registry.get("poly"), registry.get("polysexual"));
+		assert_eq!(registry.get("unknown"), None);
+		assert_eq!(registry.aliases_of("transgender"), vec![String::from("trans")]);
+		assert_eq!(GradientPresetRegistry::new().list().len(), 0);
+	}
+
+	#[test]
+	fn gradient_preset_registry_register_works() {
+		let options = Options::default();
+		let mut registry = GradientPresetRegistry::default();
+
+		registry
+			.register(
+				"Sunset",
+				GradientPreset {
+					colors: vec![String::from("red"), String::from("#00f")],
+					transition: false,
+				},
+				&options,
+			)
+			.unwrap();
+		assert_eq!(
+			registry.get("sunset"),
+			Some(&GradientPreset {
+				colors: vec![String::from("#ff0000"), String::from("#0000ff")],
+				transition: false,
+			})
+		);
+
+		// overriding an alias with its own preset
+		registry.register("lgbt", GradientPreset::transition(&["red", "blue", "green"]), &options).unwrap();
+		assert_ne!(registry.get("lgbt"), registry.get("pride"));
+		assert_eq!(registry.aliases_of("pride"), vec![String::from("lgbtq"), String::from("lgbtqa")]);
+
+		assert!(registry.register("bad", GradientPreset::transition(&["red", "nope"]), &options).is_err());
+		assert!(registry
+			.register(
+				"bad",
+				GradientPreset {
+					colors: vec![String::from("red"), String::from("green"), String::from("blue")],
+					transition: false,
+				},
+				&options,
+			)
+			.is_err());
+	}
+
+	#[test]
+	fn gradient_preset_registry_load_file_works() {
+		let options = Options::default();
+		let dir = std::env::temp_dir().join(format!("cfonts-gradient-test-{}", std::process::id()));
+		fs::create_dir_all(&dir).unwrap();
+		let file = dir.join("gradients.toml");
+		fs::write(
+			&file,
+			"[sunset]\ncolors = [\"#ff5f6d\", \"#ffc371\"]\n\n[ocean]\ncolors = [\"#00c6ff\", \"#0072ff\", \"#001f3f\"]\ntransition = true\n",
+		)
+		.unwrap();
+
+		let mut registry = GradientPresetRegistry::new();
+		assert!(registry.load_file(&file, &options).unwrap().is_empty());
+		assert_eq!(registry.list(), vec![String::from("ocean"), String::from("sunset")]);
+		assert!(registry.get("ocean").unwrap().transition);
+		assert!(!registry.get("sunset").unwrap().transition);
+
+		temp_env::with_var("XDG_CONFIG_HOME", Some(&dir), || {
+			let mut registry = GradientPresetRegistry::new();
+			assert!(registry.load_config(&options).unwrap().is_empty());
+			assert_eq!(registry.list().len(), 0);
+		});
+
+		fs::write(&file, "[dusk]\ncolours = [\"red\", \"blue\"]\n\n[dawn]\ncolors = [\"red\", \"blue\"]\n").unwrap();
+		let mut registry = GradientPresetRegistry::new();
+		let broken = registry.load_file(&file, &options).unwrap();
+		assert_eq!(broken.keys().collect::<Vec<&String>>(), vec!["dusk"]);
+		assert!(registry.get("dawn").is_some());
+		assert!(registry.get("dusk").is_none());
+
+		fs::write(&file, "[sunset\ncolors = [\"red\", \"blue\"]\n").unwrap();
+		assert!(registry.load_file(&file, &options).is_err());
+
+		let _ = fs::remove_dir_all(&dir);
+	}
 }
@@ -0,0 +1,271 @@
+extern crate cfonts;
+
+use cfonts::color::Rgb;
+use cfonts::config::{ColorMode, Colors, Fonts, Options};
+use cfonts::grid::{ansi256_to_rgb, Blend, Cell, Grid};
+use cfonts::render::render;
+
+#[cfg(test)]
+mod grid {
+	use super::*;
+
+	#[test]
+	fn from_ansi_works_with_plain_text() {
+		let grid = Grid::from_ansi("ab\n\nc", &Options::default());
+
+		assert_eq!(grid.height(), 3);
+		assert_eq!(grid.width(), 2);
+		assert_eq!(grid.rows[1], vec![]);
+		assert_eq!(
+			grid.rows[2],
+			vec![Cell {
+				character: 'c',
+				fg: None,
+				bg: None
+			}]
+		);
+	}
+
+	#[test]
+	fn from_ansi_works_with_colors() {
+		let options = Options::default();
+		let grid = Grid::from_ansi("\x1b[31ma\x1b[44mb\x1b[39mc\nd\x1b[0me\x1b[38;5;196;48;2;1;2;3mf\x1b[49mg", &options);
+		let red = Some(Rgb::Val(234, 50, 35));
+		let blue = Some(Rgb::Val(0, 32, 245));
+
+		assert_eq!(
+			grid.rows[0][0],
+			Cell {
+				character: 'a',
+				fg: red.clone(),
+				bg: None
+			}
+		);
+		assert_eq!(
+			grid.rows[0][1],
+			Cell {
+				character: 'b',
+				fg: red.clone(),
+				bg: blue.clone()
+			}
+		);
+		assert_eq!(
+			grid.rows[0][2],
+			Cell {
+				character: 'c',
+				fg: None,
+				bg: blue.clone()
+			}
+		);
+		// colors carry over line breaks
+		assert_eq!(
+			grid.rows[1][0],
+			Cell {
+				character: 'd',
+				fg: None,
+				bg: blue
+			}
+		);
+		assert_eq!(
+			grid.rows[1][1],
+			Cell {
+				character: 'e',
+				fg: None,
+				bg: None
+			}
+		);
+		assert_eq!(
+			grid.rows[1][2],
+			Cell {
+				character: 'f',
+				fg: Some(Rgb::Val(255, 0, 0)),
+				bg: Some(Rgb::Val(1, 2, 3))
+			}
+		);
+		assert_eq!(
+			grid.rows[1][3],
+			Cell {
+				character: 'g',
+				fg: Some(Rgb::Val(255, 0, 0)),
+				bg: None
+			}
+		);
+	}
+
+	#[test]
+	fn from_ansi_works_with_bright_colors() {
+		let grid = Grid::from_ansi("\x1b[92;105ma", &Options::default());
+
+		assert_eq!(grid.rows[0][0].fg, Some(Rgb::Val(140, 245, 123)));
+		assert_eq!(grid.rows[0][0].bg, Some(Rgb::Val(238, 130, 248)));
+	}
+
+	#[test]
+	fn from_ansi_works_with_render_output() {
+		let output = render(Options {
+			text: String::from("hi"),
+			font: Fonts::FontTiny,
+			colors: vec![Colors::Rgb(Rgb::Val(255, 136, 0))],
+			color_mode: ColorMode::Truecolor,
+			..Options::default()
+		});
+		let grid = Grid::from_ansi(&output.text, &Options::default());
+
+		assert_eq!(grid.height(), 6);
+		let row = &grid.rows[2];
+		assert_eq!(row.iter().map(|cell| cell.character).collect::<String>(), " █ █ █");
+		assert!(row.iter().filter(|cell| cell.character == '█').all(|cell| cell.fg == Some(Rgb::Val(255, 136, 0))));
+	}
+
+	#[test]
+	fn ansi256_to_rgb_works() {
+		assert_eq!(ansi256_to_rgb(0), Rgb::Val(0, 0, 0));
+		assert_eq!(ansi256_to_rgb(15), Rgb::Val(255, 255, 255));
+		assert_eq!(ansi256_to_rgb(16), Rgb::Val(0, 0, 0));
+		assert_eq!(ansi256_to_rgb(21), Rgb::Val(0, 0, 255));
+		assert_eq!(ansi256_to_rgb(231), Rgb::Val(255, 255, 255));
+		assert_eq!(ansi256_to_rgb(255), Rgb::Val(238, 238, 238));
+	}
+
+	#[test]
+	fn silhouette_works() {
+		let options = Options::default();
+		let grid = Grid::from_ansi("\x1b[31;44ma b\x1b[0m\nc", &options);
+		let gray = Some(Rgb::Val(1, 2, 3));
+		let shadow = Cell {
+			character: '░',
+			fg: gray.clone(),
+			bg: None,
+		};
+
+		assert_eq!(
+			grid.silhouette('░', gray),
+			Grid {
+				rows: vec![vec![shadow.clone(), Cell::default(), shadow.clone()], vec![shadow]]
+			}
+		);
+	}
+
+	#[test]
+	fn composite_works() {
+		let options = Options::default();
+		let layer = Grid::from_ansi("\x1b[31mx x\x1b[39m", &options);
+		let red = Some(Rgb::Val(234, 50, 35));
+		let blue = Some(Rgb::Val(0, 32, 245));
+
+		let mut grid = Grid::from_ansi("\x1b[44mab\x1b[49m", &options);
+		grid.composite(&layer, 1, 0, Blend::Over);
+		assert_eq!(
+			grid.rows[0],
+			vec![
+				Cell {
+					character: 'a',
+					fg: None,
+					bg: blue.clone(),
+				},
+				Cell {
+					character: 'x',
+					fg: red.clone(),
+					bg: blue.clone(),
+				},
+				Cell::default(),
+				Cell {
+					character: 'x',
+					fg: red.clone(),
+					bg: None,
+				},
+			]
+		);
+
+		let mut grid = Grid::from_ansi("\x1b[44ma \x1b[49m", &options);
+		grid.composite(&layer, 0, 0, Blend::Behind);
+		assert_eq!(
+			grid.rows[0],
+			vec![
+				Cell {
+					character: 'a',
+					fg: None,
+					bg: blue.clone(),
+				},
+				Cell {
+					character: ' ',
+					fg: None,
+					bg: blue.clone(),
+				},
+				Cell {
+					character: 'x',
+					fg: red.clone(),
+					bg: None,
+				},
+			]
+		);
+
+		// an empty cell keeps its background under the layer
+		let mut grid = Grid::from_ansi("\x1b[44ma \x1b[49m", &options);
+		grid.composite(&layer, 1, 0, Blend::Behind);
+		assert_eq!(
+			grid.rows[0][1],
+			Cell {
+				character: 'x',
+				fg: red,
+				bg: blue,
+			}
+		);
+	}
+
+	#[test]
+	fn composite_grows_grid() {
+		let options = Options::default();
+		let mut grid = Grid::from_ansi("a", &options);
+		grid.composite(&Grid::from_ansi("b\n c", &options), 2, 1, Blend::Behind);
+
+		assert_eq!(grid, Grid::from_ansi("a\n  b\n   c", &options));
+	}
+
+	#[test]
+	fn to_ansi_works() {
+		let options = Options {
+			color_mode: ColorMode::Truecolor,
+			..Options::default()
+		};
+		let text =
+			"\x1b[31ma\x1b[39m\x1b[38;2;1;2;3m\x1b[44mb\x1b[39m\x1b[49m\n\x1b[91m\x1b[48;2;4;5;6mc\x1b[39m\x1b[49m d";
+
+		assert_eq!(Grid::from_ansi(text, &options).to_ansi(&options), text);
+		assert_eq!(
+			Grid::from_ansi(text, &options).to_ansi(&Options {
+				color_mode: ColorMode::NoColor,
+				..Options::default()
+			}),
+			"ab\nc d"
+		);
+	}
+
+	#[test]
+	fn to_ansi_round_trips_render_output() {
+		let options = Options {
+			text: String::from("hi"),
+			font: Fonts::FontBlock,
+			colors: vec![Colors::Red, Colors::YellowBright],
+			color_mode: ColorMode::Ansi16,
+			spaceless: true,
+			..Options::default()
+		};
+		let output = render(options.clone());
+		let grid = Grid::from_ansi(&output.text, &options);
+
+		assert_eq!(Grid::from_ansi(&grid.to_ansi(&options), &options), grid);
+	}
+
+	#[test]
+	fn to_spans_works() {
+		let options = Options::default();
+		let text = "\x1b[38;2;1;2;3ma\x1b[48;2;4;5;6mb\x1b[39m\x1b[49m c\n\x1b[38;2;1;2;3mdd\x1b[39m";
+
+		assert_eq!(
+			Grid::from_ansi(text, &options).to_spans(&options),
+			"<span style=\"color:#010203\">a</span><span style=\"color:#010203;background:#040506\">b</span> c\n<span style=\"color:#010203\">dd</span>"
+		);
+		assert_eq!(Grid::default().to_spans(&options), "");
+	}
+}
@@ -0,0 +1,102 @@
+extern crate cfonts;
+
+use cfonts::color::Rgb;
+use cfonts::config::{Align, BgColors, Colors, Fonts, Options};
+use cfonts::grid::{Cell, Grid};
+use cfonts::html::{escape_html, grid2html, render_html};
+
+#[cfg(test)]
+mod html {
+	use super::*;
+
+	fn cell(character: char, fg: Option<Rgb>, bg: Option<Rgb>) -> Cell {
+		Cell { character, fg, bg }
+	}
+
+	#[test]
+	fn escape_html_works() {
+		assert_eq!(escape_html("abc"), "abc");
+		assert_eq!(escape_html("<>&"), "&lt;&gt;&amp;");
+		assert_eq!(escape_html("&lt;"), "&amp;lt;");
+	}
+
+	#[test]
+	fn grid2html_merges_runs() {
+		let red = Some(Rgb::Val(255, 0, 0));
+		let blue = Some(Rgb::Val(0, 0, 255));
+		let grid = Grid {
+			rows: vec![
+				vec![
+					cell(' ', None, None),
+					cell('a', red.clone(), None),
+					cell('b', red.clone(), None),
+					cell(' ', blue.clone(), None),
+					cell('c', red.clone(), None),
+					cell('d', blue.clone(), None),
+				],
+				vec![
+					cell('e', blue.clone(), red.clone()),
+					cell('f', None, red.clone()),
+					cell('g', None, None),
+				],
+			],
+		};
+		let output = grid2html(&grid, &Options::default());
+
+		assert_eq!(
+			output.html,
+			"<div class=\"cf\"><span class=\"cf-c1\"> ab c</span><span class=\"cf-c2\">d</span>\n<span class=\"cf-c2 cf-b1\">e</span><span class=\"cf-b1\">f</span>g</div>"
+		);
+		assert_eq!(
+			output.css,
+			".cf { font-family: monospace; white-space: pre; text-align: left; }\n.cf-c1 { color: #ff0000; }\n.cf-c2 { color: #0000ff; }\n.cf-b1 { background: #ff0000; }\n"
+		);
+	}
+
+	#[test]
+	fn grid2html_works_with_background_and_align() {
+		let options = Options {
+			background: BgColors::Blue,
+			align: Align::Right,
+			..Options::default()
+		};
+		let output = grid2html(&Grid::from_ansi("x", &options), &options);
+
+		assert_eq!(output.html, "<div class=\"cf cf-bg\">x</div>");
+		assert!(output.css.contains("text-align: right;"));
+		assert!(output.css.contains(".cf-bg { background: #0020f5; }"));
+	}
+
+	#[test]
+	fn grid2html_works_as_fragment() {
+		let options = Options {
+			html_fragment: true,
+			background: BgColors::Blue,
+			..Options::default()
+		};
+		let output = grid2html(&Grid::from_ansi("a\nb", &options), &options);
+
+		assert_eq!(output.html, "a\nb");
+		assert!(output.css.contains(".cf-bg"));
+	}
+
+	#[test]
+	fn render_html_works() {
+		let options = Options {
+			text: String::from("hi"),
+			font: Fonts::FontTiny,
+			colors: vec![Colors::Rgb(Rgb::Val(255, 136, 0))],
+			align: Align::Center,
+			..Options::default()
+		};
+		let output = render_html(&options);
+
+		assert_eq!(
+			output.html,
+			"<div class=\"cf\">\n\n<span class=\"cf-c1\"> █ █ █</span>\n<span class=\"cf-c1\"> █▀█ █</span>\n\n</div>"
+		);
+		assert!(output.css.contains("text-align: center;"));
+		assert!(output.css.contains(".cf-c1 { color: #ff8800; }"));
+		assert!(!output.html.contains("style="));
+	}
+}
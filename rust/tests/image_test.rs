@@ -0,0 +1,123 @@
+extern crate cfonts;
+
+use std::path::PathBuf;
+
+use cfonts::color::Rgb;
+use cfonts::config::{BgColors, Colors, Fonts, Options, TerminalBg};
+use cfonts::grid::Grid;
+use cfonts::image::{get_default_foreground, grid2png, render_png};
+
+#[cfg(test)]
+mod image {
+	use super::*;
+
+	/// Decode a png into its size and rgba pixels
+	fn decode(data: &[u8]) -> (u32, u32, Vec<u8>) {
+		let decoder = png::Decoder::new(data);
+		let mut reader = decoder.read_info().unwrap();
+		let mut pixels = vec![0; reader.output_buffer_size()];
+		let info = reader.next_frame(&mut pixels).unwrap();
+		pixels.truncate(info.buffer_size());
+		(info.width, info.height, pixels)
+	}
+
+	/// Compare a png against a golden image in `tests/fixtures/png`
+	///
+	/// Run the tests with `CFONTS_UPDATE_GOLDEN=1` to write new golden images after an intended change.
+	fn assert_golden(name: &str, data: &[u8]) {
+		let path = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures/png").join(name);
+		if std::env::var("CFONTS_UPDATE_GOLDEN").is_ok() {
+			std::fs::write(&path, data).unwrap();
+		}
+		let golden = std::fs::read(&path).unwrap_or_else(|_| panic!("Golden image {:?} is missing", path));
+		assert!(decode(data) == decode(&golden), "Output does not match the golden image {:?}", path);
+	}
+
+	#[test]
+	fn get_default_foreground_works() {
+		let options = Options::default();
+		assert_eq!(get_default_foreground(&None, &options), Rgb::Val(255, 255, 255));
+		assert_eq!(get_default_foreground(&Some(Rgb::Val(0, 0, 0)), &options), Rgb::Val(255, 255, 255));
+		assert_eq!(get_default_foreground(&Some(Rgb::Val(255, 255, 255)), &options), Rgb::Val(0, 0, 0));
+
+		let options = Options {
+			terminal_bg: TerminalBg::Light,
+			..Options::default()
+		};
+		assert_eq!(get_default_foreground(&None, &options), Rgb::Val(0, 0, 0));
+		assert_eq!(get_default_foreground(&Some(Rgb::Val(0, 0, 100)), &options), Rgb::Val(255, 255, 255));
+	}
+
+	#[test]
+	fn grid2png_works() {
+		let options = Options::default();
+		let grid = Grid::from_ansi("\x1b[38;2;255;0;0m█\x1b[39m \n\x1b[48;2;0;0;255m \x1b[49m", &options);
+		let (width, height, pixels) = decode(&grid2png(&grid, &None, &Rgb::Val(255, 255, 255), &options).unwrap());
+
+		assert_eq!((width, height), (24, 48));
+		let pixel = |x: usize, y: usize| pixels[(y * width as usize + x) * 4..(y * width as usize + x) * 4 + 4].to_vec();
+		assert_eq!(pixel(0, 0), vec![255, 0, 0, 255]);
+		assert_eq!(pixel(11, 23), vec![255, 0, 0, 255]);
+		assert_eq!(pixel(12, 0), vec![0, 0, 0, 0]);
+		assert_eq!(pixel(0, 24), vec![0, 0, 255, 255]);
+		// the second row is shorter and padded with the background
+		assert_eq!(pixel(23, 47), vec![0, 0, 0, 0]);
+	}
+
+	#[test]
+	fn grid2png_works_with_empty_grids() {
+		let options = Options::default();
+		let (width, height, pixels) =
+			decode(&grid2png(&Grid::default(), &Some(Rgb::Val(1, 2, 3)), &Rgb::Val(255, 255, 255), &options).unwrap());
+
+		assert_eq!((width, height), (12, 24));
+		assert_eq!(pixels[..4], [1, 2, 3, 255]);
+	}
+
+	#[test]
+	fn render_png_works_with_block_font() {
+		let options = Options {
+			text: String::from("Hi"),
+			font: Fonts::FontBlock,
+			colors: vec![Colors::Red, Colors::Rgb(Rgb::Val(255, 136, 0))],
+			..Options::default()
+		};
+		assert_golden("block.png", &render_png(&options).unwrap());
+	}
+
+	#[test]
+	fn render_png_works_with_chrome_font_and_background() {
+		let options = Options {
+			text: String::from("2.0"),
+			font: Fonts::FontChrome,
+			background: BgColors::Blue,
+			..Options::default()
+		};
+		assert_golden("chrome.png", &render_png(&options).unwrap());
+	}
+
+	#[test]
+	fn render_png_works_with_gradient() {
+		let options = Options {
+			text: String::from("ok"),
+			font: Fonts::FontTiny,
+			gradient: vec![String::from("#ff0000"), String::from("#0000ff")],
+			transition_gradient: true,
+			terminal_bg: TerminalBg::Light,
+			..Options::default()
+		};
+		assert_golden("gradient.png", &render_png(&options).unwrap());
+	}
+
+	#[test]
+	fn render_png_works_with_console_font() {
+		let options = Options {
+			text: String::from("Hello, world!"),
+			font: Fonts::FontConsole,
+			colors: vec![Colors::Yellow],
+			background: BgColors::Black,
+			..Options::default()
+		};
+		assert_golden("console.png", &render_png(&options).unwrap());
+	}
+}
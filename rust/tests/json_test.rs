@@ -0,0 +1,84 @@
+extern crate cfonts;
+
+use cfonts::color::Rgb;
+use cfonts::config::{BgColors, ColorMode, Colors, Fonts, Options};
+use cfonts::json::{render_json, JsonCell, RenderedJson};
+
+#[cfg(test)]
+mod json {
+	use super::*;
+
+	#[test]
+	fn render_json_works() {
+		let options = Options {
+			text: String::from("hi"),
+			font: Fonts::FontTiny,
+			colors: vec![Colors::Rgb(Rgb::Val(255, 136, 0))],
+			seed: Some(42),
+			..Options::default()
+		};
+		let output = render_json(&options);
+
+		assert_eq!(output.rows.len(), 6);
+		assert_eq!(
+			output.rows[2],
+			"\x1b[38;2;255;136;0m \x1b[39m\x1b[38;2;255;136;0m█ █\x1b[39m\x1b[38;2;255;136;0m \x1b[39m\x1b[38;2;255;136;0m█\x1b[39m"
+		);
+		assert_eq!(output.plain_rows, vec!["", "", " █ █ █", " █▀█ █", "", ""]);
+		assert_eq!((output.lines, output.width, output.height), (1, 6, 6));
+		assert_eq!(
+			output.options,
+			Options {
+				color_mode: ColorMode::Truecolor,
+				..options
+			}
+		);
+		assert_eq!(
+			output.cells[2][1],
+			JsonCell {
+				character: '█',
+				fg: Some(String::from("#ff8800")),
+				bg: None,
+			}
+		);
+	}
+
+	#[test]
+	fn render_json_respects_color_mode() {
+		let output = render_json(&Options {
+			text: String::from("hi"),
+			font: Fonts::FontTiny,
+			colors: vec![Colors::Red],
+			color_mode: ColorMode::NoColor,
+			..Options::default()
+		});
+
+		assert_eq!(output.rows, output.plain_rows);
+		assert!(output.cells.iter().flatten().all(|cell| cell.fg.is_none()));
+	}
+
+	#[test]
+	fn render_json_works_with_background() {
+		let output = render_json(&Options {
+			text: String::from("hi"),
+			font: Fonts::FontTiny,
+			background: BgColors::Rgb(Rgb::Val(0, 0, 255)),
+			..Options::default()
+		});
+
+		assert!(output.cells[3][1].bg == Some(String::from("#0000ff")));
+	}
+
+	#[test]
+	fn to_json_round_trips() {
+		let output = render_json(&Options {
+			text: String::from("hi"),
+			font: Fonts::FontConsole,
+			..Options::default()
+		});
+		let json = output.to_json().unwrap();
+
+		assert!(json.contains("\"plain_rows\""));
+		assert_eq!(serde_json::from_str::<RenderedJson>(&json).unwrap(), output);
+	}
+}
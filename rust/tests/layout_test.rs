@@ -0,0 +1,127 @@
+extern crate cfonts;
+
+use cfonts::config::{BgColors, ColorMode, Colors, Fonts, Options};
+use cfonts::layout::{join_horizontal, VerticalAlign};
+use cfonts::plain::strip_ansi;
+use cfonts::render::{render, RenderedString};
+
+#[cfg(test)]
+mod layout {
+	use super::*;
+
+	fn block(rows: Vec<&str>) -> RenderedString {
+		RenderedString {
+			text: rows.join("\n"),
+			vec: rows.into_iter().map(String::from).collect(),
+			lines: 1,
+			options: Options::default(),
+		}
+	}
+
+	#[test]
+	fn join_horizontal_pads_and_gaps() {
+		let output =
+			join_horizontal(&[block(vec!["a", "bbb"]), block(vec!["cc", "d"]), block(vec!["e"])], 2, VerticalAlign::Top);
+
+		assert_eq!(output.vec, vec![String::from("a    cc  e"), String::from("bbb  d    ")]);
+		assert_eq!(output.text, output.vec.join("\n"));
+		assert_eq!(output.lines, 1);
+	}
+
+	#[test]
+	fn join_horizontal_aligns_vertically() {
+		let blocks = [block(vec!["a", "b", "c", "d"]), block(vec!["x"])];
+
+		assert_eq!(
+			join_horizontal(&blocks, 1, VerticalAlign::Top).vec,
+			vec![
+				String::from("a x"),
+				String::from("b  "),
+				String::from("c  "),
+				String::from("d  ")
+			]
+		);
+		assert_eq!(
+			join_horizontal(&blocks, 1, VerticalAlign::Middle).vec,
+			vec![
+				String::from("a  "),
+				String::from("b x"),
+				String::from("c  "),
+				String::from("d  ")
+			]
+		);
+		assert_eq!(
+			join_horizontal(&blocks, 1, VerticalAlign::Bottom).vec,
+			vec![
+				String::from("a  "),
+				String::from("b  "),
+				String::from("c  "),
+				String::from("d x")
+			]
+		);
+	}
+
+	#[test]
+	fn join_horizontal_measures_visible_width() {
+		let output =
+			join_horizontal(&[block(vec!["\x1b[31mab\x1b[39m", "c"]), block(vec!["d", "e"])], 1, VerticalAlign::Top);
+
+		assert_eq!(output.vec, vec![String::from("\x1b[31mab\x1b[39m d"), String::from("c  e")]);
+	}
+
+	#[test]
+	fn join_horizontal_closes_colors_per_row() {
+		let output =
+			join_horizontal(&[block(vec!["\x1b[44m", "a", "b\x1b[49m"]), block(vec!["c", "d", "e"])], 1, VerticalAlign::Top);
+
+		assert_eq!(
+			output.vec,
+			vec![
+				String::from("\x1b[44m\x1b[49m  c"),
+				String::from("\x1b[44ma\x1b[49m d"),
+				String::from("\x1b[44mb\x1b[49m e"),
+			]
+		);
+	}
+
+	#[test]
+	fn join_horizontal_works_with_renders() {
+		let icon = render(Options {
+			text: String::from("i"),
+			font: Fonts::FontBlock,
+			colors: vec![Colors::Red, Colors::Blue],
+			background: BgColors::Green,
+			color_mode: ColorMode::Ansi16,
+			spaceless: true,
+			..Options::default()
+		});
+		let word = render(Options {
+			text: String::from("hi"),
+			font: Fonts::FontTiny,
+			spaceless: true,
+			..Options::default()
+		});
+		let icon_height = icon.vec.join("\n").lines().count();
+		let icon_options = icon.options.clone();
+
+		let output = join_horizontal(&[icon, word], 2, VerticalAlign::Middle);
+		let widths = output.vec.iter().map(|row| strip_ansi(row).chars().count()).collect::<Vec<usize>>();
+
+		assert_eq!(output.vec.len(), icon_height);
+		assert!(widths.iter().all(|width| *width == widths[0]));
+		// the background of the icon is closed before the gap in each row
+		for row in output.vec.iter().filter(|row| row.contains("\x1b[42m")) {
+			assert!(!row[row.rfind("\x1b[49m").unwrap()..].contains("\x1b[42m"));
+		}
+		assert_eq!(output.options, icon_options);
+	}
+
+	#[test]
+	fn join_horizontal_without_blocks() {
+		let output = join_horizontal(&[], 1, VerticalAlign::Top);
+
+		assert!(output.vec.is_empty());
+		assert_eq!(output.text, "");
+		assert_eq!(output.lines, 0);
+	}
+}
@@ -20,4 +20,479 @@ mod tests {
 
 		assert!(String::from_utf8_lossy(&output.stdout).to_string().contains("Give your cli some love."));
 	}
+
+	#[test]
+	fn contrast_warning_works() {
+		let output = Command::cargo_bin("cfonts")
+			.unwrap()
+			.args(vec!["hi", "-c", "blue", "-b", "blue"])
+			.output()
+			.expect("failed to execute rust process");
+
+		assert!(output.status.success());
+		assert!(String::from_utf8_lossy(&output.stderr).to_string().contains("contrast ratio"));
+
+		let output = Command::cargo_bin("cfonts")
+			.unwrap()
+			.args(vec!["hi", "-c", "blue", "-b", "blue", "--strict-contrast"])
+			.output()
+			.expect("failed to execute rust process");
+
+		assert_eq!(output.status.code(), Some(exitcode::DATAERR));
+		assert!(output.stdout.is_empty());
+
+		let output = Command::cargo_bin("cfonts")
+			.unwrap()
+			.args(vec!["hi", "-c", "white", "-b", "blue", "--strict-contrast"])
+			.output()
+			.expect("failed to execute rust process");
+
+		assert!(output.status.success());
+		assert!(output.stderr.is_empty());
+	}
+
+	#[test]
+	fn output_png_works() {
+		let path = std::env::temp_dir().join(format!("cfonts-main-test-{}.png", std::process::id()));
+		let output = Command::cargo_bin("cfonts")
+			.unwrap()
+			.args(vec!["hi", "-f", "tiny", "--output", path.to_str().unwrap()])
+			.output()
+			.expect("failed to execute rust process");
+
+		assert!(output.status.success());
+		assert!(output.stdout.is_empty());
+		assert_eq!(&std::fs::read(&path).unwrap()[..8], b"\x89PNG\r\n\x1a\n");
+		std::fs::remove_file(&path).unwrap();
+
+		let output = Command::cargo_bin("cfonts")
+			.unwrap()
+			.args(vec!["hi", "--output", "banner.gif"])
+			.output()
+			.expect("failed to execute rust process");
+
+		assert_eq!(output.status.code(), Some(exitcode::USAGE));
+		assert!(String::from_utf8_lossy(&output.stdout).to_string().contains("not supported"));
+	}
+
+	#[test]
+	fn output_html_works() {
+		let path = std::env::temp_dir().join(format!("cfonts-main-test-{}.html", std::process::id()));
+		let output = Command::cargo_bin("cfonts")
+			.unwrap()
+			.args(vec!["hi", "-f", "tiny", "-c", "red", "--output", path.to_str().unwrap()])
+			.output()
+			.expect("failed to execute rust process");
+
+		assert!(output.status.success());
+		assert!(std::fs::read_to_string(&path).unwrap().contains("<span class=\"cf-c1\">"));
+		assert!(std::fs::read_to_string(path.with_extension("css")).unwrap().contains(".cf-c1 { color: #ea3223; }"));
+		std::fs::remove_file(&path).unwrap();
+		std::fs::remove_file(path.with_extension("css")).unwrap();
+
+		let output = Command::cargo_bin("cfonts")
+			.unwrap()
+			.args(vec!["hi", "-f", "tiny", "-c", "red", "--output-format", "html"])
+			.output()
+			.expect("failed to execute rust process");
+
+		let stdout = String::from_utf8_lossy(&output.stdout).to_string();
+		assert!(stdout.starts_with("<div class=\"cf\">"));
+		assert!(stdout.contains("<span class=\"cf-c1\">"));
+		assert!(!stdout.contains("<style>"));
+		assert!(!stdout.contains('\x1b'));
+
+		let output = Command::cargo_bin("cfonts")
+			.unwrap()
+			.args(vec!["hi", "-f", "tiny", "-c", "red", "--output-format", "html", "--html-css"])
+			.output()
+			.expect("failed to execute rust process");
+
+		let stdout = String::from_utf8_lossy(&output.stdout).to_string();
+		assert!(stdout.starts_with(".cf {"));
+		assert!(stdout.contains(".cf-c1 { color: #ea3223; }"));
+		assert!(!stdout.contains('<'));
+	}
+
+	#[test]
+	fn output_json_works() {
+		let output = Command::cargo_bin("cfonts")
+			.unwrap()
+			.args(vec!["hi", "-f", "tiny", "-c", "red", "--output-format", "json"])
+			.output()
+			.expect("failed to execute rust process");
+
+		assert!(output.status.success());
+		let json: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+		assert_eq!(json["lines"], 1);
+		assert_eq!(json["plain_rows"][2], " █ █ █");
+		assert_eq!(json["options"]["font"], "FontTiny");
+		assert_eq!(json["cells"][2][1]["fg"], "#ea3223");
+
+		let path = std::env::temp_dir().join(format!("cfonts-main-test-{}.json", std::process::id()));
+		let output = Command::cargo_bin("cfonts")
+			.unwrap()
+			.args(vec!["hi", "-f", "tiny", "--output", path.to_str().unwrap()])
+			.output()
+			.expect("failed to execute rust process");
+
+		assert!(output.status.success());
+		let json: serde_json::Value = serde_json::from_slice(&std::fs::read(&path).unwrap()).unwrap();
+		assert_eq!(json["plain_rows"][2], " █ █ █");
+		std::fs::remove_file(&path).unwrap();
+	}
+
+	#[test]
+	fn output_cast_works() {
+		let path = std::env::temp_dir().join(format!("cfonts-main-test-{}.cast", std::process::id()));
+		let output = Command::cargo_bin("cfonts")
+			.unwrap()
+			.args(vec![
+				"hi",
+				"-f",
+				"tiny",
+				"--animate",
+				"cycle",
+				"--output",
+				path.to_str().unwrap(),
+			])
+			.output()
+			.expect("failed to execute rust process");
+
+		assert!(output.status.success());
+		let cast = std::fs::read_to_string(&path).unwrap();
+		let lines = cast.lines().collect::<Vec<&str>>();
+		assert_eq!(lines[0], "{\"version\":2,\"width\":6,\"height\":6}");
+		// a few frames for each of the 6 colors of the rainbow and one to show the cursor again
+		assert_eq!(lines.len(), 26);
+		assert!(lines[25].ends_with(",\"o\",\"\\u001b[?25h\"]"));
+		std::fs::remove_file(&path).unwrap();
+
+		// without an animation we record the output once
+		let output = Command::cargo_bin("cfonts")
+			.unwrap()
+			.args(vec!["hi", "-f", "tiny", "--output", path.to_str().unwrap()])
+			.output()
+			.expect("failed to execute rust process");
+
+		assert!(output.status.success());
+		let cast = std::fs::read_to_string(&path).unwrap();
+		assert_eq!(
+			cast,
+			"{\"version\":2,\"width\":6,\"height\":6}\n\
+			[0.0,\"o\",\"\\u001b[?25l\\r\\n\\r\\n █ █ █\\r\\n █▀█ █\\r\\n\\r\\n\"]\n\
+			[0.0,\"o\",\"\\u001b[?25h\"]\n"
+		);
+		std::fs::remove_file(&path).unwrap();
+	}
+
+	#[test]
+	fn clock_without_terminal_works() {
+		let output = Command::cargo_bin("cfonts")
+			.unwrap()
+			.args(vec!["--countdown", "90s", "-f", "console"])
+			.output()
+			.expect("failed to execute rust process");
+
+		assert!(output.status.success());
+		assert_eq!(String::from_utf8_lossy(&output.stdout), "\n\n01:30\n\n\n");
+
+		let output = Command::cargo_bin("cfonts")
+			.unwrap()
+			.args(vec!["--clock", "%H|%M", "-f", "console", "-s"])
+			.output()
+			.expect("failed to execute rust process");
+
+		assert!(output.status.success());
+		let text = String::from_utf8_lossy(&output.stdout);
+		let rows = text.trim_end().split('\n').collect::<Vec<&str>>();
+		assert_eq!(rows.len(), 2);
+		assert!(rows.iter().all(|row| row.len() == 2 && row.chars().all(|character| character.is_ascii_digit())));
+	}
+
+	#[test]
+	fn animate_without_terminal_works() {
+		let output = Command::cargo_bin("cfonts")
+			.unwrap()
+			.args(vec!["hi", "-f", "tiny", "--animate", "typewriter"])
+			.output()
+			.expect("failed to execute rust process");
+
+		assert!(output.status.success());
+		assert_eq!(String::from_utf8_lossy(&output.stdout), "\n\n █ █ █\n █▀█ █\n\n\n");
+	}
+
+	#[test]
+	fn output_plain_works() {
+		let output = Command::cargo_bin("cfonts")
+			.unwrap()
+			.env("FORCE_COLOR", "3")
+			.args(vec![
+				"hi",
+				"-f",
+				"tiny",
+				"-c",
+				"red",
+				"-b",
+				"white",
+				"--output-format",
+				"plain",
+				"--trim",
+			])
+			.output()
+			.expect("failed to execute rust process");
+
+		assert!(output.status.success());
+		assert_eq!(String::from_utf8_lossy(&output.stdout), "\n\n █ █ █\n █▀█ █\n\n\n");
+	}
+
+	#[test]
+	fn config_defaults_work() {
+		let dir = std::env::temp_dir().join(format!("cfonts-main-config-test-{}", std::process::id()));
+		std::fs::create_dir_all(dir.join("config").join("cfonts")).unwrap();
+		std::fs::create_dir_all(dir.join("project")).unwrap();
+		std::fs::write(dir.join("config").join("cfonts").join("config.toml"), "font = \"chrome\"\nalign = \"right\"\n")
+			.unwrap();
+		std::fs::write(dir.join("project").join(".cfontsrc"), "font = \"tiny\"\nalign = \"center\"\n").unwrap();
+
+		let output = Command::cargo_bin("cfonts")
+			.unwrap()
+			.args(vec!["hi", "-a", "left", "--print-config"])
+			.current_dir(dir.join("project"))
+			.env("XDG_CONFIG_HOME", dir.join("config"))
+			.env("CFONTS_SPACELESS", "true")
+			.output()
+			.expect("failed to execute rust process");
+		let stdout = String::from_utf8_lossy(&output.stdout).to_string();
+
+		assert!(output.status.success());
+		assert!(stdout
+			.contains(&format!("font                 = \"tiny\" # {}\n", dir.join("project").join(".cfontsrc").display())));
+		assert!(stdout.contains("align                = \"left\" # command line\n"));
+		assert!(stdout.contains("spaceless            = true # environment\n"));
+		assert!(stdout.contains("letter_spacing       = 1 # default\n"));
+
+		let output = Command::cargo_bin("cfonts")
+			.unwrap()
+			.args(vec!["hi"])
+			.current_dir(dir.join("project"))
+			.env("XDG_CONFIG_HOME", dir.join("config"))
+			.env("CFONTS_SPACELESS", "true")
+			.env("CFONTS_COLOR_MODE", "none")
+			.output()
+			.expect("failed to execute rust process");
+
+		assert!(output.status.success());
+		let stdout = String::from_utf8_lossy(&output.stdout).to_string();
+		// the tiny font comes from the project, centered from the project and spaceless from the environment
+		assert_eq!(stdout.lines().map(|line| line.trim()).collect::<Vec<&str>>(), vec!["█ █ █", "█▀█ █"]);
+		assert!(stdout.starts_with("    "));
+
+		std::fs::write(dir.join("project").join(".cfontsrc"), "font = \"nope\"\n").unwrap();
+		let output = Command::cargo_bin("cfonts")
+			.unwrap()
+			.args(vec!["hi"])
+			.current_dir(dir.join("project"))
+			.env("XDG_CONFIG_HOME", dir.join("config"))
+			.output()
+			.expect("failed to execute rust process");
+
+		assert_eq!(output.status.code(), Some(exitcode::CONFIG));
+
+		let _ = std::fs::remove_dir_all(&dir);
+	}
+
+	#[test]
+	fn broken_config_keeps_help_working() {
+		let dir = std::env::temp_dir().join(format!("cfonts-main-broken-config-test-{}", std::process::id()));
+		std::fs::create_dir_all(dir.join("config")).unwrap();
+		std::fs::create_dir_all(dir.join("project")).unwrap();
+		std::fs::write(dir.join("project").join(".cfontsrc"), "bogus = 1\n").unwrap();
+		let run = |args: Vec<&str>| {
+			Command::cargo_bin("cfonts")
+				.unwrap()
+				.args(args)
+				.current_dir(dir.join("project"))
+				.env("XDG_CONFIG_HOME", dir.join("config"))
+				.output()
+				.expect("failed to execute rust process")
+		};
+
+		for args in [
+			vec!["--version"],
+			vec!["--help"],
+			vec!["--man"],
+			vec!["--completions", "bash"],
+		] {
+			assert!(run(args).status.success());
+		}
+
+		let output = run(vec!["--print-config"]);
+		let stdout = String::from_utf8_lossy(&output.stdout).to_string();
+		assert!(output.status.success());
+		assert!(stdout.contains("font                 = \"block\" # default\n"));
+		assert!(stdout.contains("bogus"));
+		assert!(stdout.contains(&dir.join("project").join(".cfontsrc").display().to_string()));
+
+		let output = run(vec!["hi"]);
+		assert_eq!(output.status.code(), Some(exitcode::CONFIG));
+		assert!(String::from_utf8_lossy(&output.stdout).contains("bogus"));
+
+		let _ = std::fs::remove_dir_all(&dir);
+	}
+
+	#[test]
+	fn completions_work() {
+		let output = Command::cargo_bin("cfonts")
+			.unwrap()
+			.args(vec!["--completions", "bash"])
+			.output()
+			.expect("failed to execute rust process");
+
+		assert!(output.status.success());
+		assert!(String::from_utf8_lossy(&output.stdout).ends_with("complete -F _cfonts cfonts\n"));
+
+		let output = Command::cargo_bin("cfonts")
+			.unwrap()
+			.args(vec!["--completions", "cmd"])
+			.output()
+			.expect("failed to execute rust process");
+
+		assert_eq!(output.status.code(), Some(exitcode::USAGE));
+	}
+
+	#[test]
+	fn man_works() {
+		let output =
+			Command::cargo_bin("cfonts").unwrap().args(vec!["--man"]).output().expect("failed to execute rust process");
+
+		assert!(output.status.success());
+		assert!(String::from_utf8_lossy(&output.stdout).starts_with(".TH CFONTS 1"));
+	}
+
+	#[test]
+	fn unknown_flag_works() {
+		let output = Command::cargo_bin("cfonts")
+			.unwrap()
+			.args(vec!["hi", "--colour", "red"])
+			.output()
+			.expect("failed to execute rust process");
+
+		assert_eq!(output.status.code(), Some(exitcode::USAGE));
+		assert!(String::from_utf8_lossy(&output.stdout).to_string().contains("--colors"));
+
+		let output = Command::cargo_bin("cfonts")
+			.unwrap()
+			.args(vec!["hi", "--colour", "red", "--lenient"])
+			.output()
+			.expect("failed to execute rust process");
+
+		assert!(output.status.success());
+
+		// lenient only applies to the run it was given for
+		let output = Command::cargo_bin("cfonts")
+			.unwrap()
+			.args(vec!["hi", "--colour", "red"])
+			.env("CFONTS_LENIENT", "true")
+			.output()
+			.expect("failed to execute rust process");
+
+		assert_eq!(output.status.code(), Some(exitcode::USAGE));
+	}
+
+	#[test]
+	fn flags_before_text_work() {
+		let output = Command::cargo_bin("cfonts")
+			.unwrap()
+			.args(vec!["-f", "console", "--align=left", "hi"])
+			.output()
+			.expect("failed to execute rust process");
+		let expected = Command::cargo_bin("cfonts")
+			.unwrap()
+			.args(vec!["hi", "-f", "console", "--align", "left"])
+			.output()
+			.expect("failed to execute rust process");
+
+		assert!(output.status.success());
+		assert_eq!(output.stdout, expected.stdout);
+
+		let output = Command::cargo_bin("cfonts")
+			.unwrap()
+			.args(vec!["-fconsole", "--", "-10%"])
+			.output()
+			.expect("failed to execute rust process");
+
+		assert!(output.status.success());
+		assert!(String::from_utf8_lossy(&output.stdout).contains("-10%"));
+	}
+
+	#[test]
+	fn then_works() {
+		let output = Command::cargo_bin("cfonts")
+			.unwrap()
+			.args(vec![
+				"hi", "-f", "console", "-a", "left", "--then", "there", "-f", "console", "-a", "right",
+			])
+			.env("NO_COLOR", "1")
+			.output()
+			.expect("failed to execute rust process");
+
+		assert!(output.status.success());
+		assert_eq!(String::from_utf8_lossy(&output.stdout), "\n\nhi\nthere\n\n\n");
+
+		let output = Command::cargo_bin("cfonts")
+			.unwrap()
+			.args(vec!["hi", "--output-format", "json", "--then", "there"])
+			.output()
+			.expect("failed to execute rust process");
+
+		assert_eq!(output.status.code(), Some(exitcode::USAGE));
+	}
+
+	#[test]
+	fn border_works() {
+		let output = Command::cargo_bin("cfonts")
+			.unwrap()
+			.args(vec![
+				"hi|you",
+				"-f",
+				"console",
+				"-s",
+				"--border",
+				"ascii",
+				"--padding",
+				"0,1",
+				"--color-mode",
+				"none",
+			])
+			.output()
+			.expect("failed to execute rust process");
+
+		assert!(output.status.success());
+		assert_eq!(String::from_utf8_lossy(&output.stdout), "+-----+\n| hi  |\n| you |\n+-----+\n");
+	}
+
+	#[test]
+	fn shadow_works() {
+		let output = Command::cargo_bin("cfonts")
+			.unwrap()
+			.args(vec![
+				"hi",
+				"-f",
+				"console",
+				"-s",
+				"--shadow",
+				"--shadow-offset",
+				"1,0",
+				"--shadow-char",
+				"#",
+				"--color-mode",
+				"none",
+			])
+			.output()
+			.expect("failed to execute rust process");
+
+		assert!(output.status.success());
+		assert_eq!(String::from_utf8_lossy(&output.stdout), "hi#\n");
+	}
 }
@@ -0,0 +1,122 @@
+extern crate cfonts;
+
+use std::time::Duration;
+
+use cfonts::animate::Frame;
+use cfonts::config::{Align, BgColors, Colors, Fonts, Options};
+use cfonts::marquee::{frames, slice_line};
+
+#[cfg(test)]
+mod marquee {
+	use super::*;
+
+	fn texts(frames: &[Frame]) -> Vec<&str> {
+		frames.iter().map(|frame| frame.text.as_str()).collect()
+	}
+
+	#[test]
+	fn slice_line_works() {
+		assert_eq!(slice_line("abc", 0, 3), "abc");
+		assert_eq!(slice_line("abc", 0, 10), "abc");
+		assert_eq!(slice_line("abc", 3, 2), "");
+		assert_eq!(slice_line("", 0, 2), "");
+		// escape sequences of columns we cut away are kept
+		assert_eq!(slice_line("\x1b[31ma\x1b[39m\x1b[32mb\x1b[39m", 1, 1), "\x1b[31m\x1b[39m\x1b[32mb\x1b[39m");
+		assert_eq!(slice_line("\x1b[44m█ █\x1b[49m", 2, 1), "\x1b[44m█\x1b[49m");
+	}
+
+	#[test]
+	fn frames_works() {
+		let options = Options {
+			text: String::from("ab|c"),
+			font: Fonts::FontConsole,
+			spaceless: true,
+			speed: 4,
+			..Options::default()
+		};
+
+		// each line of text gets its own rows and is padded to the width of the widest
+		let result = frames(options, 2).take(7).collect::<Vec<Frame>>();
+		assert_eq!(
+			texts(&result),
+			vec![
+				"  \n  \n  ",
+				" a\n  \n c",
+				"ab\n  \nc ",
+				"b \n  \n  ",
+				"  \n  \n  ",
+				" a\n  \n c",
+				"ab\n  \nc "
+			]
+		);
+		assert!(result.iter().all(|frame| frame.delay == Duration::from_millis(250)));
+	}
+
+	#[test]
+	fn frames_does_not_wrap() {
+		let options = Options {
+			text: String::from("a text wider than any terminal"),
+			font: Fonts::FontBlock,
+			align: Align::Center,
+			..Options::default()
+		};
+
+		let result = frames(options, 80).take(1).collect::<Vec<Frame>>();
+		// the block font has 6 rows and the padding adds 2 above and below
+		assert_eq!(result[0].height(), 10);
+	}
+
+	#[test]
+	fn frames_keeps_colors() {
+		let options = Options {
+			text: String::from("ab"),
+			font: Fonts::FontConsole,
+			colors: vec![Colors::Red],
+			background: BgColors::Blue,
+			spaceless: true,
+			..Options::default()
+		};
+
+		let result = frames(options, 1).take(3).collect::<Vec<Frame>>();
+		assert_eq!(
+			texts(&result),
+			vec![
+				"\x1b[44m\n \x1b[31m\x1b[39m\x1b[31m\x1b[39m\x1b[31m\x1b[39m\x1b[31m\x1b[39m\x1b[49m",
+				"\x1b[44m\n\x1b[31ma\x1b[39m\x1b[31m\x1b[39m\x1b[31m\x1b[39m\x1b[31m\x1b[39m\x1b[49m",
+				"\x1b[44m\n\x1b[31m\x1b[39m\x1b[31mb\x1b[39m\x1b[31m\x1b[39m\x1b[31m\x1b[39m\x1b[49m",
+			]
+		);
+	}
+
+	#[test]
+	fn frames_duration_works() {
+		let options = Options {
+			text: String::from("hi"),
+			font: Fonts::FontConsole,
+			spaceless: true,
+			speed: 10,
+			duration: Some(1_000),
+			..Options::default()
+		};
+
+		let result = frames(options, 2).collect::<Vec<Frame>>();
+		assert_eq!(result.len(), 10);
+		assert_eq!(result[9].delay, Duration::ZERO);
+		assert_eq!(result[4].text, result[0].text);
+	}
+
+	#[test]
+	fn frames_work_with_huge_durations() {
+		let options = Options {
+			text: String::from("hi"),
+			font: Fonts::FontConsole,
+			spaceless: true,
+			duration: Some(u64::MAX),
+			..Options::default()
+		};
+
+		let result = frames(options, 2).take(3).collect::<Vec<Frame>>();
+		assert_eq!(result.len(), 3);
+		assert!(result.iter().all(|frame| frame.delay > Duration::ZERO));
+	}
+}
@@ -0,0 +1,98 @@
+extern crate cfonts;
+
+use cfonts::config::{Align, BgColors, ColorMode, Colors, Env, Fonts, Options};
+use cfonts::plain::{render_plain, strip_ansi, strip_html, to_plain, trim_lines};
+use cfonts::render::render;
+
+#[cfg(test)]
+mod plain {
+	use super::*;
+
+	#[test]
+	fn strip_ansi_works() {
+		assert_eq!(strip_ansi("no colors"), "no colors");
+		assert_eq!(strip_ansi("\x1b[31ma\x1b[39m\n\x1b[48;5;196mb\x1b[49m"), "a\nb");
+		assert_eq!(strip_ansi("\x1b[38;2;1;2;3m█\x1b[0m"), "█");
+		// a lone escape character is kept
+		assert_eq!(strip_ansi("a\x1bb"), "a\x1bb");
+	}
+
+	#[test]
+	fn strip_html_works() {
+		assert_eq!(strip_html("plain"), "plain");
+		assert_eq!(strip_html("<span style=\"color:#ff0000\">a</span>"), "a");
+		assert_eq!(strip_html("<div style=\"a:b\">x<br>y</div>"), "xy");
+		// glyphs that look like html stay
+		assert_eq!(strip_html("<a> <span"), "<a> <span");
+	}
+
+	#[test]
+	fn trim_lines_works() {
+		assert_eq!(trim_lines("a \nb\t\n  \n"), "a\nb\n\n");
+		assert_eq!(trim_lines("  a"), "  a");
+	}
+
+	#[test]
+	fn to_plain_works_with_cli() {
+		let output = render(Options {
+			text: String::from("hi"),
+			font: Fonts::FontTiny,
+			colors: vec![Colors::Red],
+			background: BgColors::Blue,
+			color_mode: ColorMode::Truecolor,
+			align: Align::Center,
+			..Options::default()
+		});
+		let plain = to_plain(&output);
+
+		assert!(!plain.text.contains('\x1b'));
+		assert!(plain.vec.iter().all(|line| !line.contains('\x1b')));
+		assert_eq!(plain.text.split('\n').count(), output.text.split('\n').count());
+		assert_eq!(plain.lines, 1);
+	}
+
+	#[test]
+	fn to_plain_works_with_browser() {
+		let output = render(Options {
+			text: String::from("hi"),
+			font: Fonts::FontTiny,
+			colors: vec![Colors::Red],
+			env: Env::Browser,
+			..Options::default()
+		});
+		let plain = to_plain(&output);
+
+		assert!(!plain.text.contains('<'));
+		assert_eq!(plain.text, "\n\n █ █ █\n █▀█ █\n\n");
+	}
+
+	#[test]
+	fn to_plain_trims() {
+		let output = render(Options {
+			text: String::from("hi"),
+			font: Fonts::FontTiny,
+			align: Align::Right,
+			letter_spacing: 3,
+			trim: true,
+			..Options::default()
+		});
+		let plain = to_plain(&output);
+
+		assert!(plain.text.split('\n').all(|line| line == line.trim_end()));
+		assert!(plain.vec.iter().flat_map(|line| line.split('\n')).all(|line| line == line.trim_end()));
+	}
+
+	#[test]
+	fn render_plain_works() {
+		let output = render_plain(&Options {
+			text: String::from("hi"),
+			font: Fonts::FontTiny,
+			colors: vec![Colors::Red],
+			background: BgColors::Red,
+			color_mode: ColorMode::Truecolor,
+			..Options::default()
+		});
+
+		assert_eq!(output.text, "\n\n █ █ █\n █▀█ █\n\n");
+	}
+}
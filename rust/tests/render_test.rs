@@ -0,0 +1,335 @@
+extern crate cfonts;
+
+use cfonts::config::{Align, BgColors, Border, CandyMode, ColorMode, Colors, Env, Fonts, Options, TerminalBg};
+use cfonts::render::{render, render_many};
+
+#[cfg(test)]
+mod render {
+	use super::*;
+
+	#[test]
+	fn render_is_deterministic_with_seed() {
+		let options = Options {
+			text: String::from("candy|colors"),
+			font: Fonts::FontBlock,
+			colors: vec![Colors::Candy, Colors::Candy],
+			color_mode: ColorMode::Truecolor,
+			seed: Some(42),
+			..Options::default()
+		};
+
+		for candy_mode in [CandyMode::Segment, CandyMode::Glyph, CandyMode::Row, CandyMode::Layer] {
+			let options = Options {
+				candy_mode,
+				..options.clone()
+			};
+			assert_eq!(render(options.clone()).text, render(options).text);
+		}
+	}
+
+	#[test]
+	fn render_returns_unresolved_options() {
+		let options = Options {
+			text: String::from("hi"),
+			colors: vec![Colors::Candy],
+			..Options::default()
+		};
+
+		let output = render(options.clone());
+		assert_eq!(output.options, options);
+		assert_eq!(output.options.seed, None);
+		assert_eq!(output.options.color_mode, ColorMode::Auto);
+	}
+
+	#[test]
+	fn render_respects_color_mode() {
+		let options = Options {
+			text: String::from("hi"),
+			font: Fonts::FontTiny,
+			colors: vec![Colors::Red],
+			..Options::default()
+		};
+
+		temp_env::with_var("FORCE_COLOR", Some("3"), || {
+			let output = render(Options {
+				color_mode: ColorMode::NoColor,
+				..options.clone()
+			});
+			assert!(!output.text.contains('\x1b'));
+
+			let output = render(options.clone());
+			assert!(output.text.contains("\x1b[31m"));
+		});
+	}
+
+	#[test]
+	fn render_respects_no_wrap() {
+		let options = Options {
+			text: String::from("a text wider than any terminal"),
+			font: Fonts::FontBlock,
+			..Options::default()
+		};
+		assert!(render(options.clone()).lines > 1);
+
+		let output = render(Options {
+			no_wrap: true,
+			..options.clone()
+		});
+		assert_eq!(output.lines, 1);
+		assert!(output.vec.iter().any(|line| line.chars().count() > 200));
+
+		// aligning a line wider than the terminal leaves it where it is
+		for align in [Align::Center, Align::Right] {
+			let aligned = render(Options {
+				no_wrap: true,
+				align,
+				..options.clone()
+			});
+			assert_eq!(aligned.text, output.text);
+		}
+	}
+
+	#[test]
+	fn render_many_with_one_block_is_render() {
+		let options = Options {
+			text: String::from("hi"),
+			font: Fonts::FontTiny,
+			background: BgColors::Blue,
+			color_mode: ColorMode::Ansi16,
+			..Options::default()
+		};
+
+		assert_eq!(render_many(std::slice::from_ref(&options)).text, render(options).text);
+		assert_eq!(render_many(&[]).text, render(Options::default()).text);
+	}
+
+	#[test]
+	fn render_many_stacks_blocks() {
+		let title = Options {
+			text: String::from("hi"),
+			font: Fonts::FontTiny,
+			align: Align::Right,
+			background: BgColors::Blue,
+			color_mode: ColorMode::Ansi16,
+			..Options::default()
+		};
+		let subtitle = Options {
+			text: String::from("there"),
+			font: Fonts::FontConsole,
+			line_height: 0,
+			background: BgColors::Red,
+			spaceless: true,
+			..Options::default()
+		};
+
+		let output = render_many(&[title.clone(), subtitle.clone()]);
+		let title_lines = render(Options {
+			background: BgColors::Transparent,
+			spaceless: true,
+			..title.clone()
+		})
+		.vec;
+		let subtitle_lines = render(Options {
+			align: Align::Right,
+			background: BgColors::Transparent,
+			..subtitle
+		})
+		.vec;
+
+		assert_eq!(output.lines, 2);
+		assert_eq!(output.options, title);
+		assert_eq!(output.vec.len(), title_lines.len() + subtitle_lines.len());
+		// the space and background of the first block go around all blocks
+		assert!(output.vec[0].starts_with("\x1b[44m\n\n\n"));
+		assert_eq!(output.vec[1], title_lines[1]);
+		assert!(output.vec[2].starts_with(&subtitle_lines[0]));
+		assert!(output.vec[2].ends_with("\n\n\x1b[49m"));
+		assert!(!output.text.contains("\x1b[41m"));
+	}
+
+	#[test]
+	fn render_many_shares_env() {
+		let output = render_many(&[
+			Options {
+				text: String::from("a"),
+				env: Env::Browser,
+				..Options::default()
+			},
+			Options {
+				text: String::from("b"),
+				..Options::default()
+			},
+		]);
+
+		assert_eq!(output.text.matches("<div").count(), 1);
+		assert!(!output.text.contains("\x1b["));
+	}
+
+	#[test]
+	fn render_draws_border() {
+		let options = Options {
+			text: String::from("hi|there"),
+			font: Fonts::FontConsole,
+			line_height: 0,
+			border: Some(Border::Rounded),
+			padding: [0, 1, 0, 1],
+			color_mode: ColorMode::NoColor,
+			..Options::default()
+		};
+
+		let output = render(options.clone());
+		assert_eq!(output.text, "\n\n╭───────╮\n│ hi    │\n│ there │\n╰───────╯\n\n");
+		assert_eq!(output.lines, 2);
+		assert_eq!(output.options, options);
+
+		let output = render(Options {
+			spaceless: true,
+			padding: [0; 4],
+			border: None,
+			..options
+		});
+		assert_eq!(output.text, "hi\nthere");
+	}
+
+	#[test]
+	fn render_aligns_border() {
+		let output = render(Options {
+			text: String::from("hi|there"),
+			font: Fonts::FontConsole,
+			line_height: 0,
+			border: Some(Border::Ascii),
+			align: Align::Right,
+			spaceless: true,
+			color_mode: ColorMode::NoColor,
+			..Options::default()
+		});
+
+		// the frame is aligned as a whole and the text within it
+		let indent = output.vec[0].len() - output.vec[0].trim_start().len();
+		assert!(indent > 0);
+		assert!(output.vec.iter().all(|row| row.len() - row.trim_start().len() == indent));
+		assert_eq!(output.vec[1].trim_start(), "|   hi|");
+	}
+
+	#[test]
+	fn render_paints_background_inside_border() {
+		let output = render(Options {
+			text: String::from("hi"),
+			font: Fonts::FontConsole,
+			border: Some(Border::Ascii),
+			background: BgColors::Blue,
+			color_mode: ColorMode::Ansi16,
+			spaceless: true,
+			..Options::default()
+		});
+		assert_eq!(output.text, "+--+\n|\x1b[44mhi\x1b[49m|\n+--+");
+
+		let output = render(Options {
+			text: String::from("hi"),
+			font: Fonts::FontConsole,
+			border: Some(Border::Ascii),
+			background: BgColors::Blue,
+			env: Env::Browser,
+			spaceless: true,
+			..Options::default()
+		});
+		assert!(output.text.starts_with("<div style="));
+		assert!(output.text.contains("background:#0020f5"));
+		assert!(output.text.contains("+--+<br>\n|hi|<br>\n+--+"));
+	}
+
+	#[test]
+	fn render_draws_shadow() {
+		let options = Options {
+			text: String::from("hi|you"),
+			font: Fonts::FontConsole,
+			line_height: 0,
+			shadow: true,
+			shadow_char: '.',
+			color_mode: ColorMode::NoColor,
+			..Options::default()
+		};
+
+		let output = render(options.clone());
+		assert_eq!(output.text, "\n\nhi\nyou\n ...\n\n");
+		assert_eq!(output.lines, 2);
+		assert_eq!(output.options, options);
+
+		let output = render(Options {
+			shadow_offset: [2, 0],
+			spaceless: true,
+			..options.clone()
+		});
+		assert_eq!(output.text, "hi..\nyou..");
+
+		let output = render(Options {
+			border: Some(Border::Ascii),
+			spaceless: true,
+			..options
+		});
+		assert_eq!(output.text, "+----+\n|hi  |\n|you |\n| ...|\n+----+");
+	}
+
+	#[test]
+	fn render_paints_shadow() {
+		let output = render(Options {
+			text: String::from("a"),
+			font: Fonts::FontConsole,
+			shadow: true,
+			colors: vec![Colors::Red],
+			background: BgColors::Blue,
+			color_mode: ColorMode::Ansi16,
+			spaceless: true,
+			..Options::default()
+		});
+		assert_eq!(output.text, "\x1b[44m\n\x1b[31ma\x1b[39m\n \x1b[90m░\x1b[39m\x1b[49m");
+
+		let output = render(Options {
+			text: String::from("a"),
+			font: Fonts::FontConsole,
+			shadow: true,
+			colors: vec![Colors::Red],
+			env: Env::Browser,
+			spaceless: true,
+			..Options::default()
+		});
+		assert!(output.text.starts_with("<div style="));
+		assert!(output
+			.text
+			.contains("<span style=\"color:#ea3223\">a</span><br>\n <span style=\"color:#808080\">░</span>"));
+	}
+
+	#[test]
+	fn render_swaps_unreadable_colors() {
+		let options = Options {
+			text: String::from("a"),
+			font: Fonts::FontConsole,
+			colors: vec![Colors::WhiteBright],
+			border: Some(Border::Ascii),
+			terminal_bg: TerminalBg::Light,
+			color_mode: ColorMode::Ansi16,
+			spaceless: true,
+			..Options::default()
+		};
+		assert_eq!(
+			render(options.clone()).text,
+			"\x1b[30m+-+\x1b[39m\n\x1b[30m|\x1b[39m\x1b[30ma\x1b[39m\x1b[30m|\x1b[39m\n\x1b[30m+-+\x1b[39m"
+		);
+
+		let output = render(Options {
+			colors: vec![Colors::Black],
+			border: None,
+			terminal_bg: TerminalBg::Dark,
+			..options.clone()
+		});
+		assert_eq!(output.text, "\x1b[37ma\x1b[39m");
+
+		// with a background the terminal doesn't show through
+		let output = render(Options {
+			background: BgColors::Black,
+			border: None,
+			..options
+		});
+		assert_eq!(output.text, "\x1b[40m\n\x1b[97ma\x1b[39m\x1b[49m");
+	}
+}
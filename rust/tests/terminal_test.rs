@@ -0,0 +1,126 @@
+extern crate cfonts;
+
+use std::io::{Read, Result, Write};
+use std::time::{Duration, Instant};
+
+use cfonts::color::Rgb;
+use cfonts::config::{Env, Options, TerminalBg};
+use cfonts::terminal::{
+	parse_colorfgbg, parse_osc11_response, query_osc11, resolve_terminal_bg, resolve_terminal_bg_with, rgb2terminal_bg,
+	OSC11_QUERY,
+};
+
+/// A tty that answers with its response in chunks
+struct FakeTty {
+	chunks: Vec<Vec<u8>>,
+	written: Vec<u8>,
+}
+
+impl FakeTty {
+	fn new(chunks: &[&[u8]]) -> Self {
+		FakeTty {
+			chunks: chunks.iter().map(|chunk| chunk.to_vec()).collect(),
+			written: Vec::new(),
+		}
+	}
+}
+
+impl Read for FakeTty {
+	fn read(&mut self, buf: &mut [u8]) -> Result<usize> {
+		if self.chunks.is_empty() {
+			return Ok(0);
+		}
+		let chunk = self.chunks.remove(0);
+		buf[..chunk.len()].copy_from_slice(&chunk);
+		Ok(chunk.len())
+	}
+}
+
+impl Write for FakeTty {
+	fn write(&mut self, buf: &[u8]) -> Result<usize> {
+		self.written.extend_from_slice(buf);
+		Ok(buf.len())
+	}
+
+	fn flush(&mut self) -> Result<()> {
+		Ok(())
+	}
+}
+
+#[cfg(test)]
+mod terminal {
+	use super::*;
+
+	#[test]
+	fn parse_osc11_response_works() {
+		assert_eq!(parse_osc11_response("\x1b]11;rgb:ffff/ffff/ffff\x1b\\"), Some(Rgb::Val(255, 255, 255)));
+		assert_eq!(parse_osc11_response("\x1b]11;rgb:2828/2c2c/3434\x07"), Some(Rgb::Val(40, 44, 52)));
+		assert_eq!(parse_osc11_response("\x1b]11;rgb:28/2c/34\x07"), Some(Rgb::Val(40, 44, 52)));
+		assert_eq!(parse_osc11_response("\x1b]11;rgb:fff/000/888\x07"), Some(Rgb::Val(255, 0, 136)));
+		assert_eq!(parse_osc11_response("\x1b]11;rgb:ffff/ffff\x07"), None);
+		assert_eq!(parse_osc11_response("\x1b]11;rgb:fffff/0/0\x07"), None);
+		assert_eq!(parse_osc11_response("\x1b]11;rgb:zz/00/00\x07"), None);
+		assert_eq!(parse_osc11_response(""), None);
+	}
+
+	#[test]
+	fn parse_colorfgbg_works() {
+		assert_eq!(parse_colorfgbg("15;0"), Some(TerminalBg::Dark));
+		assert_eq!(parse_colorfgbg("7;8"), Some(TerminalBg::Dark));
+		assert_eq!(parse_colorfgbg("0;15"), Some(TerminalBg::Light));
+		assert_eq!(parse_colorfgbg("0;default;7"), Some(TerminalBg::Light));
+		assert_eq!(parse_colorfgbg("0;42"), None);
+		assert_eq!(parse_colorfgbg(""), None);
+	}
+
+	#[test]
+	fn rgb2terminal_bg_works() {
+		assert_eq!(rgb2terminal_bg(&Rgb::Val(0, 0, 0)), TerminalBg::Dark);
+		assert_eq!(rgb2terminal_bg(&Rgb::Val(0, 0, 255)), TerminalBg::Dark);
+		assert_eq!(rgb2terminal_bg(&Rgb::Val(253, 246, 227)), TerminalBg::Light);
+		assert_eq!(rgb2terminal_bg(&Rgb::Val(255, 255, 255)), TerminalBg::Light);
+	}
+
+	#[test]
+	fn query_osc11_works() {
+		let mut tty = FakeTty::new(&[b"\x1b]11;rgb:fdfd/", b"f6f6/e3e3\x1b", b"\\"]);
+		assert_eq!(query_osc11(&mut tty, Duration::from_millis(100)), Some(Rgb::Val(253, 246, 227)));
+		assert_eq!(tty.written, OSC11_QUERY.as_bytes());
+	}
+
+	#[test]
+	fn query_osc11_times_out() {
+		let mut tty = FakeTty::new(&[]);
+		let start = Instant::now();
+		assert_eq!(query_osc11(&mut tty, Duration::from_millis(20)), None);
+		assert!(start.elapsed() < Duration::from_millis(500));
+
+		let mut tty = FakeTty::new(&[b"\x1b]11;rgb:0000/00"]);
+		assert_eq!(query_osc11(&mut tty, Duration::from_millis(20)), None);
+	}
+
+	#[test]
+	fn resolve_terminal_bg_works() {
+		let options = Options {
+			terminal_bg: TerminalBg::Dark,
+			..Options::default()
+		};
+		assert_eq!(resolve_terminal_bg(&options), TerminalBg::Dark);
+		assert_eq!(resolve_terminal_bg_with(&options, || Some(Rgb::Val(255, 255, 255))), TerminalBg::Dark);
+
+		let options = Options::default();
+		temp_env::with_var("COLORFGBG", Some("0;15"), || {
+			assert_eq!(resolve_terminal_bg_with(&options, || Some(Rgb::Val(0, 0, 0))), TerminalBg::Dark);
+			assert_eq!(resolve_terminal_bg_with(&options, || None), TerminalBg::Light);
+		});
+		temp_env::with_var_unset("COLORFGBG", || {
+			assert_eq!(resolve_terminal_bg_with(&options, || None), TerminalBg::Dark);
+		});
+
+		let options = Options {
+			env: Env::Browser,
+			..Options::default()
+		};
+		assert_eq!(resolve_terminal_bg_with(&options, || Some(Rgb::Val(255, 255, 255))), TerminalBg::Auto);
+	}
+}
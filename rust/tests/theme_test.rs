@@ -0,0 +1,303 @@
+extern crate cfonts;
+
+use std::fs;
+use std::path::PathBuf;
+
+use cfonts::args::parse;
+use cfonts::color::Rgb;
+use cfonts::config::{BgColors, Colors, Fonts, Options, TerminalBg, GRADIENTS_PRIDE};
+use cfonts::theme::{Theme, ThemeRegistry};
+
+fn temp_dir(name: &str) -> PathBuf {
+	let dir = std::env::temp_dir().join(format!("cfonts-theme-test-{}-{}", name, std::process::id()));
+	let _ = fs::remove_dir_all(&dir);
+	fs::create_dir_all(&dir).unwrap();
+	dir
+}
+
+#[cfg(test)]
+mod theme {
+	use super::*;
+
+	#[test]
+	fn theme_from_toml_works() {
+		let theme = Theme::from_toml(
+			"font = \"block\"\ncolors = [\"#1e90ff\", \"red\"]\nbackground = \"black\"\nletter_spacing = 2\nspaceless = true",
+		)
+		.unwrap();
+
+		assert_eq!(
+			theme,
+			Theme {
+				font: Some(String::from("block")),
+				colors: vec![String::from("#1e90ff"), String::from("red")],
+				background: Some(String::from("black")),
+				letter_spacing: Some(2),
+				spaceless: Some(true),
+				..Theme::default()
+			}
+		);
+	}
+
+	#[test]
+	fn theme_rejects_unknown_fields() {
+		assert!(Theme::from_toml("colour = [\"red\"]").is_err());
+		assert!(Theme::from_json("{ \"colour\": [\"red\"] }").is_err());
+	}
+
+	#[test]
+	fn theme_apply_works() {
+		let theme = Theme {
+			colors: vec![String::from("#1e90ff"), String::from("#ff6f61")],
+			gradient: vec![String::from("red"), String::from("blue")],
+			background: Some(String::from("#000")),
+			..Theme::default()
+		};
+		let mut options = Options::default();
+		theme.apply(&mut options).unwrap();
+
+		assert_eq!(options.colors, vec![Colors::Rgb(Rgb::Val(30, 144, 255)), Colors::Rgb(Rgb::Val(255, 111, 97))]);
+		assert_eq!(options.gradient, vec![String::from("#ff0000"), String::from("#0000ff")]);
+		assert_eq!(options.background, BgColors::Rgb(Rgb::Val(0, 0, 0)));
+		assert_eq!(options.font, Fonts::FontBlock);
+	}
+
+	#[test]
+	fn theme_apply_validates() {
+		let mut options = Options::default();
+		let theme = Theme {
+			colors: vec![String::from("nope")],
+			..Theme::default()
+		};
+		assert!(theme.apply(&mut options).is_err());
+
+		let theme = Theme {
+			gradient: vec![String::from("red"), String::from("green"), String::from("blue")],
+			..Theme::default()
+		};
+		assert!(theme.apply(&mut options).is_err());
+	}
+
+	#[test]
+	fn theme_apply_console_font_line_height() {
+		let mut options = Options::default();
+		Theme {
+			font: Some(String::from("console")),
+			..Theme::default()
+		}
+		.apply(&mut options)
+		.unwrap();
+		assert_eq!(options.line_height, 0);
+
+		let mut options = Options::default();
+		Theme {
+			font: Some(String::from("console")),
+			line_height: Some(3),
+			..Theme::default()
+		}
+		.apply(&mut options)
+		.unwrap();
+		assert_eq!(options.line_height, 3);
+	}
+
+	#[test]
+	fn theme_apply_picks_terminal_bg_variant() {
+		let theme = Theme::from_toml(
+			"background = \"transparent\"\ncolors = [\"red\"]\n\n[light]\ncolors = [\"black\"]\n\n[dark]\ncolors = [\"white\"]",
+		)
+		.unwrap();
+
+		let mut options = Options {
+			terminal_bg: TerminalBg::Light,
+			..Options::default()
+		};
+		theme.apply(&mut options).unwrap();
+		assert_eq!(options.colors, vec![Colors::Black]);
+
+		let mut options = Options {
+			terminal_bg: TerminalBg::Dark,
+			..Options::default()
+		};
+		theme.apply(&mut options).unwrap();
+		assert_eq!(options.colors, vec![Colors::White]);
+
+		let theme = Theme::from_toml("colors = [\"red\"]\n\n[light]\ncolors = [\"black\"]").unwrap();
+		let mut options = Options {
+			terminal_bg: TerminalBg::Dark,
+			..Options::default()
+		};
+		theme.apply(&mut options).unwrap();
+		assert_eq!(options.colors, vec![Colors::Red]);
+	}
+
+	#[test]
+	fn theme_registry_has_builtin_themes() {
+		let registry = ThemeRegistry::default();
+
+		assert_eq!(registry.list().len(), 13);
+		assert_eq!(registry.get("pride").unwrap().gradient, GRADIENTS_PRIDE.map(String::from).to_vec());
+		assert_eq!(registry.get("LGBTQ"), registry.get("pride"));
+		assert_eq!(registry.get("trans"), registry.get("transgender"));
+		assert_eq!(registry.get("nope"), None);
+		assert_eq!(ThemeRegistry::new().list().len(), 0);
+	}
+
+	#[test]
+	fn theme_registry_register_works() {
+		let mut registry = ThemeRegistry::default();
+		let theme = Theme {
+			font: Some(String::from("tiny")),
+			..Theme::default()
+		};
+
+		registry.register("Brand", theme.clone());
+		assert_eq!(registry.get("brand"), Some(&theme));
+
+		registry.register("pride", theme.clone());
+		assert_eq!(registry.get("pride"), Some(&theme));
+		assert_eq!(registry.get("lgbt"), Some(&theme));
+	}
+
+	#[test]
+	fn theme_registry_load_dir_works() {
+		let dir = temp_dir("load-dir");
+		fs::write(dir.join("brand.toml"), "colors = [\"#1e90ff\", \"#ff6f61\"]").unwrap();
+		fs::write(dir.join("Night.json"), "{ \"background\": \"black\" }").unwrap();
+		fs::write(dir.join("notes.txt"), "not a theme").unwrap();
+
+		let mut registry = ThemeRegistry::new();
+		assert!(registry.load_dir(&dir, &Options::default()).is_empty());
+		assert_eq!(registry.list(), vec![String::from("brand"), String::from("night")]);
+		assert_eq!(registry.get("night").unwrap().background, Some(String::from("black")));
+
+		assert!(registry.load_dir(&dir.join("missing"), &Options::default()).is_empty());
+
+		// broken files are skipped and reported by name
+		fs::write(dir.join("Broken.toml"), "colors = ").unwrap();
+		let mut registry = ThemeRegistry::new();
+		let broken = registry.load_dir(&dir, &Options::default());
+		assert_eq!(broken.keys().collect::<Vec<&String>>(), vec!["broken"]);
+		assert!(broken["broken"].contains("Broken.toml"));
+		assert_eq!(registry.list(), vec![String::from("brand"), String::from("night")]);
+
+		let _ = fs::remove_dir_all(&dir);
+	}
+
+	#[test]
+	fn theme_cli_works() {
+		let dir = temp_dir("cli");
+		fs::create_dir_all(dir.join("cfonts").join("themes")).unwrap();
+		fs::write(
+			dir.join("cfonts").join("themes").join("brand.toml"),
+			"font = \"tiny\"\ncolors = [\"#1e90ff\", \"#ff6f61\"]\nbackground = \"blue\"",
+		)
+		.unwrap();
+
+		temp_env::with_var("XDG_CONFIG_HOME", Some(&dir), || {
+			let options = parse(vec![
+				"path/to/bin".to_string(),
+				"text".to_string(),
+				"--theme".to_string(),
+				"brand".to_string(),
+			])
+			.unwrap();
+			assert_eq!(options.font, Fonts::FontTiny);
+			assert_eq!(options.colors, vec![Colors::Rgb(Rgb::Val(30, 144, 255)), Colors::Rgb(Rgb::Val(255, 111, 97))]);
+			assert_eq!(options.background, BgColors::Blue);
+
+			// flags win over the theme no matter where they are
+			let options = parse(vec![
+				"path/to/bin".to_string(),
+				"text".to_string(),
+				"-b".to_string(),
+				"red".to_string(),
+				"--theme".to_string(),
+				"brand".to_string(),
+			])
+			.unwrap();
+			assert_eq!(options.font, Fonts::FontTiny);
+			assert_eq!(options.background, BgColors::Red);
+
+			// the terminal background is known before the theme is applied
+			std::fs::write(
+				dir.join("cfonts").join("themes").join("contrast.toml"),
+				"[light]\ncolors = [\"black\"]\n\n[dark]\ncolors = [\"white\"]",
+			)
+			.unwrap();
+			let options = parse(vec![
+				"path/to/bin".to_string(),
+				"text".to_string(),
+				"--theme".to_string(),
+				"contrast".to_string(),
+				"--terminal-bg".to_string(),
+				"light".to_string(),
+			])
+			.unwrap();
+			assert_eq!(options.colors, vec![Colors::Black]);
+
+			let error = parse(vec![
+				"path/to/bin".to_string(),
+				"text".to_string(),
+				"--theme".to_string(),
+				"nope".to_string(),
+			])
+			.unwrap_err();
+			assert!(error.contains("brand"));
+			assert!(error.contains("pride"));
+
+			assert!(parse(vec!["path/to/bin".to_string(), "text".to_string(), "--theme".to_string()]).is_err());
+
+			// a broken theme file only fails when it's asked for
+			fs::write(dir.join("cfonts").join("themes").join("broken.toml"), "colors = ").unwrap();
+			let theme = |name: &str| {
+				parse(vec![
+					"path/to/bin".to_string(),
+					"text".to_string(),
+					"--theme".to_string(),
+					name.to_string(),
+				])
+			};
+			assert!(theme("pride").unwrap().transition_gradient);
+			assert_eq!(theme("brand").unwrap().font, Fonts::FontTiny);
+			assert!(theme("broken").unwrap_err().contains("broken.toml"));
+
+			// a theme gradient with more than two colors needs the transition flag
+			fs::write(dir.join("cfonts").join("themes").join("t3.toml"), "gradient = [\"red\", \"blue\", \"green\"]")
+				.unwrap();
+			assert!(theme("t3").is_err());
+			let options = parse(vec![
+				"path/to/bin".to_string(),
+				"text".to_string(),
+				"--theme".to_string(),
+				"t3".to_string(),
+				"-t".to_string(),
+			])
+			.unwrap();
+			assert!(options.transition_gradient);
+			assert_eq!(options.gradient.len(), 3);
+		});
+
+		let file = dir.join("night.json");
+		fs::write(&file, "{ \"background\": \"black\" }").unwrap();
+		let options = parse(vec![
+			"path/to/bin".to_string(),
+			"text".to_string(),
+			"--theme".to_string(),
+			file.display().to_string(),
+		])
+		.unwrap();
+		assert_eq!(options.background, BgColors::Black);
+
+		let options = parse(vec![
+			"path/to/bin".to_string(),
+			"text".to_string(),
+			"--theme".to_string(),
+			"pride".to_string(),
+		])
+		.unwrap();
+		assert!(options.transition_gradient);
+		assert_eq!(options.gradient.len(), GRADIENTS_PRIDE.len());
+
+		let _ = fs::remove_dir_all(&dir);
+	}
+}